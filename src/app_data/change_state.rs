@@ -0,0 +1,184 @@
+use std::fmt;
+
+use bollard::service::ContainerChangeResponseItem;
+use ratatui::widgets::ListState;
+
+use crate::app_data::{container_state::ContainerId, statefull_list::StatefulList};
+
+/// The kind of a change to the filesystem of a container, as returned by docker, 0 is modified, 1 is added, and 2 is deleted
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ChangeKind {
+    Modified,
+    Added,
+    Deleted,
+}
+
+impl ChangeKind {
+    const fn from_docker(kind: i64) -> Self {
+        match kind {
+            1 => Self::Added,
+            2 => Self::Deleted,
+            _ => Self::Modified,
+        }
+    }
+}
+
+/// Single letter, as shown by `docker diff`
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Added => "A",
+            Self::Modified => "C",
+            Self::Deleted => "D",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// A single changed path in the filesystem of a container
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct ChangeItem {
+    pub kind: ChangeKind,
+    pub path: String,
+}
+
+impl From<ContainerChangeResponseItem> for ChangeItem {
+    fn from(value: ContainerChangeResponseItem) -> Self {
+        Self {
+            kind: ChangeKind::from_docker(value.kind),
+            path: value.path,
+        }
+    }
+}
+
+/// Only show changes of a single kind, and, or, with a path that contains some text
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq)]
+pub struct ChangeFilter {
+    pub kind: Option<ChangeKind>,
+    pub path: String,
+}
+
+impl ChangeFilter {
+    pub fn new(kind: &str, path: &str) -> Self {
+        let kind = match kind {
+            "added" => Some(ChangeKind::Added),
+            "changed" => Some(ChangeKind::Modified),
+            "deleted" => Some(ChangeKind::Deleted),
+            _ => None,
+        };
+        Self {
+            kind,
+            path: path.to_owned(),
+        }
+    }
+
+    /// The kind, as used in the filter form
+    pub const fn kind_text(&self) -> &'static str {
+        match self.kind {
+            Some(ChangeKind::Added) => "added",
+            Some(ChangeKind::Modified) => "changed",
+            Some(ChangeKind::Deleted) => "deleted",
+            None => "all",
+        }
+    }
+
+    fn matches(&self, change: &ChangeItem) -> bool {
+        self.kind.is_none_or(|i| i == change.kind)
+            && change
+                .path
+                .to_lowercase()
+                .contains(&self.path.to_lowercase())
+    }
+}
+
+/// Display the filter for the panel title, empty if nothing is filtered
+impl fmt::Display for ChangeFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.kind.is_some(), self.path.is_empty()) {
+            (false, true) => Ok(()),
+            (false, false) => write!(f, " filter: \"{}\"", self.path),
+            (true, true) => write!(f, " filter: {}", self.kind_text()),
+            (true, false) => write!(f, " filter: {} \"{}\"", self.kind_text(), self.path),
+        }
+    }
+}
+
+/// The paths added, changed, or deleted in the filesystem of a container, outside of its volumes, compared to its image
+#[derive(Debug, Clone)]
+pub struct ContainerChanges {
+    pub name: String,
+    pub container: ContainerId,
+    pub filter: ChangeFilter,
+    changes: Vec<ChangeItem>,
+    pub list: StatefulList<ChangeItem>,
+}
+
+impl ContainerChanges {
+    pub fn new(name: String, container: ContainerId, mut changes: Vec<ChangeItem>) -> Self {
+        changes.sort_by(|a, b| a.path.cmp(&b.path));
+        let mut output = Self {
+            name,
+            container,
+            filter: ChangeFilter::default(),
+            changes,
+            list: StatefulList::new(vec![]),
+        };
+        output.apply_filter();
+        output
+    }
+
+    /// Replace the changes, e.g. when refreshed, keeping the current filter, and the selected path if it's still present
+    pub fn set_changes(&mut self, other: Self) {
+        self.changes = other.changes;
+        self.apply_filter();
+    }
+
+    /// Set a new filter, keeping the selected path if it still matches
+    pub fn set_filter(&mut self, filter: ChangeFilter) {
+        self.filter = filter;
+        self.apply_filter();
+    }
+
+    /// Rebuild the list from the changes that match the filter
+    fn apply_filter(&mut self) {
+        let selected = self
+            .list
+            .state
+            .selected()
+            .and_then(|i| self.list.items.get(i))
+            .map(|i| i.path.clone());
+        self.list = StatefulList::new(
+            self.changes
+                .iter()
+                .filter(|i| self.filter.matches(i))
+                .cloned()
+                .collect(),
+        );
+        match selected.and_then(|path| self.list.items.iter().position(|i| i.path == path)) {
+            Some(index) => self.list.state.select(Some(index)),
+            None => self.list.start(),
+        }
+    }
+
+    /// The number of changes of a single kind, ignoring the filter
+    fn count(&self, kind: ChangeKind) -> usize {
+        self.changes.iter().filter(|i| i.kind == kind).count()
+    }
+
+    /// The container name, count of each kind, the filter, and the selected position, for the panel title
+    pub fn get_title(&self) -> String {
+        format!(
+            "({}) {} added, {} changed, {} deleted{} - {}",
+            self.name,
+            self.count(ChangeKind::Added),
+            self.count(ChangeKind::Modified),
+            self.count(ChangeKind::Deleted),
+            self.filter,
+            self.list.get_state_title()
+        )
+    }
+
+    pub const fn list_state(&mut self) -> &mut ListState {
+        &mut self.list.state
+    }
+}
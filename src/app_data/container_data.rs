@@ -1,20 +1,29 @@
 use core::fmt;
+use std::collections::{HashMap, HashSet};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use bollard::models::ContainerSummary;
 use ratatui::widgets::{ListItem, ListState};
 
 use crate::app_data::container_state::{
-    ByteStats, Columns, ContainerId, ContainerItem, CpuStats, CpuTuple, LogsTz, MemTuple, State,
+    ByteStats, Columns, ContainerDetails, ContainerId, ContainerItem, ContainerNetwork,
+    ContainerRow, CpuStats, CpuTuple, LogsTz, MemTuple, ProjectSummary, State,
+    COMPOSE_CONFIG_FILES,
 };
-use crate::{parse_args::CliArgs, ui::log_sanitizer, ENTRY_POINT};
+use crate::app_data::network_state::NetworkContainer;
+use crate::{parse_args::CliArgs, ui::log_sanitizer};
 
 use super::statefull_list::StatefulList;
 
 /// Global app_state, stored in an Arc<Mutex>
 #[derive(Debug, Clone)]
 pub struct ContainerData {
+    collapsed: HashSet<String>,
     containers: StatefulList<ContainerItem>,
+    group_state: ListState,
+    grouped: bool,
+    pending_select: Option<ContainerId>,
+    selected_project: Option<String>,
     sorted_by: Option<(Header, SortedOrder)>,
     pub args: CliArgs,
 }
@@ -61,21 +70,20 @@ impl ContainerData {
     pub fn new(args: CliArgs) -> Self {
         Self {
             args,
+            collapsed: HashSet::new(),
             containers: StatefulList::new(vec![]),
+            group_state: ListState::default(),
+            grouped: false,
+            pending_select: None,
+            selected_project: None,
             sorted_by: None,
         }
     }
 
-    /// Change the sorted order, also set the selected container state to match new order
+    /// Change the sorted order, sort_containers keeps the same container selected
     fn set_sorted(&mut self, x: Option<(Header, SortedOrder)>) {
         self.sorted_by = x;
         self.sort_containers();
-        self.containers
-            .state
-            .select(self.containers.items.iter().position(|i| {
-                self.get_selected_container_id()
-                    .map_or(false, |id| i.id == id)
-            }));
     }
 
     /// Container sort related methods
@@ -105,7 +113,9 @@ impl ContainerData {
 
     /// Sort the containers vec, based on a heading, either ascending or descending,
     /// If not sort set, then sort by created time
+    /// When grouped, the containers are then ordered by compose project, with containers not in a project last
     pub fn sort_containers(&mut self) {
+        let selected_id = self.get_selected_index_id();
         if let Some((head, ord)) = self.sorted_by {
             match head {
                 Header::State => match ord {
@@ -176,6 +186,168 @@ impl ContainerData {
                 .items
                 .sort_by(|a, b| a.created.cmp(&b.created));
         }
+        if self.grouped {
+            // sort_by is stable, so within each project the containers keep the order from above
+            self.containers.items.sort_by(|a, b| {
+                (a.project().is_none(), a.project()).cmp(&(b.project().is_none(), b.project()))
+            });
+        }
+        if selected_id.is_some() {
+            self.containers.state.select(
+                self.containers
+                    .items
+                    .iter()
+                    .position(|i| selected_id.as_ref() == Some(&i.id)),
+            );
+        }
+    }
+
+    /// The id of the container at the selected index, ignoring if a project header is selected
+    fn get_selected_index_id(&self) -> Option<ContainerId> {
+        self.containers
+            .state
+            .selected()
+            .and_then(|i| self.containers.items.get(i))
+            .map(|i| i.id.clone())
+    }
+
+    // Compose project grouping related methods
+
+    pub const fn is_grouped(&self) -> bool {
+        self.grouped
+    }
+
+    /// Switch between a flat list of containers, and containers grouped by compose project
+    pub fn toggle_grouped(&mut self) {
+        self.grouped = !self.grouped;
+        self.selected_project = None;
+        self.sort_containers();
+    }
+
+    /// Get the rows to display, a header for each compose project, followed by its containers, unless collapsed
+    pub fn get_rows(&self) -> Vec<ContainerRow> {
+        if !self.grouped {
+            return (0..self.containers.items.len())
+                .map(ContainerRow::Container)
+                .collect();
+        }
+        let mut rows = vec![];
+        let mut current = None;
+        for (index, container) in self.containers.items.iter().enumerate() {
+            if let Some(project) = container.project() {
+                let collapsed = self.collapsed.contains(project);
+                if current != Some(project) {
+                    current = Some(project);
+                    rows.push(ContainerRow::Project(ProjectSummary::new(
+                        project,
+                        collapsed,
+                        self.containers
+                            .items
+                            .iter()
+                            .filter(|i| i.project() == Some(project)),
+                    )));
+                }
+                if collapsed {
+                    continue;
+                }
+            }
+            rows.push(ContainerRow::Container(index));
+        }
+        rows
+    }
+
+    /// Index of the selected row, None if the selected container is hidden in a collapsed project
+    fn get_selected_row(&self, rows: &[ContainerRow]) -> Option<usize> {
+        rows.iter().position(|row| match row {
+            ContainerRow::Project(project) => self.selected_project.as_ref() == Some(&project.name),
+            ContainerRow::Container(index) => {
+                self.selected_project.is_none() && self.containers.state.selected() == Some(*index)
+            }
+        })
+    }
+
+    /// Select a project header, the container state is set to the first container of the project, so that it is never None
+    fn select_project(&mut self, project: String) {
+        let first = self
+            .containers
+            .items
+            .iter()
+            .position(|i| i.project() == Some(project.as_str()));
+        self.containers.state.select(first);
+        self.selected_project = Some(project);
+    }
+
+    /// Select a row, based on the currently selected row index and the index of the last row
+    fn select_row(&mut self, index: impl FnOnce(Option<usize>, usize) -> usize) {
+        let rows = self.get_rows();
+        if rows.is_empty() {
+            return;
+        }
+        match rows.get(index(self.get_selected_row(&rows), rows.len() - 1)) {
+            Some(ContainerRow::Project(project)) => self.select_project(project.name.clone()),
+            Some(ContainerRow::Container(index)) => {
+                self.selected_project = None;
+                self.containers.state.select(Some(*index));
+            }
+            None => (),
+        }
+    }
+
+    /// Expand, or collapse, the selected project, or the project of the selected container
+    /// The project header is then selected, so the selection is never hidden
+    pub fn toggle_collapsed(&mut self) {
+        if !self.grouped {
+            return;
+        }
+        let project = self.selected_project.clone().or_else(|| {
+            self.get_selected_container()
+                .and_then(ContainerItem::project)
+                .map(ToOwned::to_owned)
+        });
+        if let Some(project) = project {
+            if !self.collapsed.remove(&project) {
+                self.collapsed.insert(project.clone());
+            }
+            self.select_project(project);
+        }
+    }
+
+    /// Get the name of the selected compose project, only Some when a project header is selected
+    pub fn get_selected_project(&self) -> Option<String> {
+        self.selected_project.clone()
+    }
+
+    /// Get the ids of the containers in a compose project, that are in a state accepted by the filter
+    pub fn get_project_ids(
+        &self,
+        project: &str,
+        filter: impl Fn(State) -> bool,
+    ) -> Vec<ContainerId> {
+        self.containers
+            .items
+            .iter()
+            .filter(|i| i.project() == Some(project) && filter(i.state))
+            .map(|i| i.id.clone())
+            .collect()
+    }
+
+    /// Get the compose file a project was created from, if docker compose recorded several, use the first
+    pub fn get_project_file(&self, project: &str) -> Option<String> {
+        self.containers
+            .items
+            .iter()
+            .filter(|i| i.project() == Some(project))
+            .find_map(|i| i.labels.get(COMPOSE_CONFIG_FILES))
+            .and_then(|i| i.split(',').next())
+            .map(ToOwned::to_owned)
+    }
+
+    /// Get ListState of the grouped rows, with the selected row set
+    pub fn get_group_state(&mut self) -> &mut ListState {
+        let rows = self.get_rows();
+        let selected = self.get_selected_row(&rows);
+        self.group_state.select(selected);
+        &mut self.group_state
     }
 
     /// Container state methods
@@ -190,24 +362,45 @@ impl ContainerData {
         self.containers.get_state_title()
     }
 
-    /// Select the first container
+    /// Select the first container, or row if grouped
     pub fn containers_start(&mut self) {
-        self.containers.start();
+        if self.grouped {
+            self.select_row(|_, _| 0);
+        } else {
+            self.containers.start();
+        }
     }
 
-    /// select the last container
+    /// select the last container, or row if grouped
     pub fn containers_end(&mut self) {
-        self.containers.end();
+        if self.grouped {
+            self.select_row(|_, last| last);
+        } else {
+            self.containers.end();
+        }
     }
 
-    /// Select the next container
+    /// Select the next container, or row if grouped
     pub fn containers_next(&mut self) {
-        self.containers.next();
+        if self.grouped {
+            self.select_row(|current, last| current.map_or(0, |i| (i + 1).min(last)));
+        } else {
+            self.containers.next();
+        }
     }
 
-    /// select the previous container
+    /// select the previous container, or row if grouped
     pub fn containers_previous(&mut self) {
-        self.containers.previous();
+        if self.grouped {
+            self.select_row(|current, _| current.map_or(0, |i| i.saturating_sub(1)));
+        } else {
+            self.containers.previous();
+        }
+    }
+
+    /// Select a container once it's in the containers list, e.g. a container that has just been created
+    pub fn select_when_present(&mut self, id: ContainerId) {
+        self.pending_select = Some(id);
     }
 
     /// Get Container items
@@ -215,11 +408,12 @@ impl ContainerData {
         &self.containers.items
     }
 
-    /// Get Option of the current selected container
+    /// Get Option of the current selected container, None if a project header is selected
     pub fn get_selected_container(&self) -> Option<&ContainerItem> {
         self.containers
             .state
             .selected()
+            .filter(|_| self.selected_project.is_none())
             .and_then(|i| self.containers.items.get(i))
     }
 
@@ -350,13 +544,6 @@ impl ContainerData {
             .map(|i| &mut i.info.state)
     }
 
-    /// Check if the selected container is a dockerised version of oxker
-    /// So that can disallow commands to be send
-    /// Is a shabby way of implementing this
-    pub fn is_oxker(&self) -> bool {
-        self.get_selected_container().map_or(false, |i| i.is_oxker)
-    }
-
     /// Check if the initial parsing has been completed, by making sure that all ids given (which are running) have a non empty cpu_stats vecdec
     pub fn initialised(&mut self, all_ids: &[(bool, ContainerId)]) -> bool {
         let count_is_running = all_ids.iter().filter(|i| i.0).count();
@@ -433,6 +620,47 @@ impl ContainerData {
         self.get_selected_container().map(|i| i.name.clone())
     }
 
+    /// Count the number of containers using each image id
+    pub fn get_image_usage(&self) -> HashMap<String, usize> {
+        let mut usage = HashMap::new();
+        for container in &self.containers.items {
+            *usage.entry(container.image_id.clone()).or_insert(0) += 1;
+        }
+        usage
+    }
+
+    /// Get the names of the containers mounting each volume, by volume name
+    pub fn get_volume_usage(&self) -> HashMap<String, Vec<String>> {
+        let mut usage = HashMap::<String, Vec<String>>::new();
+        for container in &self.containers.items {
+            for volume in &container.volumes {
+                usage
+                    .entry(volume.clone())
+                    .or_default()
+                    .push(container.name.clone());
+            }
+        }
+        usage
+    }
+
+    /// Get the containers attached to each network, by network name
+    pub fn get_network_usage(&self) -> HashMap<String, Vec<NetworkContainer>> {
+        let mut usage = HashMap::<String, Vec<NetworkContainer>>::new();
+        for container in &self.containers.items {
+            for network in &container.networks {
+                usage
+                    .entry(network.name.clone())
+                    .or_default()
+                    .push(NetworkContainer {
+                        name: container.name.clone(),
+                        ip: network.ip.clone(),
+                        aliases: network.aliases.clone(),
+                    });
+            }
+        }
+        usage
+    }
+
     /// Update container mem, cpu, & network stats, in single function so only need to call .lock() once
     /// Will also, if a sort is set, sort the containers
     pub fn update_stats(
@@ -535,11 +763,6 @@ impl ContainerData {
                     })
                 });
 
-                let is_oxker = i
-                    .command
-                    .as_ref()
-                    .map_or(false, |i| i.starts_with(ENTRY_POINT));
-
                 let state = State::from(i.state.as_ref().map_or("dead".to_owned(), trim_owned));
                 let status = i.status.as_ref().map_or(String::new(), trim_owned);
 
@@ -548,6 +771,37 @@ impl ContainerData {
                     .as_ref()
                     .map_or(String::new(), std::clone::Clone::clone);
 
+                let image_id = i.image_id.as_ref().map_or(String::new(), trim_owned);
+
+                // Names of the volumes mounted, bind mounts don't have a name
+                let volumes = i.mounts.as_ref().map_or(vec![], |mounts| {
+                    mounts.iter().filter_map(|m| m.name.clone()).collect()
+                });
+
+                // Networks attached, sorted by name, an ipv6 only network has no ipv4 address
+                let mut networks = i
+                    .network_settings
+                    .as_ref()
+                    .and_then(|i| i.networks.as_ref())
+                    .map_or(vec![], |networks| {
+                        networks
+                            .iter()
+                            .map(|(name, endpoint)| ContainerNetwork {
+                                name: name.clone(),
+                                ip: endpoint
+                                    .ip_address
+                                    .clone()
+                                    .filter(|i| !i.is_empty())
+                                    .or_else(|| endpoint.global_ipv6_address.clone())
+                                    .unwrap_or_default(),
+                                aliases: endpoint.aliases.clone().unwrap_or_default(),
+                            })
+                            .collect::<Vec<_>>()
+                    });
+                networks.sort_by(|a, b| a.name.cmp(&b.name));
+
+                let labels = i.labels.clone().unwrap_or_default();
+
                 let id = ContainerId::from(id);
 
                 let created = i
@@ -567,19 +821,67 @@ impl ContainerData {
                     if item.image != image {
                         item.image = image;
                     };
+                    if item.image_id != image_id {
+                        item.image_id = image_id;
+                    }
+                    if item.volumes != volumes {
+                        item.volumes = volumes;
+                    }
+                    if item.networks != networks {
+                        item.networks = networks;
+                    }
+                    if item.labels != labels {
+                        item.labels = labels;
+                    }
                 } else {
                     // container not known, so make new ContainerItem and push into containers Vec
-                    let container =
-                        ContainerItem::new(created, id, image, is_oxker, name, state, status);
+                    let container = ContainerItem::new(ContainerDetails {
+                        created,
+                        id,
+                        image,
+                        image_id,
+                        labels,
+                        name,
+                        networks,
+                        state,
+                        status,
+                        volumes,
+                    });
                     self.containers.items.push(container);
                 }
             }
         }
+
+        // A newly created container has appeared, so select it, expanding its project if grouped
+        let pending = self
+            .pending_select
+            .as_ref()
+            .and_then(|id| self.containers.items.iter().position(|i| &i.id == id));
+        if let Some(index) = pending {
+            if let Some(project) = self.containers.items[index].project() {
+                self.collapsed.remove(project);
+            }
+            self.containers.state.select(Some(index));
+            self.selected_project = None;
+            self.pending_select = None;
+        }
+
+        // The selected project no longer has any containers
+        if let Some(project) = self.selected_project.as_deref() {
+            if !self
+                .containers
+                .items
+                .iter()
+                .any(|i| i.project() == Some(project))
+            {
+                self.selected_project = None;
+            }
+        }
     }
 
     /// Current time as unix timestamp
     #[allow(clippy::expect_used)]
-    fn get_systemtime() -> u64 {
+    pub fn get_systemtime() -> u64 {
         SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .expect("In our known reality, this error should never occur")
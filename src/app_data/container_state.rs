@@ -1,6 +1,6 @@
 use std::{
     cmp::Ordering,
-    collections::{HashSet, VecDeque},
+    collections::{HashMap, HashSet, VecDeque},
     fmt,
 };
 
@@ -217,6 +217,9 @@ impl ByteStats {
     pub const fn new(value: u64) -> Self {
         Self(value)
     }
+    pub const fn get(self) -> u64 {
+        self.0
+    }
     pub fn update(&mut self, value: u64) {
         self.0 = value;
     }
@@ -326,6 +329,435 @@ impl Logs {
     }
 }
 
+/// Label that docker compose adds to each container, with the name of its project
+pub const COMPOSE_PROJECT: &str = "com.docker.compose.project";
+/// Label that docker compose adds to each container, with the name of its service
+pub const COMPOSE_SERVICE: &str = "com.docker.compose.service";
+/// Label that docker compose adds to each container, with the path of the compose file it was created from
+pub const COMPOSE_CONFIG_FILES: &str = "com.docker.compose.project.config_files";
+
+/// What to do with the services of a compose file
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum ComposeAction {
+    Up,
+    Down,
+    Recreate(String),
+}
+
+/// Split a command string into arguments, single and double quotes group words together
+pub fn split_command(input: &str) -> Vec<String> {
+    let mut output = vec![];
+    let mut current = String::new();
+    let mut quote = None;
+    let mut in_word = false;
+    for c in input.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), c) => current.push(c),
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                in_word = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if in_word {
+                    output.push(std::mem::take(&mut current));
+                    in_word = false;
+                }
+            }
+            (None, c) => {
+                current.push(c);
+                in_word = true;
+            }
+        }
+    }
+    if in_word {
+        output.push(current);
+    }
+    output
+}
+
+/// Join arguments into a command string, the reverse of split_command, arguments with whitespace, or quotes, are quoted
+pub fn join_command(args: &[String]) -> String {
+    args.iter()
+        .map(|i| {
+            if i.is_empty() || i.contains(char::is_whitespace) || i.contains('\'') {
+                format!("\"{i}\"")
+            } else if i.contains('"') {
+                format!("'{i}'")
+            } else {
+                i.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Parse a size, as used for memory limits, e.g. `128M`, `1.5gb`, or a number of bytes
+pub fn parse_bytes(text: &str) -> Option<i64> {
+    let text = text.to_lowercase();
+    let text = text.trim().trim_end_matches('b');
+    let (number, multiplier) = match text.chars().last()? {
+        'k' => (&text[..text.len() - 1], 1024.0),
+        'm' => (&text[..text.len() - 1], 1024.0 * 1024.0),
+        'g' => (&text[..text.len() - 1], 1024.0 * 1024.0 * 1024.0),
+        _ => (text, 1.0),
+    };
+    let bytes = number.trim().parse::<f64>().ok()? * multiplier;
+    #[allow(clippy::cast_possible_truncation)]
+    Some(bytes as i64)
+}
+
+/// Parse a number of cpus, e.g. `1.5`, into the billionths of a cpu that docker uses
+pub fn parse_cpus(text: &str) -> Option<i64> {
+    let cpus = text.trim().parse::<f64>().ok().filter(|i| *i > 0.0)?;
+    #[allow(clippy::cast_possible_truncation)]
+    Some((cpus * 1_000_000_000.0) as i64)
+}
+
+/// The signals offered when killing a container, or a process in a container
+pub const SIGNALS: &[&str] = &[
+    "SIGTERM", "SIGKILL", "SIGHUP", "SIGINT", "SIGQUIT", "SIGUSR1", "SIGUSR2",
+];
+
+/// The number of each of the named SIGNALS, on linux
+fn signal_number(name: &str) -> Option<u8> {
+    match name {
+        "SIGHUP" => Some(1),
+        "SIGINT" => Some(2),
+        "SIGQUIT" => Some(3),
+        "SIGKILL" => Some(9),
+        "SIGUSR1" => Some(10),
+        "SIGUSR2" => Some(12),
+        "SIGTERM" => Some(15),
+        _ => None,
+    }
+}
+
+/// A signal to send, either one of the named SIGNALS, or any other signal by its number
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct Signal(u8);
+
+impl Signal {
+    /// A custom number, if not empty, is used instead of the named signal, None if either isn't valid
+    pub fn new(name: &str, number: &str) -> Option<Self> {
+        if number.is_empty() {
+            signal_number(name).map(Self)
+        } else {
+            number
+                .parse::<u8>()
+                .ok()
+                .filter(|i| (1..=64).contains(i))
+                .map(Self)
+        }
+    }
+
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+}
+
+/// The name of the signal, if it's one of the named SIGNALS, else its number
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match SIGNALS.iter().find(|i| signal_number(i) == Some(self.0)) {
+            Some(name) => write!(f, "{name}"),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+/// A port to publish, `container` includes the protocol, e.g. `80/tcp`, without a host port docker picks a random one
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct RunPort {
+    pub container: String,
+    pub host_ip: Option<String>,
+    pub host_port: Option<String>,
+}
+
+impl RunPort {
+    /// Parse `[[host_ip:]host_port:]container_port[/protocol]`, None if the container port isn't a number
+    fn new(text: &str) -> Option<Self> {
+        let (text, protocol) = text.split_once('/').unwrap_or((text, "tcp"));
+        let mut parts = text.rsplitn(3, ':');
+        let container = parts.next()?.parse::<u16>().ok()?;
+        let host_port = parts.next().filter(|i| !i.is_empty());
+        if host_port.is_some_and(|i| i.parse::<u16>().is_err()) {
+            return None;
+        }
+        Some(Self {
+            container: format!("{container}/{protocol}"),
+            host_port: host_port.map(ToOwned::to_owned),
+            host_ip: parts.next().map(ToOwned::to_owned),
+        })
+    }
+}
+
+/// Display as `[[host_ip:]host_port:]container_port[/protocol]`, the protocol is only shown if it's not tcp
+impl fmt::Display for RunPort {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let container = self.container.trim_end_matches("/tcp");
+        match (&self.host_ip, &self.host_port) {
+            (Some(ip), Some(port)) if !ip.is_empty() && !port.is_empty() => {
+                write!(f, "{ip}:{port}:{container}")
+            }
+            (_, Some(port)) if !port.is_empty() => write!(f, "{port}:{container}"),
+            _ => write!(f, "{container}"),
+        }
+    }
+}
+
+/// The inputs of a new container, created and then started, like `docker run -d`
+/// Empty inputs are left for docker, or the image, to decide
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct ContainerRun {
+    pub image: String,
+    pub name: Option<String>,
+    pub command: Vec<String>,
+    pub env: Vec<String>,
+    pub ports: Vec<RunPort>,
+    pub volumes: Vec<String>,
+    pub network: Option<String>,
+    pub restart: String,
+    pub memory: Option<i64>,
+    pub nano_cpus: Option<i64>,
+}
+
+impl ContainerRun {
+    /// The command is split like a shell would, returns None if the image is empty
+    pub fn new(image: &str, name: &str, command: &str) -> Option<Self> {
+        if image.is_empty() {
+            return None;
+        }
+        Some(Self {
+            image: image.to_owned(),
+            name: Some(name.to_owned()).filter(|i| !i.is_empty()),
+            command: split_command(command),
+            env: vec![],
+            ports: vec![],
+            volumes: vec![],
+            network: None,
+            restart: String::from("no"),
+            memory: None,
+            nano_cpus: None,
+        })
+    }
+
+    /// `env` is a whitespace separated list of `KEY=VALUE` pairs, returns None if a pair doesn't have a value
+    pub fn env(mut self, env: &str) -> Option<Self> {
+        self.env = split_command(env);
+        if self.env.iter().any(|i| !i.contains('=')) {
+            return None;
+        }
+        Some(self)
+    }
+
+    /// `ports` is a whitespace separated list, as used by `docker run -p`, returns None if a port is invalid
+    pub fn ports(mut self, ports: &str) -> Option<Self> {
+        self.ports = ports
+            .split_whitespace()
+            .map(RunPort::new)
+            .collect::<Option<Vec<_>>>()?;
+        Some(self)
+    }
+
+    /// `volumes` is a whitespace separated list, as used by `docker run -v`, either `source:target[:mode]`, or just a target for an anonymous volume
+    pub fn volumes(mut self, volumes: &str) -> Self {
+        self.volumes = volumes.split_whitespace().map(ToOwned::to_owned).collect();
+        self
+    }
+
+    /// The network to attach to, and the restart policy, `on-failure` can have a maximum retry count, e.g. `on-failure:3`
+    pub fn network(mut self, network: &str, restart: &str) -> Self {
+        self.network = Some(network.to_owned()).filter(|i| !i.is_empty());
+        if !restart.is_empty() {
+            restart.clone_into(&mut self.restart);
+        }
+        self
+    }
+
+    /// Memory, e.g. `512m`, and cpus, e.g. `1.5`, limits, returns None if a limit can't be parsed
+    pub fn limits(mut self, memory: &str, cpus: &str) -> Option<Self> {
+        if !memory.is_empty() {
+            self.memory = Some(parse_bytes(memory)?);
+        }
+        if !cpus.is_empty() {
+            self.nano_cpus = Some(parse_cpus(cpus)?);
+        }
+        Some(self)
+    }
+
+    /// The ports, as they would be given to `docker run -p`, whitespace separated
+    pub fn ports_text(&self) -> String {
+        self.ports
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// The memory limit, in megabytes if it's a whole number of them, else in bytes, empty if not limited
+    pub fn memory_text(&self) -> String {
+        const MB: i64 = 1024 * 1024;
+        match self.memory {
+            Some(x) if x > 0 && x % MB == 0 => format!("{}m", x / MB),
+            Some(x) if x > 0 => x.to_string(),
+            _ => String::new(),
+        }
+    }
+
+    /// The cpu limit, as a number of cpus, empty if not limited
+    #[allow(clippy::cast_precision_loss)]
+    pub fn cpus_text(&self) -> String {
+        self.nano_cpus
+            .filter(|i| *i > 0)
+            .map_or_else(String::new, |i| (i as f64 / 1_000_000_000.0).to_string())
+    }
+}
+
+/// A container that has been recreated, but the new container failed to start, the original container is kept, stopped, with the backup name
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct ContainerBackup {
+    pub name: String,
+    pub backup: String,
+    pub new: ContainerId,
+    pub running: bool,
+}
+
+/// The inputs of a container commit, the config is merged with the config of the container, so empty values are kept from the container
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ContainerCommit {
+    pub tag: String,
+    pub cmd: Vec<String>,
+    pub entrypoint: Vec<String>,
+    pub env: Vec<String>,
+    pub labels: HashMap<String, String>,
+    pub exposed_ports: Vec<String>,
+    pub recreate: bool,
+}
+
+impl ContainerCommit {
+    /// `cmd` and `entrypoint` are split like a shell would, `env`, `labels`, and `exposed_ports` are whitespace separated lists
+    /// Returns None if the tag is empty, an env or label doesn't have a value, or a port isn't `port[/protocol]`
+    pub fn new(
+        tag: &str,
+        cmd: &str,
+        entrypoint: &str,
+        env: &str,
+        labels: &str,
+        exposed_ports: &str,
+        recreate: bool,
+    ) -> Option<Self> {
+        if tag.is_empty() {
+            return None;
+        }
+        let env = split_command(env);
+        if env.iter().any(|i| !i.contains('=')) {
+            return None;
+        }
+        let labels = split_command(labels)
+            .iter()
+            .map(|i| {
+                i.split_once('=')
+                    .map(|(key, value)| (key.to_owned(), value.to_owned()))
+            })
+            .collect::<Option<HashMap<_, _>>>()?;
+        let exposed_ports = exposed_ports
+            .split_whitespace()
+            .map(|i| {
+                let (port, protocol) = i.split_once('/').unwrap_or((i, "tcp"));
+                port.parse::<u16>()
+                    .ok()
+                    .map(|port| format!("{port}/{protocol}"))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self {
+            tag: tag.to_owned(),
+            cmd: split_command(cmd),
+            entrypoint: split_command(entrypoint),
+            env,
+            labels,
+            exposed_ports,
+            recreate,
+        })
+    }
+}
+
+/// A compose project, as shown in the grouped containers panel
+/// The number of containers in each state, and the total cpu & memory of all its containers
+#[derive(Debug, Clone)]
+pub struct ProjectSummary {
+    pub name: String,
+    pub collapsed: bool,
+    pub services: usize,
+    pub states: Vec<(State, usize)>,
+    pub cpu: CpuStats,
+    pub mem: ByteStats,
+}
+
+impl ProjectSummary {
+    /// Create a summary from all of the containers of a project
+    pub fn new<'a>(
+        name: &str,
+        collapsed: bool,
+        containers: impl Iterator<Item = &'a ContainerItem>,
+    ) -> Self {
+        let mut services = HashSet::new();
+        let mut states = Vec::<(State, usize)>::new();
+        let mut cpu = 0.0;
+        let mut mem = 0;
+        for container in containers {
+            services.insert(container.service().unwrap_or_default());
+            if let Some((_, count)) = states.iter_mut().find(|(i, _)| *i == container.state) {
+                *count += 1;
+            } else {
+                states.push((container.state, 1));
+            }
+            cpu += container.cpu_stats.back().map_or(0.0, Stats::get_value);
+            mem += container.mem_stats.back().map_or(0, |i| i.get());
+        }
+        states.sort_by_key(|(state, _)| state.order());
+        Self {
+            name: name.to_owned(),
+            collapsed,
+            services: services.len(),
+            states,
+            cpu: CpuStats::new(cpu),
+            mem: ByteStats::new(mem),
+        }
+    }
+
+    /// Number of services, e.g. `3 services`
+    pub fn services_text(&self) -> String {
+        let plural = if self.services == 1 { "" } else { "s" };
+        format!("{} service{plural}", self.services)
+    }
+
+    /// Number of containers in each state, e.g. `2 ✓ running, 1 ✖ exited`
+    pub fn states_text(&self) -> String {
+        self.states
+            .iter()
+            .map(|(state, count)| format!("{count} {state}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// A single line of the containers panel, when grouped by compose project
+/// Container holds the index of the container in the containers list
+#[derive(Debug, Clone)]
+pub enum ContainerRow {
+    Project(ProjectSummary),
+    Container(usize),
+}
+
+/// A network that a container is attached to, with the address and aliases of the container on that network
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ContainerNetwork {
+    pub name: String,
+    pub ip: String,
+    pub aliases: Vec<String>,
+}
+
 /// Info for each container
 #[derive(Debug, Clone)]
 pub struct ContainerItem {
@@ -333,49 +765,72 @@ pub struct ContainerItem {
     pub cpu_stats: VecDeque<CpuStats>,
     pub id: ContainerId,
     pub image: String,
+    pub image_id: String,
+    pub labels: HashMap<String, String>,
     pub last_updated: u64,
     pub logs: Logs,
     pub mem_limit: ByteStats,
     pub mem_stats: VecDeque<ByteStats>,
     pub name: String,
+    pub networks: Vec<ContainerNetwork>,
     pub rx: ByteStats,
     pub state: State,
     pub status: String,
     pub tx: ByteStats,
-    pub is_oxker: bool,
+    pub volumes: Vec<String>,
     pub info: StatefulList<ListItem<'static>>,
 }
 
+/// The details of a container, as read from the docker container list, used to create a new container item
+#[derive(Debug, Clone)]
+pub struct ContainerDetails {
+    pub created: u64,
+    pub id: ContainerId,
+    pub image: String,
+    pub image_id: String,
+    pub labels: HashMap<String, String>,
+    pub name: String,
+    pub networks: Vec<ContainerNetwork>,
+    pub state: State,
+    pub status: String,
+    pub volumes: Vec<String>,
+}
+
 impl ContainerItem {
     /// Create a new container item
-    pub fn new(
-        created: u64,
-        id: ContainerId,
-        image: String,
-        is_oxker: bool,
-        name: String,
-        state: State,
-        status: String,
-    ) -> Self {
+    pub fn new(details: ContainerDetails) -> Self {
         Self {
-            created,
+            created: details.created,
             cpu_stats: VecDeque::with_capacity(60),
-            id,
-            image,
-            is_oxker,
+            id: details.id,
+            image: details.image,
+            image_id: details.image_id,
+            labels: details.labels,
             last_updated: 0,
             logs: Logs::default(),
             mem_limit: ByteStats::default(),
             mem_stats: VecDeque::with_capacity(60),
-            name,
+            name: details.name,
+            networks: details.networks,
             rx: ByteStats::default(),
-            state,
-            status,
+            state: details.state,
+            status: details.status,
             tx: ByteStats::default(),
+            volumes: details.volumes,
             info: StatefulList::new(vec![]),
         }
     }
 
+    /// The docker compose project this container is part of, if any
+    pub fn project(&self) -> Option<&str> {
+        self.labels.get(COMPOSE_PROJECT).map(String::as_str)
+    }
+
+    /// The docker compose service this container runs, if any
+    pub fn service(&self) -> Option<&str> {
+        self.labels.get(COMPOSE_SERVICE).map(String::as_str)
+    }
+
     /// Find the max value in the cpu stats VecDeque
     fn max_cpu_stats(&self) -> CpuStats {
         self.cpu_stats
@@ -0,0 +1,70 @@
+/// What to save an export as, the `docker run` command, or the compose service
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ExportFormat {
+    Run,
+    Compose,
+}
+
+/// A container, converted to an equivalent `docker run` command, and compose service, shown in a scrollable popup
+#[derive(Debug, Clone)]
+pub struct ContainerExport {
+    pub name: String,
+    pub run: String,
+    pub compose: String,
+    offset: usize,
+}
+
+impl ContainerExport {
+    pub const fn new(name: String, run: String, compose: String) -> Self {
+        Self {
+            name,
+            run,
+            compose,
+            offset: 0,
+        }
+    }
+
+    /// The lines to show, the run command, followed by the compose service, each with a comment heading
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = vec![String::from("# docker run")];
+        lines.extend(self.run.lines().map(ToOwned::to_owned));
+        lines.push(String::new());
+        lines.push(String::from("# docker-compose.yml"));
+        lines.extend(self.compose.lines().map(ToOwned::to_owned));
+        lines
+    }
+
+    /// The text to save to a file
+    pub fn text(&self, format: ExportFormat) -> String {
+        match format {
+            ExportFormat::Run => format!("{}\n", self.run),
+            ExportFormat::Compose => self.compose.clone(),
+        }
+    }
+
+    /// Index of the first line to show
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Scroll down by a number of lines, stopping at the last line
+    pub fn scroll_down(&mut self, lines: usize) {
+        let last = self.lines().len().saturating_sub(1);
+        self.offset = self.offset.saturating_add(lines).min(last);
+    }
+
+    /// Scroll up by a number of lines, stopping at the first line
+    pub const fn scroll_up(&mut self, lines: usize) {
+        self.offset = self.offset.saturating_sub(lines);
+    }
+
+    /// Scroll to the first line
+    pub const fn scroll_start(&mut self) {
+        self.offset = 0;
+    }
+
+    /// Scroll to the last line
+    pub fn scroll_end(&mut self) {
+        self.offset = self.lines().len().saturating_sub(1);
+    }
+}
@@ -0,0 +1,212 @@
+use std::fmt;
+
+use ratatui::widgets::{ListItem, ListState};
+
+use crate::app_data::{
+    container_state::{ByteStats, ContainerId},
+    image_state::Created,
+    statefull_list::StatefulList,
+};
+
+/// The type of an entry in a directory listing
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum FileKind {
+    Directory,
+    File,
+    Symlink(String),
+    Other,
+}
+
+/// A single entry of a directory listing, read from the output of `stat`, or the headers of a tar archive
+#[derive(Debug, Clone)]
+pub struct FileEntry {
+    pub name: String,
+    pub kind: FileKind,
+    pub mode: u32,
+    pub modified: Created,
+    pub size: ByteStats,
+}
+
+impl FileEntry {
+    pub const fn is_directory(&self) -> bool {
+        matches!(self.kind, FileKind::Directory)
+    }
+
+    /// Permissions in the same format as `ls -l`, e.g. `drwxr-xr-x`
+    pub fn mode_text(&self) -> String {
+        let kind = match self.kind {
+            FileKind::Directory => 'd',
+            FileKind::Symlink(_) => 'l',
+            FileKind::File => '-',
+            FileKind::Other => '?',
+        };
+        let perms = ['r', 'w', 'x']
+            .iter()
+            .cycle()
+            .take(9)
+            .enumerate()
+            .map(|(index, c)| {
+                if self.mode & (0o400 >> index) == 0 {
+                    '-'
+                } else {
+                    *c
+                }
+            })
+            .collect::<String>();
+        format!("{kind}{perms}")
+    }
+}
+
+/// Name with a trailing `/` for directories, and the target of symlinks, as shown by `ls -F`
+impl fmt::Display for FileEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            FileKind::Directory => write!(f, "{}/", self.name),
+            FileKind::Symlink(target) => write!(f, "{} -> {target}", self.name),
+            FileKind::File | FileKind::Other => write!(f, "{}", self.name),
+        }
+    }
+}
+
+/// File type bits of a unix mode
+const TYPE_MASK: u32 = 0o170_000;
+const TYPE_DIRECTORY: u32 = 0o040_000;
+const TYPE_FILE: u32 = 0o100_000;
+const TYPE_SYMLINK: u32 = 0o120_000;
+
+/// Parse a directory listing made inside a container, each entry is three NUL terminated fields
+/// The first is the output of `stat -c '%f %s %Y'`, the raw mode in hex, the size, and the modified timestamp, then the name, then the target if it's a symlink
+pub fn parse_listing(output: &str) -> Vec<FileEntry> {
+    let fields = output.split('\0').collect::<Vec<_>>();
+    fields
+        .chunks_exact(3)
+        .filter_map(|entry| {
+            let mut stat = entry[0].split_whitespace();
+            let mode = u32::from_str_radix(stat.next()?, 16).ok()?;
+            let size = stat.next()?.parse().ok()?;
+            let modified = stat.next()?.parse().ok()?;
+            let kind = match mode & TYPE_MASK {
+                TYPE_DIRECTORY => FileKind::Directory,
+                TYPE_FILE => FileKind::File,
+                TYPE_SYMLINK => FileKind::Symlink(entry[2].to_owned()),
+                _ => FileKind::Other,
+            };
+            Some(FileEntry {
+                name: entry[1].to_owned(),
+                kind,
+                mode: mode & !TYPE_MASK,
+                modified: Created::new(modified),
+                size: ByteStats::new(size),
+            })
+        })
+        .collect()
+}
+
+/// Join a directory and a name into an absolute path
+pub fn join_path(dir: &str, name: &str) -> String {
+    format!("{}/{name}", dir.trim_end_matches('/'))
+}
+
+/// The parent directory of an absolute path, the root is its own parent
+pub fn parent_path(path: &str) -> String {
+    match path.trim_end_matches('/').rsplit_once('/') {
+        Some((parent, _)) if !parent.is_empty() => parent.to_owned(),
+        _ => String::from("/"),
+    }
+}
+
+/// Browse the filesystem of a container, by listing each directory inside the container, or, if that isn't possible, downloading a tar archive of it
+/// If the container is a helper, created just to mount a volume, it gets removed when the browser is closed
+#[derive(Debug, Clone)]
+pub struct FileBrowser {
+    pub title: String,
+    pub container: ContainerId,
+    pub helper: bool,
+    pub root: String,
+    pub path: String,
+    pub workdir: Option<String>,
+    pub entries: StatefulList<FileEntry>,
+    pub viewing: Option<String>,
+    pub viewer: StatefulList<ListItem<'static>>,
+}
+
+impl FileBrowser {
+    pub fn new(title: String, container: ContainerId, helper: bool, root: &str) -> Self {
+        Self {
+            title,
+            container,
+            helper,
+            root: root.to_owned(),
+            path: root.to_owned(),
+            workdir: None,
+            entries: StatefulList::new(vec![]),
+            viewing: None,
+            viewer: StatefulList::new(vec![]),
+        }
+    }
+
+    /// The current directory, and the number of entries, for the panel title
+    pub fn get_title(&self) -> String {
+        format!(
+            "{} {} - {}",
+            self.title,
+            self.path,
+            self.entries.get_state_title()
+        )
+    }
+
+    /// Replace the entries with the listing of a new directory, directories first, then by name
+    pub fn set_entries(&mut self, path: String, mut entries: Vec<FileEntry>) {
+        entries.sort_by(|a, b| {
+            b.is_directory()
+                .cmp(&a.is_directory())
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        let same_path = self.path == path;
+        let selected = self.entries.state.selected();
+        self.path = path;
+        self.entries = StatefulList::new(entries);
+        if same_path && selected.is_some_and(|i| i < self.entries.items.len()) {
+            self.entries.state.select(selected);
+        } else if !self.entries.items.is_empty() {
+            self.entries.start();
+        }
+    }
+
+    /// Absolute path of the selected entry
+    pub fn get_selected(&self) -> Option<(String, FileEntry)> {
+        self.entries
+            .state
+            .selected()
+            .and_then(|i| self.entries.items.get(i))
+            .map(|i| (join_path(&self.path, &i.name), i.clone()))
+    }
+
+    /// The parent of the current directory, None if already at the root of the browser
+    pub fn get_parent(&self) -> Option<String> {
+        if self.path.trim_end_matches('/') == self.root.trim_end_matches('/') {
+            None
+        } else {
+            Some(parent_path(&self.path))
+        }
+    }
+
+    /// Show the lines of a text file in the viewer
+    pub fn set_viewer(&mut self, path: String, text: &str) {
+        self.viewing = Some(path);
+        self.viewer = StatefulList::new(
+            text.lines()
+                .map(|i| ListItem::new(i.replace('\t', "    ")))
+                .collect(),
+        );
+        self.viewer.start();
+    }
+
+    pub const fn entries_state(&mut self) -> &mut ListState {
+        &mut self.entries.state
+    }
+
+    pub const fn viewer_state(&mut self) -> &mut ListState {
+        &mut self.viewer.state
+    }
+}
@@ -1,9 +1,377 @@
+use core::fmt;
+use std::collections::HashMap;
+
+use bollard::models::{HistoryResponseItem, ImageSummary};
+use ratatui::widgets::{ListItem, ListState};
+
+use crate::app_data::container_data::SortedOrder;
+use crate::app_data::container_state::ByteStats;
+use crate::app_data::image_state::{
+    Created, HistoryLayer, ImageColumns, ImageId, ImageItem, ImagePrune,
+};
+
 use super::statefull_list::StatefulList;
 
-/// Global app_state, stored in an Arc<Mutex>
+/// All local images, stored in the global app_state
 #[derive(Debug, Clone)]
 pub struct ImageData {
-    containers: StatefulList<ContainerItem>,
-    sorted_by: Option<(Header, SortedOrder)>,
-    pub args: CliArgs,
-}
\ No newline at end of file
+    images: StatefulList<ImageItem>,
+    sorted_by: Option<(ImageHeader, SortedOrder)>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ImageHeader {
+    Name,
+    Id,
+    Size,
+    Created,
+    Containers,
+}
+
+/// Convert ImageHeader enum into strings to display
+impl fmt::Display for ImageHeader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Name => "repo:tag",
+            Self::Id => "id",
+            Self::Size => "size",
+            Self::Created => "created",
+            Self::Containers => "containers",
+        };
+        write!(f, "{disp:>x$}", x = f.width().unwrap_or(1))
+    }
+}
+
+impl ImageData {
+    /// Generate a default image_state
+    pub fn new() -> Self {
+        Self {
+            images: StatefulList::new(vec![]),
+            sorted_by: None,
+        }
+    }
+
+    /// Change the sorted order, also set the selected image state to match new order
+    fn set_sorted(&mut self, x: Option<(ImageHeader, SortedOrder)>) {
+        let selected_id = self.get_selected_image_id();
+        self.sorted_by = x;
+        self.sort_images();
+        self.images.state.select(
+            self.images
+                .items
+                .iter()
+                .position(|i| selected_id.as_ref() == Some(&i.id)),
+        );
+    }
+
+    // Image sort related methods
+
+    /// Remove the sorted header & order, and sort by default - created datetime
+    pub fn reset_sorted(&mut self) {
+        self.set_sorted(None);
+    }
+
+    /// Sort images based on a given header, if headings match, and already ascending, remove sorting
+    pub fn set_sort_by_header(&mut self, selected_header: ImageHeader) {
+        let mut output = Some((selected_header, SortedOrder::Asc));
+        if let Some((current_header, order)) = self.get_sorted() {
+            if current_header == selected_header {
+                match order {
+                    SortedOrder::Desc => output = None,
+                    SortedOrder::Asc => output = Some((selected_header, SortedOrder::Desc)),
+                }
+            }
+        }
+        self.set_sorted(output);
+    }
+
+    pub const fn get_sorted(&self) -> Option<(ImageHeader, SortedOrder)> {
+        self.sorted_by
+    }
+
+    /// Sort the images vec, based on a heading, either ascending or descending,
+    /// If not sort set, then sort by created time, newest first
+    pub fn sort_images(&mut self) {
+        let items = &mut self.images.items;
+        if let Some((head, ord)) = self.sorted_by {
+            match head {
+                ImageHeader::Name => items.sort_by_cached_key(ImageItem::name),
+                ImageHeader::Id => items.sort_by(|a, b| a.id.cmp(&b.id)),
+                ImageHeader::Size => items.sort_by_key(|i| i.size),
+                ImageHeader::Created => items.sort_by_key(|i| i.created),
+                ImageHeader::Containers => items.sort_by_key(|i| i.containers),
+            }
+            if ord == SortedOrder::Desc {
+                items.reverse();
+            }
+        } else {
+            items.sort_by_key(|i| std::cmp::Reverse(i.created));
+        }
+    }
+
+    // Image state methods
+
+    /// Get title for images section
+    pub fn image_title(&self) -> String {
+        self.images.get_state_title()
+    }
+
+    /// Select the first image
+    pub fn images_start(&mut self) {
+        self.images.start();
+    }
+
+    /// select the last image
+    pub fn images_end(&mut self) {
+        self.images.end();
+    }
+
+    /// Select the next image
+    pub fn images_next(&mut self) {
+        self.images.next();
+    }
+
+    /// select the previous image
+    pub fn images_previous(&mut self) {
+        self.images.previous();
+    }
+
+    /// Get Image items
+    pub const fn get_image_items(&self) -> &Vec<ImageItem> {
+        &self.images.items
+    }
+
+    /// Get Option of the current selected image
+    pub fn get_selected_image(&self) -> Option<&ImageItem> {
+        self.images
+            .state
+            .selected()
+            .and_then(|i| self.images.items.get(i))
+    }
+
+    /// Get mutable Option of the current selected image
+    fn get_mut_selected_image(&mut self) -> Option<&mut ImageItem> {
+        self.images
+            .state
+            .selected()
+            .and_then(|i| self.images.items.get_mut(i))
+    }
+
+    /// Find the id of the currently selected image.
+    pub fn get_selected_image_id(&self) -> Option<ImageId> {
+        self.get_selected_image().map(|i| i.id.clone())
+    }
+
+    /// Get the repo:tag of the currently selected image
+    pub fn get_selected_image_name(&self) -> Option<String> {
+        self.get_selected_image().map(ImageItem::name)
+    }
+
+    /// Get ListState of images
+    pub const fn get_image_state(&mut self) -> &mut ListState {
+        &mut self.images.state
+    }
+
+    // Info related methods
+
+    /// select first selected info line
+    pub fn info_start(&mut self) {
+        if let Some(i) = self.get_mut_selected_image() {
+            i.info.start();
+        }
+    }
+
+    /// select next selected info line
+    pub fn info_next(&mut self) {
+        if let Some(i) = self.get_mut_selected_image() {
+            i.info.next();
+        }
+    }
+
+    /// select previous selected info line
+    pub fn info_previous(&mut self) {
+        if let Some(i) = self.get_mut_selected_image() {
+            i.info.previous();
+        }
+    }
+
+    /// select last selected info line
+    pub fn info_end(&mut self) {
+        if let Some(i) = self.get_mut_selected_image() {
+            i.info.end();
+        }
+    }
+
+    /// Get mutable Option of the currently selected image Info state
+    pub fn get_info_state(&mut self) -> Option<&mut ListState> {
+        self.get_mut_selected_image().map(|i| &mut i.info.state)
+    }
+
+    /// Replace the info of a given image with the lines of a yaml string
+    pub fn update_infos(&mut self, id: &ImageId, info: &str) {
+        if let Some(image) = self.get_image_by_id(id) {
+            image.info = StatefulList::new(
+                info.lines()
+                    .map(|l| ListItem::new(l.to_owned()))
+                    .collect::<Vec<_>>(),
+            );
+        }
+    }
+
+    /// Get the info lines of the currently selected image
+    pub fn get_infos(&self) -> Vec<ListItem<'static>> {
+        self.get_selected_image()
+            .map_or(vec![], |i| i.info.items.clone())
+    }
+
+    // History related methods
+
+    /// select first history layer
+    pub fn history_start(&mut self) {
+        if let Some(i) = self.get_mut_selected_image() {
+            i.history.start();
+        }
+    }
+
+    /// select next history layer
+    pub fn history_next(&mut self) {
+        if let Some(i) = self.get_mut_selected_image() {
+            i.history.next();
+        }
+    }
+
+    /// select previous history layer
+    pub fn history_previous(&mut self) {
+        if let Some(i) = self.get_mut_selected_image() {
+            i.history.previous();
+        }
+    }
+
+    /// select last history layer
+    pub fn history_end(&mut self) {
+        if let Some(i) = self.get_mut_selected_image() {
+            i.history.end();
+        }
+    }
+
+    /// Get mutable Option of the currently selected image History state
+    pub fn get_history_state(&mut self) -> Option<&mut ListState> {
+        self.get_mut_selected_image().map(|i| &mut i.history.state)
+    }
+
+    /// Replace the history of a given image, docker returns the newest layer first, so the cumulative size is calculated from the end
+    pub fn update_history(&mut self, id: &ImageId, history: &[HistoryResponseItem]) {
+        if let Some(image) = self.get_image_by_id(id) {
+            let mut cumulative = 0;
+            let mut layers = history
+                .iter()
+                .rev()
+                .map(|i| {
+                    let size = u64::try_from(i.size).unwrap_or_default();
+                    cumulative += size;
+                    HistoryLayer {
+                        created: Created::new(u64::try_from(i.created).unwrap_or_default()),
+                        created_by: i
+                            .created_by
+                            .trim_start_matches("/bin/sh -c #(nop) ")
+                            .trim()
+                            .to_owned(),
+                        cumulative: ByteStats::new(cumulative),
+                        size: ByteStats::new(size),
+                    }
+                })
+                .collect::<Vec<_>>();
+            layers.reverse();
+            let selected = image.history.state.selected();
+            image.history = StatefulList::new(layers);
+            image.history.state.select(selected.or(Some(0)));
+        }
+    }
+
+    /// Get the history layers of the currently selected image, newest first
+    pub fn get_history(&self) -> Vec<HistoryLayer> {
+        self.get_selected_image()
+            .map_or(vec![], |i| i.history.items.clone())
+    }
+
+    /// Find the widths for the strings in the images panel.
+    /// So can display nicely and evenly
+    pub fn get_width(&self) -> ImageColumns {
+        let mut columns = ImageColumns::new();
+        let count = |x: &String| u8::try_from(x.chars().count()).unwrap_or(12);
+
+        for image in &self.images.items {
+            columns.name.1 = columns.name.1.max(count(&image.name()));
+            columns.size.1 = columns.size.1.max(count(&image.size.to_string()));
+            columns.created.1 = columns.created.1.max(count(&image.created.to_string()));
+        }
+        columns
+    }
+
+    // Update related methods
+
+    /// return a mutable image by given id
+    fn get_image_by_id(&mut self, id: &ImageId) -> Option<&mut ImageItem> {
+        self.images.items.iter_mut().find(|i| &i.id == id)
+    }
+
+    /// Update, or insert, images, `usage` is the number of containers using each image id
+    pub fn update_images(&mut self, all_images: &[ImageSummary], usage: &HashMap<String, usize>) {
+        let selected_id = self.get_selected_image_id();
+
+        // Remove any images that no longer exist
+        self.images
+            .items
+            .retain(|i| all_images.iter().any(|x| x.id == i.id.get()));
+
+        for i in all_images {
+            let id = ImageId::from(&i.id);
+            let containers = usage.get(&i.id).copied().unwrap_or_default();
+            let size = u64::try_from(i.size).unwrap_or_default();
+            if let Some(item) = self.get_image_by_id(&id) {
+                item.containers = containers;
+                item.labels.clone_from(&i.labels);
+                item.repo_tags.clone_from(&i.repo_tags);
+                item.size.update(size);
+            } else {
+                let created = u64::try_from(i.created).unwrap_or_default();
+                self.images.items.push(ImageItem::new(
+                    containers,
+                    created,
+                    id,
+                    i.labels.clone(),
+                    i.repo_tags.clone(),
+                    size,
+                ));
+            }
+        }
+        self.sort_images();
+
+        // Keep the same image selected, or if removed, select the first one
+        let position = self
+            .images
+            .items
+            .iter()
+            .position(|i| selected_id.as_ref() == Some(&i.id));
+        if position.is_some() {
+            self.images.state.select(position);
+        } else if self.images.items.is_empty() {
+            self.images.state.select(None);
+        } else {
+            self.images.start();
+        }
+    }
+
+    /// Get the images that would be removed by a prune, and the total size that would be reclaimed
+    pub fn prune_preview(&self, prune: &ImagePrune) -> (Vec<ImageItem>, ByteStats) {
+        let images = self
+            .images
+            .items
+            .iter()
+            .filter(|i| prune.matches(i))
+            .cloned()
+            .collect::<Vec<_>>();
+        let size = ByteStats::new(images.iter().map(|i| i.size.get()).sum());
+        (images, size)
+    }
+}
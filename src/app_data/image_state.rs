@@ -0,0 +1,326 @@
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fmt,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use ratatui::widgets::ListItem;
+
+use crate::app_data::container_state::ByteStats;
+use crate::app_data::image_data::ImageHeader;
+use crate::app_data::statefull_list::StatefulList;
+
+const ONE_MINUTE: u64 = 60;
+const ONE_HOUR: u64 = ONE_MINUTE * 60;
+const ONE_DAY: u64 = ONE_HOUR * 24;
+const ONE_WEEK: u64 = ONE_DAY * 7;
+const ONE_MONTH: u64 = ONE_DAY * 30;
+const ONE_YEAR: u64 = ONE_DAY * 365;
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct ImageId(String);
+
+impl From<String> for ImageId {
+    fn from(x: String) -> Self {
+        Self(x)
+    }
+}
+
+impl From<&String> for ImageId {
+    fn from(x: &String) -> Self {
+        Self(x.clone())
+    }
+}
+
+impl From<&str> for ImageId {
+    fn from(x: &str) -> Self {
+        Self(x.to_owned())
+    }
+}
+
+impl ImageId {
+    pub const fn get(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// The id without the `sha256:` prefix, limited to 12 chars, as shown by the docker cli
+    pub fn short(&self) -> String {
+        self.0
+            .trim_start_matches("sha256:")
+            .chars()
+            .take(12)
+            .collect()
+    }
+}
+
+impl Ord for ImageId {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl PartialOrd for ImageId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Split an image name into the repository and the tag, defaulting to the `latest` tag
+/// The tag is after the last `:`, as long as that `:` isn't part of a registry host:port, e.g. `localhost:5000/image`
+/// An image referenced by digest is returned whole, with an empty tag, as docker will resolve the digest itself
+pub fn split_repo_tag(image: &str) -> (String, String) {
+    if image.contains('@') {
+        return (image.to_owned(), String::new());
+    }
+    match image.rsplit_once(':') {
+        Some((repo, tag)) if !tag.contains('/') => (repo.to_owned(), tag.to_owned()),
+        _ => (image.to_owned(), String::from("latest")),
+    }
+}
+
+/// Unix timestamp of when something was created
+/// So can use custom display formatter, to show as an age, e.g. "3 days ago"
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Created(u64);
+
+/// Current time as unix timestamp
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |i| i.as_secs())
+}
+
+impl Created {
+    pub const fn new(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+/// convert from unix timestamp to seconds, minutes, hours etc ago
+impl fmt::Display for Created {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let elapsed = now().saturating_sub(self.0);
+        let (value, unit) = match elapsed {
+            x if x >= ONE_YEAR => (x / ONE_YEAR, "year"),
+            x if x >= ONE_MONTH => (x / ONE_MONTH, "month"),
+            x if x >= ONE_WEEK => (x / ONE_WEEK, "week"),
+            x if x >= ONE_DAY => (x / ONE_DAY, "day"),
+            x if x >= ONE_HOUR => (x / ONE_HOUR, "hour"),
+            x if x >= ONE_MINUTE => (x / ONE_MINUTE, "minute"),
+            x => (x, "second"),
+        };
+        let plural = if value == 1 { "" } else { "s" };
+        let disp = format!("{value} {unit}{plural} ago");
+        write!(f, "{disp:>x$}", x = f.width().unwrap_or(1))
+    }
+}
+
+/// A single layer of an image, as shown by `docker history`
+/// cumulative is the size of this layer plus all the layers beneath it
+#[derive(Debug, Clone)]
+pub struct HistoryLayer {
+    pub created: Created,
+    pub created_by: String,
+    pub cumulative: ByteStats,
+    pub size: ByteStats,
+}
+
+/// Info for each image
+#[derive(Debug, Clone)]
+pub struct ImageItem {
+    pub containers: usize,
+    pub created: Created,
+    pub history: StatefulList<HistoryLayer>,
+    pub id: ImageId,
+    pub info: StatefulList<ListItem<'static>>,
+    pub labels: HashMap<String, String>,
+    pub repo_tags: Vec<String>,
+    pub size: ByteStats,
+}
+
+impl ImageItem {
+    /// Create a new image item
+    pub fn new(
+        containers: usize,
+        created: u64,
+        id: ImageId,
+        labels: HashMap<String, String>,
+        repo_tags: Vec<String>,
+        size: u64,
+    ) -> Self {
+        Self {
+            containers,
+            created: Created::new(created),
+            history: StatefulList::new(vec![]),
+            id,
+            info: StatefulList::new(vec![]),
+            labels,
+            repo_tags,
+            size: ByteStats::new(size),
+        }
+    }
+
+    /// The first repo:tag of the image, dangling images don't have any, so display the same as the docker cli
+    pub fn name(&self) -> String {
+        self.repo_tags
+            .first()
+            .map_or_else(|| String::from("<none>:<none>"), std::clone::Clone::clone)
+    }
+
+    /// A dangling image has no repo:tag, older docker versions return `<none>:<none>` rather than nothing
+    pub fn is_dangling(&self) -> bool {
+        self.repo_tags.iter().all(|i| i == "<none>:<none>")
+    }
+}
+
+/// Parse a go style duration, as accepted by the docker `until` filter, e.g. `24h` or `1h30m`, into seconds
+fn parse_duration(input: &str) -> Option<u64> {
+    let mut total = 0;
+    let mut number = String::new();
+    for c in input.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+        } else {
+            let multiplier = match c {
+                'h' => ONE_HOUR,
+                'm' => ONE_MINUTE,
+                's' => 1,
+                _ => return None,
+            };
+            total += number.parse::<u64>().ok()? * multiplier;
+            number.clear();
+        }
+    }
+    if number.is_empty() && total > 0 {
+        Some(total)
+    } else {
+        None
+    }
+}
+
+/// Filters used to prune images, the same as `docker image prune`, used both to preview which images would be removed, and to execute the prune
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ImagePrune {
+    pub all: bool,
+    until: Option<(String, u64)>,
+    label: Option<(String, bool)>,
+}
+
+impl ImagePrune {
+    /// `until` is either a unix timestamp, or a duration, `label` is `key` or `key=value`, prefixed with `!` to negate
+    /// Returns None if until can't be parsed
+    pub fn new(all: bool, until: &str, label: &str) -> Option<Self> {
+        let until = if until.is_empty() {
+            None
+        } else if let Ok(timestamp) = until.parse::<u64>() {
+            Some((until.to_owned(), timestamp))
+        } else {
+            Some((
+                until.to_owned(),
+                now().saturating_sub(parse_duration(until)?),
+            ))
+        };
+        let label = if label.is_empty() {
+            None
+        } else if let Some(negated) = label.strip_prefix('!') {
+            Some((negated.to_owned(), true))
+        } else {
+            Some((label.to_owned(), false))
+        };
+        Some(Self { all, until, label })
+    }
+
+    /// Filters in the format expected by the docker api
+    pub fn filters(&self) -> HashMap<String, Vec<String>> {
+        let mut filters = HashMap::new();
+        let dangling = if self.all { "false" } else { "true" };
+        filters.insert(String::from("dangling"), vec![dangling.to_owned()]);
+        if let Some((until, _)) = &self.until {
+            filters.insert(String::from("until"), vec![until.clone()]);
+        }
+        if let Some((label, negated)) = &self.label {
+            let key = if *negated { "label!" } else { "label" };
+            filters.insert(key.to_owned(), vec![label.clone()]);
+        }
+        filters
+    }
+
+    /// Check if a given image would be removed by this prune
+    /// Images used by any container, running or not, are never removed
+    pub fn matches(&self, image: &ImageItem) -> bool {
+        let unused = image.containers == 0 && (self.all || image.is_dangling());
+        let old_enough = self
+            .until
+            .as_ref()
+            .is_none_or(|(_, cutoff)| image.created.0 < *cutoff);
+        let labelled = self.label.as_ref().is_none_or(|(label, negated)| {
+            let has_label = label.split_once('=').map_or_else(
+                || image.labels.contains_key(label),
+                |(key, value)| image.labels.get(key).is_some_and(|i| i == value),
+            );
+            has_label != *negated
+        });
+        unused && old_enough && labelled
+    }
+}
+
+/// The inputs of an image build, the context directory is sent to docker as a tarball
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ImageBuild {
+    pub context: String,
+    pub dockerfile: String,
+    pub tag: String,
+    pub build_args: HashMap<String, String>,
+}
+
+impl ImageBuild {
+    /// `build_args` is a whitespace separated list of `KEY=VALUE` pairs
+    /// Returns None if the context or tag is empty, or if a build arg doesn't have a value
+    pub fn new(context: &str, dockerfile: &str, tag: &str, build_args: &str) -> Option<Self> {
+        if context.is_empty() || tag.is_empty() {
+            return None;
+        }
+        let build_args = build_args
+            .split_whitespace()
+            .map(|i| {
+                i.split_once('=')
+                    .map(|(key, value)| (key.to_owned(), value.to_owned()))
+            })
+            .collect::<Option<HashMap<_, _>>>()?;
+        let dockerfile = if dockerfile.is_empty() {
+            "Dockerfile"
+        } else {
+            dockerfile
+        };
+        Some(Self {
+            context: context.to_owned(),
+            dockerfile: dockerfile.to_owned(),
+            tag: tag.to_owned(),
+            build_args,
+        })
+    }
+}
+
+/// Image information panel headings + widths, for nice pretty formatting
+#[derive(Debug, Clone, Copy)]
+pub struct ImageColumns {
+    pub name: (ImageHeader, u8),
+    pub id: (ImageHeader, u8),
+    pub size: (ImageHeader, u8),
+    pub created: (ImageHeader, u8),
+    pub containers: (ImageHeader, u8),
+}
+
+impl ImageColumns {
+    /// (Column titles, minimum header string length)
+    pub const fn new() -> Self {
+        Self {
+            name: (ImageHeader::Name, 8),
+            id: (ImageHeader::Id, 12),
+            size: (ImageHeader::Size, 8),
+            created: (ImageHeader::Created, 12),
+            containers: (ImageHeader::Containers, 10),
+        }
+    }
+}
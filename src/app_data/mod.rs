@@ -1,14 +1,48 @@
 use crate::{app_error::AppError, parse_args::CliArgs};
 
+use self::{
+    change_state::ContainerChanges,
+    export_state::ContainerExport,
+    file_state::FileBrowser,
+    progress_state::{BuildLog, Progress},
+};
+
+pub mod change_state;
 pub mod container_data;
 pub mod container_state;
+pub mod export_state;
+pub mod file_state;
+pub mod image_data;
+pub mod image_state;
+pub mod network_data;
+pub mod network_state;
+pub mod node_data;
+pub mod node_state;
+pub mod process_data;
+pub mod process_state;
+pub mod progress_state;
+pub mod service_data;
+pub mod service_state;
 pub mod statefull_list;
+pub mod volume_data;
+pub mod volume_state;
 
 /// Global app_state, stored in an Arc<Mutex>
 #[derive(Debug, Clone)]
 pub struct AppData {
+    pub build: Option<BuildLog>,
+    pub changes: Option<ContainerChanges>,
     pub container_data: container_data::ContainerData,
     pub error: Option<AppError>,
+    pub export: Option<ContainerExport>,
+    pub files: Option<FileBrowser>,
+    pub image_data: image_data::ImageData,
+    pub network_data: network_data::NetworkData,
+    pub node_data: node_data::NodeData,
+    pub process_data: process_data::ProcessData,
+    pub progress: Option<Progress>,
+    pub service_data: service_data::ServiceData,
+    pub volume_data: volume_data::VolumeData,
     pub args: CliArgs,
 }
 
@@ -17,8 +51,19 @@ impl AppData {
     pub fn default(args: CliArgs) -> Self {
         Self {
             args,
+            build: None,
+            changes: None,
             container_data: container_data::ContainerData::new(args),
             error: None,
+            export: None,
+            files: None,
+            image_data: image_data::ImageData::new(),
+            network_data: network_data::NetworkData::new(),
+            node_data: node_data::NodeData::new(),
+            process_data: process_data::ProcessData::new(),
+            progress: None,
+            service_data: service_data::ServiceData::new(),
+            volume_data: volume_data::VolumeData::new(),
         }
     }
 
@@ -38,4 +83,206 @@ impl AppData {
     pub fn set_error(&mut self, error: AppError) {
         self.error = Some(error);
     }
+
+    // Progress related methods
+
+    /// Get a clone of the current progress, if any, for drawing
+    pub fn get_progress(&self) -> Option<Progress> {
+        self.progress.clone()
+    }
+
+    /// Get a mutable reference to the current progress
+    pub const fn get_mut_progress(&mut self) -> Option<&mut Progress> {
+        self.progress.as_mut()
+    }
+
+    /// Set, or remove, the current progress
+    pub fn set_progress(&mut self, progress: Option<Progress>) {
+        self.progress = progress;
+    }
+
+    // Export related methods
+
+    /// Get a clone of the current container export, if any, for drawing
+    pub fn get_export(&self) -> Option<ContainerExport> {
+        self.export.clone()
+    }
+
+    /// Get a mutable reference to the current container export, to scroll it
+    pub const fn get_mut_export(&mut self) -> Option<&mut ContainerExport> {
+        self.export.as_mut()
+    }
+
+    /// Set, or remove, the current container export
+    pub fn set_export(&mut self, export: Option<ContainerExport>) {
+        self.export = export;
+    }
+
+    // Build related methods
+
+    /// Get a clone of the current build log, if any, for drawing
+    pub fn get_build(&self) -> Option<BuildLog> {
+        self.build.clone()
+    }
+
+    /// Get a mutable reference to the current build log
+    pub const fn get_mut_build(&mut self) -> Option<&mut BuildLog> {
+        self.build.as_mut()
+    }
+
+    /// Set, or remove, the current build log
+    pub fn set_build(&mut self, build: Option<BuildLog>) {
+        self.build = build;
+    }
+
+    /// select first build log line
+    pub fn build_start(&mut self) {
+        if let Some(build) = self.build.as_mut() {
+            build.logs.start();
+        }
+    }
+
+    /// select next build log line
+    pub fn build_next(&mut self) {
+        if let Some(build) = self.build.as_mut() {
+            build.logs.next();
+        }
+    }
+
+    /// select previous build log line
+    pub fn build_previous(&mut self) {
+        if let Some(build) = self.build.as_mut() {
+            build.logs.previous();
+        }
+    }
+
+    /// select last build log line
+    pub fn build_end(&mut self) {
+        if let Some(build) = self.build.as_mut() {
+            build.logs.end();
+        }
+    }
+
+    // Container changes related methods
+
+    /// Get a clone of the container changes, if any, for drawing
+    pub fn get_changes(&self) -> Option<ContainerChanges> {
+        self.changes.clone()
+    }
+
+    /// Get a mutable reference to the container changes
+    pub const fn get_mut_changes(&mut self) -> Option<&mut ContainerChanges> {
+        self.changes.as_mut()
+    }
+
+    /// Set new container changes, if they're of the same container, keep the current filter and selection
+    pub fn set_changes(&mut self, changes: ContainerChanges) {
+        match self.changes.as_mut() {
+            Some(current) if current.container == changes.container => {
+                current.set_changes(changes);
+            }
+            _ => self.changes = Some(changes),
+        }
+    }
+
+    /// select first container change
+    pub fn changes_start(&mut self) {
+        if let Some(changes) = self.changes.as_mut() {
+            changes.list.start();
+        }
+    }
+
+    /// select next container change
+    pub fn changes_next(&mut self) {
+        if let Some(changes) = self.changes.as_mut() {
+            changes.list.next();
+        }
+    }
+
+    /// select previous container change
+    pub fn changes_previous(&mut self) {
+        if let Some(changes) = self.changes.as_mut() {
+            changes.list.previous();
+        }
+    }
+
+    /// select last container change
+    pub fn changes_end(&mut self) {
+        if let Some(changes) = self.changes.as_mut() {
+            changes.list.end();
+        }
+    }
+
+    // File browser related methods
+
+    /// Get a clone of the open file browser, if any, for drawing
+    pub fn get_files(&self) -> Option<FileBrowser> {
+        self.files.clone()
+    }
+
+    /// Get a mutable reference to the open file browser
+    pub const fn get_mut_files(&mut self) -> Option<&mut FileBrowser> {
+        self.files.as_mut()
+    }
+
+    /// Open, or close, the file browser
+    pub fn set_files(&mut self, files: Option<FileBrowser>) {
+        self.files = files;
+    }
+
+    /// select first file browser entry
+    pub fn files_start(&mut self) {
+        if let Some(files) = self.files.as_mut() {
+            files.entries.start();
+        }
+    }
+
+    /// select next file browser entry
+    pub fn files_next(&mut self) {
+        if let Some(files) = self.files.as_mut() {
+            files.entries.next();
+        }
+    }
+
+    /// select previous file browser entry
+    pub fn files_previous(&mut self) {
+        if let Some(files) = self.files.as_mut() {
+            files.entries.previous();
+        }
+    }
+
+    /// select last file browser entry
+    pub fn files_end(&mut self) {
+        if let Some(files) = self.files.as_mut() {
+            files.entries.end();
+        }
+    }
+
+    /// select first line of the viewed file
+    pub fn viewer_start(&mut self) {
+        if let Some(files) = self.files.as_mut() {
+            files.viewer.start();
+        }
+    }
+
+    /// select next line of the viewed file
+    pub fn viewer_next(&mut self) {
+        if let Some(files) = self.files.as_mut() {
+            files.viewer.next();
+        }
+    }
+
+    /// select previous line of the viewed file
+    pub fn viewer_previous(&mut self) {
+        if let Some(files) = self.files.as_mut() {
+            files.viewer.previous();
+        }
+    }
+
+    /// select last line of the viewed file
+    pub fn viewer_end(&mut self) {
+        if let Some(files) = self.files.as_mut() {
+            files.viewer.end();
+        }
+    }
 }
@@ -0,0 +1,184 @@
+use core::fmt;
+use std::collections::HashMap;
+
+use bollard::models::Network;
+use ratatui::widgets::ListState;
+
+use crate::app_data::network_state::{NetworkColumns, NetworkContainer, NetworkItem};
+
+use super::statefull_list::StatefulList;
+
+/// All networks, stored in the global app_state
+#[derive(Debug, Clone)]
+pub struct NetworkData {
+    networks: StatefulList<NetworkItem>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum NetworkHeader {
+    Name,
+    Driver,
+    Scope,
+    Subnet,
+    Containers,
+}
+
+/// Convert NetworkHeader enum into strings to display
+impl fmt::Display for NetworkHeader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Name => "name",
+            Self::Driver => "driver",
+            Self::Scope => "scope",
+            Self::Subnet => "subnet",
+            Self::Containers => "containers",
+        };
+        write!(f, "{disp:>x$}", x = f.width().unwrap_or(1))
+    }
+}
+
+impl NetworkData {
+    /// Generate a default network_state
+    pub fn new() -> Self {
+        Self {
+            networks: StatefulList::new(vec![]),
+        }
+    }
+
+    // Network state methods
+
+    /// Get title for networks section
+    pub fn network_title(&self) -> String {
+        self.networks.get_state_title()
+    }
+
+    /// Select the first network
+    pub fn networks_start(&mut self) {
+        self.networks.start();
+    }
+
+    /// select the last network
+    pub fn networks_end(&mut self) {
+        self.networks.end();
+    }
+
+    /// Select the next network
+    pub fn networks_next(&mut self) {
+        self.networks.next();
+    }
+
+    /// select the previous network
+    pub fn networks_previous(&mut self) {
+        self.networks.previous();
+    }
+
+    /// Get Network items
+    pub const fn get_network_items(&self) -> &Vec<NetworkItem> {
+        &self.networks.items
+    }
+
+    /// Get Option of the current selected network
+    pub fn get_selected_network(&self) -> Option<&NetworkItem> {
+        self.networks
+            .state
+            .selected()
+            .and_then(|i| self.networks.items.get(i))
+    }
+
+    /// Get the name of the currently selected network
+    pub fn get_selected_network_name(&self) -> Option<String> {
+        self.get_selected_network().map(|i| i.name.clone())
+    }
+
+    /// Get the containers attached to the currently selected network
+    pub fn get_selected_containers(&self) -> Vec<NetworkContainer> {
+        self.get_selected_network()
+            .map_or(vec![], |i| i.containers.clone())
+    }
+
+    /// Get ListState of networks
+    pub const fn get_network_state(&mut self) -> &mut ListState {
+        &mut self.networks.state
+    }
+
+    /// Find the widths for the strings in the networks panel.
+    /// So can display nicely and evenly
+    pub fn get_width(&self) -> NetworkColumns {
+        let mut columns = NetworkColumns::new();
+        let count = |x: &str| u8::try_from(x.chars().count()).unwrap_or(64);
+
+        for network in &self.networks.items {
+            columns.name.1 = columns.name.1.max(count(&network.name));
+            columns.driver.1 = columns.driver.1.max(count(&network.driver));
+            columns.scope.1 = columns.scope.1.max(count(&network.scope));
+            columns.subnet.1 = columns.subnet.1.max(count(&network.subnet));
+        }
+        columns
+    }
+
+    // Update related methods
+
+    /// return a mutable network by given id
+    fn get_network_by_id(&mut self, id: &str) -> Option<&mut NetworkItem> {
+        self.networks.items.iter_mut().find(|i| i.id == id)
+    }
+
+    /// Update, or insert, networks, sorted by name, `usage` is the containers attached to each network, by network name
+    pub fn update_networks(
+        &mut self,
+        all_networks: &[Network],
+        usage: &HashMap<String, Vec<NetworkContainer>>,
+    ) {
+        let selected_name = self.get_selected_network_name();
+
+        // Remove any networks that no longer exist
+        self.networks
+            .items
+            .retain(|i| all_networks.iter().any(|x| x.id.as_ref() == Some(&i.id)));
+
+        for i in all_networks {
+            let (Some(id), Some(name)) = (i.id.as_ref(), i.name.as_ref()) else {
+                continue;
+            };
+            let mut containers = usage.get(name).cloned().unwrap_or_default();
+            containers.sort_by(|a, b| a.name.cmp(&b.name));
+            let subnet = i
+                .ipam
+                .as_ref()
+                .and_then(|i| i.config.as_ref())
+                .map_or(vec![], |config| {
+                    config.iter().filter_map(|i| i.subnet.clone()).collect()
+                })
+                .join(", ");
+            if let Some(item) = self.get_network_by_id(id) {
+                item.containers = containers;
+                item.name.clone_from(name);
+                item.subnet = subnet;
+            } else {
+                self.networks.items.push(NetworkItem::new(
+                    containers,
+                    i.driver.clone().unwrap_or_default(),
+                    id.clone(),
+                    name.clone(),
+                    i.scope.clone().unwrap_or_default(),
+                    subnet,
+                ));
+            }
+        }
+        self.networks.items.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Keep the same network selected, or if removed, select the first one
+        let position = self
+            .networks
+            .items
+            .iter()
+            .position(|i| selected_name.as_ref() == Some(&i.name));
+        if position.is_some() {
+            self.networks.state.select(position);
+        } else if self.networks.items.is_empty() {
+            self.networks.state.select(None);
+        } else {
+            self.networks.start();
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use std::net::IpAddr;
+
+use crate::app_data::network_data::NetworkHeader;
+
+/// A container attached to a network, with its address and aliases on that network
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NetworkContainer {
+    pub name: String,
+    pub ip: String,
+    pub aliases: Vec<String>,
+}
+
+/// Info for each network
+#[derive(Debug, Clone)]
+pub struct NetworkItem {
+    pub containers: Vec<NetworkContainer>,
+    pub driver: String,
+    pub id: String,
+    pub name: String,
+    pub scope: String,
+    pub subnet: String,
+}
+
+impl NetworkItem {
+    /// Create a new network item
+    pub const fn new(
+        containers: Vec<NetworkContainer>,
+        driver: String,
+        id: String,
+        name: String,
+        scope: String,
+        subnet: String,
+    ) -> Self {
+        Self {
+            containers,
+            driver,
+            id,
+            name,
+            scope,
+            subnet,
+        }
+    }
+
+    /// The networks that docker creates itself, which can't be removed
+    pub fn is_predefined(&self) -> bool {
+        matches!(self.name.as_str(), "bridge" | "host" | "none")
+    }
+}
+
+/// Network information panel headings + widths, for nice pretty formatting
+#[derive(Debug, Clone, Copy)]
+pub struct NetworkColumns {
+    pub name: (NetworkHeader, u8),
+    pub driver: (NetworkHeader, u8),
+    pub scope: (NetworkHeader, u8),
+    pub subnet: (NetworkHeader, u8),
+    pub containers: (NetworkHeader, u8),
+}
+
+impl NetworkColumns {
+    /// (Column titles, minimum header string length)
+    pub const fn new() -> Self {
+        Self {
+            name: (NetworkHeader::Name, 6),
+            driver: (NetworkHeader::Driver, 6),
+            scope: (NetworkHeader::Scope, 5),
+            subnet: (NetworkHeader::Subnet, 6),
+            containers: (NetworkHeader::Containers, 10),
+        }
+    }
+}
+
+/// Connect a container to a network, optionally with an alias, and a static address
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NetworkConnect {
+    pub container: String,
+    pub network: String,
+    pub alias: Option<String>,
+    pub ip: Option<IpAddr>,
+}
+
+impl NetworkConnect {
+    /// Returns None if the container or network is empty, or if the ip can't be parsed
+    pub fn new(container: &str, network: &str, alias: &str, ip: &str) -> Option<Self> {
+        if container.is_empty() || network.is_empty() {
+            return None;
+        }
+        let ip = if ip.is_empty() {
+            None
+        } else {
+            Some(ip.parse::<IpAddr>().ok()?)
+        };
+        Some(Self {
+            container: container.to_owned(),
+            network: network.to_owned(),
+            alias: Some(alias.to_owned()).filter(|i| !i.is_empty()),
+            ip,
+        })
+    }
+}
+
+/// Create a new network, the subnet is optional, docker will pick one if not given
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NetworkCreate {
+    pub name: String,
+    pub driver: String,
+    pub subnet: Option<String>,
+}
+
+impl NetworkCreate {
+    /// Returns None if the name is empty, or if the subnet isn't in CIDR notation, e.g. `172.20.0.0/16`
+    pub fn new(name: &str, driver: &str, subnet: &str) -> Option<Self> {
+        if name.is_empty() {
+            return None;
+        }
+        let subnet = if subnet.is_empty() {
+            None
+        } else {
+            let (ip, prefix) = subnet.split_once('/')?;
+            ip.parse::<IpAddr>().ok()?;
+            prefix.parse::<u8>().ok()?;
+            Some(subnet.to_owned())
+        };
+        Some(Self {
+            name: name.to_owned(),
+            driver: driver.to_owned(),
+            subnet,
+        })
+    }
+}
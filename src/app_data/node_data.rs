@@ -0,0 +1,199 @@
+use core::fmt;
+
+use bollard::service::Node;
+use ratatui::widgets::ListState;
+
+use crate::app_data::node_state::{NodeColumns, NodeItem};
+
+use super::statefull_list::StatefulList;
+
+/// All swarm nodes, stored in the global app_state
+/// If the nodes can't be listed, e.g. when the daemon isn't a swarm manager, `unavailable` has the reason to show instead
+#[derive(Debug, Clone)]
+pub struct NodeData {
+    nodes: StatefulList<NodeItem>,
+    unavailable: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum NodeHeader {
+    Hostname,
+    Role,
+    Availability,
+    Status,
+    Engine,
+    Labels,
+}
+
+/// Convert NodeHeader enum into strings to display
+impl fmt::Display for NodeHeader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Hostname => "hostname",
+            Self::Role => "role",
+            Self::Availability => "availability",
+            Self::Status => "status",
+            Self::Engine => "engine",
+            Self::Labels => "labels",
+        };
+        write!(f, "{disp:>x$}", x = f.width().unwrap_or(1))
+    }
+}
+
+impl NodeData {
+    /// Generate a default node_state
+    pub fn new() -> Self {
+        Self {
+            nodes: StatefulList::new(vec![]),
+            unavailable: None,
+        }
+    }
+
+    // Node state methods
+
+    /// Get title for nodes section
+    pub fn node_title(&self) -> String {
+        self.nodes.get_state_title()
+    }
+
+    /// Select the first node
+    pub fn nodes_start(&mut self) {
+        self.nodes.start();
+    }
+
+    /// select the last node
+    pub fn nodes_end(&mut self) {
+        self.nodes.end();
+    }
+
+    /// Select the next node
+    pub fn nodes_next(&mut self) {
+        self.nodes.next();
+    }
+
+    /// select the previous node
+    pub fn nodes_previous(&mut self) {
+        self.nodes.previous();
+    }
+
+    /// Get Node items
+    pub const fn get_node_items(&self) -> &Vec<NodeItem> {
+        &self.nodes.items
+    }
+
+    /// Get Option of the current selected node
+    pub fn get_selected_node(&self) -> Option<&NodeItem> {
+        self.nodes
+            .state
+            .selected()
+            .and_then(|i| self.nodes.items.get(i))
+    }
+
+    /// Get ListState of nodes
+    pub const fn get_node_state(&mut self) -> &mut ListState {
+        &mut self.nodes.state
+    }
+
+    /// The reason the nodes can't be listed, if any
+    pub fn get_unavailable(&self) -> Option<String> {
+        self.unavailable.clone()
+    }
+
+    /// Find the widths for the strings in the nodes panel.
+    /// So can display nicely and evenly
+    pub fn get_width(&self) -> NodeColumns {
+        let mut columns = NodeColumns::new();
+        let count = |x: &str| u8::try_from(x.chars().count()).unwrap_or(64);
+
+        for node in &self.nodes.items {
+            columns.hostname.1 = columns.hostname.1.max(count(&node.hostname));
+            columns.role.1 = columns.role.1.max(count(&node.role));
+            columns.availability.1 = columns.availability.1.max(count(&node.availability));
+            columns.status.1 = columns.status.1.max(count(&node.status));
+            columns.engine.1 = columns.engine.1.max(count(&node.engine));
+            columns.labels.1 = columns.labels.1.max(count(&node.labels));
+        }
+        columns
+    }
+
+    // Update related methods
+
+    /// Clear the nodes, and set the reason they can't be listed
+    pub fn set_unavailable(&mut self, reason: String) {
+        self.nodes = StatefulList::new(vec![]);
+        self.unavailable = Some(reason);
+    }
+
+    /// Replace the nodes, sorted by hostname, keeping the same node selected
+    pub fn update_nodes(&mut self, all_nodes: &[Node]) {
+        self.unavailable = None;
+        let selected_id = self.get_selected_node().map(|i| i.id.clone());
+
+        self.nodes.items = all_nodes
+            .iter()
+            .filter_map(|node| {
+                let spec = node.spec.as_ref();
+                let description = node.description.as_ref();
+                let leader = node
+                    .manager_status
+                    .as_ref()
+                    .map(|i| {
+                        if i.leader == Some(true) {
+                            String::from("leader")
+                        } else {
+                            i.reachability.map(|i| i.to_string()).unwrap_or_default()
+                        }
+                    })
+                    .filter(|i| !i.is_empty());
+                let role = spec
+                    .and_then(|i| i.role)
+                    .map(|i| i.to_string())
+                    .unwrap_or_default();
+                let mut labels = spec
+                    .and_then(|i| i.labels.as_ref())
+                    .map_or(vec![], |labels| {
+                        labels.iter().map(|(k, v)| format!("{k}={v}")).collect()
+                    });
+                labels.sort();
+                Some(NodeItem {
+                    id: node.id.clone()?,
+                    hostname: description
+                        .and_then(|i| i.hostname.clone())
+                        .unwrap_or_default(),
+                    is_manager: role == "manager",
+                    role: leader.map_or_else(|| role.clone(), |i| format!("{role} ({i})")),
+                    availability: spec
+                        .and_then(|i| i.availability)
+                        .map(|i| i.to_string())
+                        .unwrap_or_default(),
+                    status: node
+                        .status
+                        .as_ref()
+                        .and_then(|i| i.state)
+                        .map(|i| i.to_string())
+                        .unwrap_or_default(),
+                    engine: description
+                        .and_then(|i| i.engine.as_ref())
+                        .and_then(|i| i.engine_version.clone())
+                        .unwrap_or_default(),
+                    labels: labels.join(", "),
+                })
+            })
+            .collect();
+        self.nodes.items.sort_by(|a, b| a.hostname.cmp(&b.hostname));
+
+        // Keep the same node selected, or if removed, select the first one
+        let position = self
+            .nodes
+            .items
+            .iter()
+            .position(|i| selected_id.as_ref() == Some(&i.id));
+        if position.is_some() {
+            self.nodes.state.select(position);
+        } else if self.nodes.items.is_empty() {
+            self.nodes.state.select(None);
+        } else {
+            self.nodes.start();
+        }
+    }
+}
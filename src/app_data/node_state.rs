@@ -0,0 +1,64 @@
+use std::fmt;
+
+use crate::app_data::node_data::NodeHeader;
+
+/// Info for each swarm node, `role` includes the manager status, e.g. `manager (leader)`
+#[derive(Debug, Clone)]
+pub struct NodeItem {
+    pub id: String,
+    pub hostname: String,
+    pub role: String,
+    pub availability: String,
+    pub status: String,
+    pub engine: String,
+    pub labels: String,
+    pub is_manager: bool,
+}
+
+/// A change to the availability, or the role, of a node
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum NodeChange {
+    Activate,
+    Pause,
+    Drain,
+    Promote,
+    Demote,
+}
+
+impl fmt::Display for NodeChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Activate => "activate",
+            Self::Pause => "pause",
+            Self::Drain => "drain",
+            Self::Promote => "promote",
+            Self::Demote => "demote",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// Node information panel headings + widths, for nice pretty formatting
+#[derive(Debug, Clone, Copy)]
+pub struct NodeColumns {
+    pub hostname: (NodeHeader, u8),
+    pub role: (NodeHeader, u8),
+    pub availability: (NodeHeader, u8),
+    pub status: (NodeHeader, u8),
+    pub engine: (NodeHeader, u8),
+    pub labels: (NodeHeader, u8),
+}
+
+impl NodeColumns {
+    /// (Column titles, minimum header string length)
+    pub const fn new() -> Self {
+        Self {
+            hostname: (NodeHeader::Hostname, 8),
+            role: (NodeHeader::Role, 4),
+            availability: (NodeHeader::Availability, 12),
+            status: (NodeHeader::Status, 6),
+            engine: (NodeHeader::Engine, 6),
+            labels: (NodeHeader::Labels, 6),
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use core::fmt;
+
+use bollard::service::ContainerTopResponse;
+use ratatui::widgets::ListState;
+
+use crate::app_data::{
+    container_data::SortedOrder,
+    container_state::ContainerId,
+    process_state::{ProcessColumns, ProcessItem, ProcessTarget},
+};
+
+use super::statefull_list::StatefulList;
+
+/// The processes of a single container, stored in the global app_state, refreshed whilst the processes panel is open
+#[derive(Debug, Clone)]
+pub struct ProcessData {
+    container: Option<(ContainerId, String)>,
+    processes: StatefulList<ProcessItem>,
+    sorted_by: Option<(ProcessHeader, SortedOrder)>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ProcessHeader {
+    Pid,
+    User,
+    Cpu,
+    Memory,
+    Command,
+}
+
+/// Convert ProcessHeader enum into strings to display
+impl fmt::Display for ProcessHeader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Pid => "pid",
+            Self::User => "user",
+            Self::Cpu => "cpu",
+            Self::Memory => "memory",
+            Self::Command => "command",
+        };
+        write!(f, "{disp:>x$}", x = f.width().unwrap_or(1))
+    }
+}
+
+impl ProcessData {
+    /// Generate a default process_state
+    pub fn new() -> Self {
+        Self {
+            container: None,
+            processes: StatefulList::new(vec![]),
+            sorted_by: None,
+        }
+    }
+
+    /// Show the processes of a different container, removing those of the previous container
+    pub fn set_container(&mut self, id: ContainerId, name: String) {
+        if self.get_container_id().as_ref() != Some(&id) {
+            self.processes = StatefulList::new(vec![]);
+        }
+        self.container = Some((id, name));
+    }
+
+    /// The id of the container the processes belong to
+    pub fn get_container_id(&self) -> Option<ContainerId> {
+        self.container.as_ref().map(|i| i.0.clone())
+    }
+
+    /// Change the sorted order, also set the selected process state to match new order
+    fn set_sorted(&mut self, x: Option<(ProcessHeader, SortedOrder)>) {
+        let selected_pid = self.get_selected_pid();
+        self.sorted_by = x;
+        self.sort_processes();
+        self.select_pid(selected_pid);
+    }
+
+    // Process sort related methods
+
+    /// Remove the sorted header & order, and sort by default - pid
+    pub fn reset_sorted(&mut self) {
+        self.set_sorted(None);
+    }
+
+    /// Sort processes based on a given header, if headings match, and already ascending, remove sorting
+    pub fn set_sort_by_header(&mut self, selected_header: ProcessHeader) {
+        let mut output = Some((selected_header, SortedOrder::Asc));
+        if let Some((current_header, order)) = self.get_sorted() {
+            if current_header == selected_header {
+                match order {
+                    SortedOrder::Desc => output = None,
+                    SortedOrder::Asc => output = Some((selected_header, SortedOrder::Desc)),
+                }
+            }
+        }
+        self.set_sorted(output);
+    }
+
+    pub const fn get_sorted(&self) -> Option<(ProcessHeader, SortedOrder)> {
+        self.sorted_by
+    }
+
+    /// Sort the processes vec, based on a heading, either ascending or descending,
+    /// If not sort set, then sort by pid
+    fn sort_processes(&mut self) {
+        let items = &mut self.processes.items;
+        if let Some((head, ord)) = self.sorted_by {
+            match head {
+                ProcessHeader::Pid => items.sort_by_key(ProcessItem::pid_number),
+                ProcessHeader::User => items.sort_by(|a, b| a.user.cmp(&b.user)),
+                ProcessHeader::Cpu => items.sort_by(|a, b| a.cpu.total_cmp(&b.cpu)),
+                ProcessHeader::Memory => items.sort_by(|a, b| a.memory.total_cmp(&b.memory)),
+                ProcessHeader::Command => items.sort_by(|a, b| a.command.cmp(&b.command)),
+            }
+            if ord == SortedOrder::Desc {
+                items.reverse();
+            }
+        } else {
+            items.sort_by_key(ProcessItem::pid_number);
+        }
+    }
+
+    // Process state methods
+
+    /// Get title for processes section, the container name, and the selected process
+    pub fn process_title(&self) -> String {
+        format!(
+            "({}) {}",
+            self.container
+                .as_ref()
+                .map(|i| i.1.as_str())
+                .unwrap_or_default(),
+            self.processes.get_state_title()
+        )
+    }
+
+    /// Select the first process
+    pub fn processes_start(&mut self) {
+        self.processes.start();
+    }
+
+    /// select the last process
+    pub fn processes_end(&mut self) {
+        self.processes.end();
+    }
+
+    /// Select the next process
+    pub fn processes_next(&mut self) {
+        self.processes.next();
+    }
+
+    /// select the previous process
+    pub fn processes_previous(&mut self) {
+        self.processes.previous();
+    }
+
+    /// Get Process items
+    pub const fn get_process_items(&self) -> &Vec<ProcessItem> {
+        &self.processes.items
+    }
+
+    /// Get the current process list state
+    pub const fn get_process_state(&mut self) -> &mut ListState {
+        &mut self.processes.state
+    }
+
+    /// Get the pid of the selected process
+    pub fn get_selected_pid(&self) -> Option<String> {
+        self.processes
+            .state
+            .selected()
+            .and_then(|i| self.processes.items.get(i))
+            .map(|i| i.pid.clone())
+    }
+
+    /// Get the selected process, with its position amongst the processes with the same command, so that it can be found inside the container
+    pub fn get_selected_target(&self) -> Option<ProcessTarget> {
+        let selected = self
+            .processes
+            .state
+            .selected()
+            .and_then(|i| self.processes.items.get(i))?;
+        let mut same = self
+            .processes
+            .items
+            .iter()
+            .filter(|i| i.command == selected.command)
+            .map(ProcessItem::pid_number)
+            .collect::<Vec<_>>();
+        same.sort_unstable();
+        Some(ProcessTarget {
+            pid: selected.pid.clone(),
+            command: selected.command.clone(),
+            index: same.iter().position(|i| *i == selected.pid_number())? + 1,
+            count: same.len(),
+        })
+    }
+
+    /// Select a process by its pid, or the first process if it no longer exists
+    fn select_pid(&mut self, pid: Option<String>) {
+        match pid.and_then(|pid| self.processes.items.iter().position(|i| i.pid == pid)) {
+            Some(index) => self.processes.state.select(Some(index)),
+            None => self.processes.start(),
+        }
+    }
+
+    /// Get the widths of each column, based on the longest value of each
+    pub fn get_width(&self) -> ProcessColumns {
+        let mut columns = ProcessColumns::new();
+        let count = |x: &str| u8::try_from(x.chars().count()).unwrap_or(12);
+
+        for process in &self.processes.items {
+            columns.pid.1 = columns.pid.1.max(count(&process.pid));
+            columns.user.1 = columns.user.1.max(count(&process.user));
+        }
+        columns
+    }
+
+    // Update related methods
+
+    /// Replace the processes with the output of `docker top`, keeping the sort order, and the selected process
+    pub fn update_processes(&mut self, top: ContainerTopResponse) {
+        let selected_pid = self.get_selected_pid();
+        let titles = top.titles.unwrap_or_default();
+        self.processes.items = top
+            .processes
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|i| ProcessItem::new(&titles, i))
+            .collect();
+        self.sort_processes();
+        self.select_pid(selected_pid);
+    }
+}
@@ -0,0 +1,75 @@
+use crate::app_data::process_data::ProcessHeader;
+
+/// A single process running in a container, as listed by `docker top`
+/// The pid is the pid on the docker host, not the pid inside the container
+#[derive(Debug, Clone)]
+pub struct ProcessItem {
+    pub pid: String,
+    pub user: String,
+    pub cpu: f64,
+    pub memory: f64,
+    pub command: String,
+}
+
+impl ProcessItem {
+    /// Create a process from a row of `docker top` output, using the column titles to find each value
+    pub fn new(titles: &[String], row: &[String]) -> Option<Self> {
+        let value = |names: &[&str]| {
+            titles
+                .iter()
+                .position(|i| names.contains(&i.as_str()))
+                .and_then(|i| row.get(i))
+                .cloned()
+        };
+        let percent = |names: &[&str]| {
+            value(names)
+                .and_then(|i| i.parse::<f64>().ok())
+                .unwrap_or_default()
+        };
+        Some(Self {
+            pid: value(&["PID"])?,
+            user: value(&["USER", "UID"]).unwrap_or_default(),
+            cpu: percent(&["%CPU", "C"]),
+            memory: percent(&["%MEM"]),
+            command: value(&["COMMAND", "CMD"]).unwrap_or_default(),
+        })
+    }
+
+    /// The pid as a number, for sorting
+    pub fn pid_number(&self) -> u64 {
+        self.pid.parse().unwrap_or_default()
+    }
+}
+
+/// A process to send a signal to, its host pid isn't visible inside the container, so it's found there by its command instead
+/// `index` is its position amongst the `count` processes with the same command, ordered by pid, as pids are allocated in the same order on the host and inside the container
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct ProcessTarget {
+    pub pid: String,
+    pub command: String,
+    pub index: usize,
+    pub count: usize,
+}
+
+/// Process information panel headings + widths, for nice pretty formatting
+#[derive(Debug, Clone, Copy)]
+pub struct ProcessColumns {
+    pub pid: (ProcessHeader, u8),
+    pub user: (ProcessHeader, u8),
+    pub cpu: (ProcessHeader, u8),
+    pub memory: (ProcessHeader, u8),
+    pub command: (ProcessHeader, u8),
+}
+
+impl ProcessColumns {
+    /// (Column titles, minimum header string length)
+    pub const fn new() -> Self {
+        Self {
+            pid: (ProcessHeader::Pid, 5),
+            user: (ProcessHeader::User, 6),
+            cpu: (ProcessHeader::Cpu, 6),
+            memory: (ProcessHeader::Memory, 6),
+            command: (ProcessHeader::Command, 7),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use ratatui::widgets::ListItem;
+
+use crate::{
+    app_data::container_state::{ByteStats, Logs, LogsTz},
+    parse_args::CliArgs,
+    ui::log_sanitizer,
+};
+
+/// Progress of a single layer of an image pull
+#[derive(Debug, Clone)]
+pub struct LayerProgress {
+    pub id: String,
+    pub status: String,
+    pub current: ByteStats,
+    pub total: ByteStats,
+}
+
+impl LayerProgress {
+    /// Percentage complete, only known when docker has sent a total, which it does when downloading or extracting
+    pub fn percent(&self) -> Option<u16> {
+        let (current, total) = (self.current.get(), self.total.get());
+        (current.min(total) * 100)
+            .checked_div(total)
+            .and_then(|i| u16::try_from(i).ok())
+    }
+}
+
+/// Current state of a long running, streamed, docker operation
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ProgressState {
+    Running,
+    Complete,
+    Cancelled,
+    Failed,
+}
+
+/// Progress of a streamed docker operation, such as an image pull, is shown in a popup
+/// Docker sends a status line for each layer, identified by the layer id, and general status lines without an id
+#[derive(Debug, Clone)]
+pub struct Progress {
+    pub title: String,
+    pub layers: Vec<LayerProgress>,
+    pub status: String,
+    pub state: ProgressState,
+}
+
+impl Progress {
+    pub const fn new(title: String) -> Self {
+        Self {
+            title,
+            layers: vec![],
+            status: String::new(),
+            state: ProgressState::Running,
+        }
+    }
+
+    /// Update either a layer, or the general status, from a line of the docker stream
+    pub fn update(
+        &mut self,
+        id: Option<&str>,
+        status: Option<&str>,
+        current: Option<i64>,
+        total: Option<i64>,
+    ) {
+        let status = status.unwrap_or_default().to_owned();
+        let to_bytes = |x: Option<i64>| {
+            ByteStats::new(x.and_then(|x| u64::try_from(x).ok()).unwrap_or_default())
+        };
+        match id {
+            Some(id) if !id.is_empty() => {
+                if let Some(layer) = self.layers.iter_mut().find(|i| i.id == id) {
+                    layer.status = status;
+                    layer.current = to_bytes(current);
+                    layer.total = to_bytes(total);
+                } else {
+                    self.layers.push(LayerProgress {
+                        id: id.to_owned(),
+                        status,
+                        current: to_bytes(current),
+                        total: to_bytes(total),
+                    });
+                }
+            }
+            _ => self.status = status,
+        }
+    }
+
+    /// Set the final state, and the final status line
+    pub fn finish(&mut self, state: ProgressState, status: String) {
+        self.state = state;
+        self.status = status;
+    }
+
+    pub const fn is_running(&self) -> bool {
+        matches!(self.state, ProgressState::Running)
+    }
+}
+
+/// Output of an image build, shown in a panel the same way as container logs
+#[derive(Debug, Clone)]
+pub struct BuildLog {
+    pub title: String,
+    pub logs: Logs,
+    pub status: String,
+    pub state: ProgressState,
+    lines: usize,
+}
+
+impl BuildLog {
+    pub fn new(title: String) -> Self {
+        Self {
+            title,
+            logs: Logs::default(),
+            status: String::from("building"),
+            state: ProgressState::Running,
+            lines: 0,
+        }
+    }
+
+    /// Add a chunk of build output, which can contain multiple lines, sanitized the same way as container logs
+    /// Build output has no timestamps, so the line number is used to keep each line unique
+    /// Keeps following the output, unless the user has scrolled up
+    pub fn push(&mut self, output: &str, args: CliArgs) {
+        let current_len = self.logs.len();
+        for line in output.lines().filter(|i| !i.trim().is_empty()) {
+            let lines = if args.color {
+                log_sanitizer::colorize_logs(line)
+            } else if args.raw {
+                log_sanitizer::raw(line)
+            } else {
+                log_sanitizer::remove_ansi(line)
+            };
+            self.lines += 1;
+            self.logs.insert(
+                ListItem::new(lines),
+                LogsTz::from(&format!("{} ", self.lines)),
+            );
+        }
+        if self.logs.state().selected().is_none()
+            || self.logs.state().selected().map_or(1, |f| f + 1) == current_len
+        {
+            self.logs.end();
+        }
+    }
+
+    /// Set the final state, and the final status line
+    pub fn finish(&mut self, state: ProgressState, status: String) {
+        self.state = state;
+        self.status = status;
+    }
+
+    pub const fn is_running(&self) -> bool {
+        matches!(self.state, ProgressState::Running)
+    }
+}
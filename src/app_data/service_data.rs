@@ -0,0 +1,480 @@
+use core::fmt;
+use std::collections::HashMap;
+
+use bollard::service::{Service, Task};
+use ratatui::{
+    style::{Color, Style},
+    text::Span,
+    widgets::{ListItem, ListState},
+};
+
+use crate::{
+    app_data::{
+        container_data::ContainerData,
+        container_state::LogsTz,
+        service_state::{ServiceColumns, ServiceItem, TaskItem},
+    },
+    parse_args::CliArgs,
+    ui::log_sanitizer,
+};
+
+use super::statefull_list::StatefulList;
+
+/// Colors used for the task tag at the start of each line of the service logs, in order of the task names
+const TASK_COLORS: [Color; 6] = [
+    Color::Cyan,
+    Color::Magenta,
+    Color::Yellow,
+    Color::Green,
+    Color::LightBlue,
+    Color::LightRed,
+];
+
+/// All swarm services, stored in the global app_state
+/// If the services can't be listed, e.g. when the daemon isn't a swarm manager, `unavailable` has the reason to show instead
+/// If only the tasks can't be listed, `tasks_unavailable` has the reason to show in the tasks panel
+#[derive(Debug, Clone)]
+pub struct ServiceData {
+    services: StatefulList<ServiceItem>,
+    unavailable: Option<String>,
+    tasks_unavailable: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ServiceHeader {
+    Name,
+    Mode,
+    Replicas,
+    Image,
+    Ports,
+}
+
+/// Convert ServiceHeader enum into strings to display
+impl fmt::Display for ServiceHeader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Name => "name",
+            Self::Mode => "mode",
+            Self::Replicas => "replicas",
+            Self::Image => "image",
+            Self::Ports => "ports",
+        };
+        write!(f, "{disp:>x$}", x = f.width().unwrap_or(1))
+    }
+}
+
+impl ServiceData {
+    /// Generate a default service_state
+    pub fn new() -> Self {
+        Self {
+            services: StatefulList::new(vec![]),
+            unavailable: None,
+            tasks_unavailable: None,
+        }
+    }
+
+    // Service state methods
+
+    /// Get title for services section
+    pub fn service_title(&self) -> String {
+        self.services.get_state_title()
+    }
+
+    /// Select the first service
+    pub fn services_start(&mut self) {
+        self.services.start();
+    }
+
+    /// select the last service
+    pub fn services_end(&mut self) {
+        self.services.end();
+    }
+
+    /// Select the next service
+    pub fn services_next(&mut self) {
+        self.services.next();
+    }
+
+    /// select the previous service
+    pub fn services_previous(&mut self) {
+        self.services.previous();
+    }
+
+    /// Get Service items
+    pub const fn get_service_items(&self) -> &Vec<ServiceItem> {
+        &self.services.items
+    }
+
+    /// Get Option of the current selected service
+    pub fn get_selected_service(&self) -> Option<&ServiceItem> {
+        self.services
+            .state
+            .selected()
+            .and_then(|i| self.services.items.get(i))
+    }
+
+    /// Get Option of the current selected service, mutable
+    fn get_mut_selected_service(&mut self) -> Option<&mut ServiceItem> {
+        self.services
+            .state
+            .selected()
+            .and_then(|i| self.services.items.get_mut(i))
+    }
+
+    /// Get the name of the currently selected service
+    pub fn get_selected_service_name(&self) -> Option<String> {
+        self.get_selected_service().map(|i| i.name.clone())
+    }
+
+    /// Get ListState of services
+    pub const fn get_service_state(&mut self) -> &mut ListState {
+        &mut self.services.state
+    }
+
+    /// The reason the services can't be listed, if any
+    pub fn get_unavailable(&self) -> Option<String> {
+        self.unavailable.clone()
+    }
+
+    /// The reason the tasks of the services can't be listed, if any
+    pub fn get_tasks_unavailable(&self) -> Option<String> {
+        self.tasks_unavailable.clone()
+    }
+
+    /// Find the widths for the strings in the services panel.
+    /// So can display nicely and evenly
+    pub fn get_width(&self) -> ServiceColumns {
+        let mut columns = ServiceColumns::new();
+        let count = |x: &str| u8::try_from(x.chars().count()).unwrap_or(64);
+
+        for service in &self.services.items {
+            columns.name.1 = columns.name.1.max(count(&service.name));
+            columns.mode.1 = columns.mode.1.max(count(service.mode()));
+            columns.replicas.1 = columns.replicas.1.max(count(&service.replicas_text()));
+            columns.image.1 = columns.image.1.max(count(&service.image));
+            columns.ports.1 = columns.ports.1.max(count(&service.ports));
+        }
+        columns
+    }
+
+    // Task related methods
+
+    /// select first task of the selected service
+    pub fn tasks_start(&mut self) {
+        if let Some(i) = self.get_mut_selected_service() {
+            i.tasks.start();
+        }
+    }
+
+    /// select next task of the selected service
+    pub fn tasks_next(&mut self) {
+        if let Some(i) = self.get_mut_selected_service() {
+            i.tasks.next();
+        }
+    }
+
+    /// select previous task of the selected service
+    pub fn tasks_previous(&mut self) {
+        if let Some(i) = self.get_mut_selected_service() {
+            i.tasks.previous();
+        }
+    }
+
+    /// select last task of the selected service
+    pub fn tasks_end(&mut self) {
+        if let Some(i) = self.get_mut_selected_service() {
+            i.tasks.end();
+        }
+    }
+
+    /// Get the tasks of the currently selected service
+    pub fn get_tasks(&self) -> Vec<TaskItem> {
+        self.get_selected_service()
+            .map_or(vec![], |i| i.tasks.items.clone())
+    }
+
+    /// Get mutable Option of the currently selected service tasks state
+    pub fn get_task_state(&mut self) -> Option<&mut ListState> {
+        self.get_mut_selected_service().map(|i| &mut i.tasks.state)
+    }
+
+    // Logs related methods
+
+    /// Get title for the service logs panel
+    pub fn get_log_title(&self) -> String {
+        self.get_selected_service().map_or_else(String::new, |i| {
+            let logs_len = i.logs.get_state_title();
+            if logs_len.is_empty() {
+                format!("- {} ", i.name)
+            } else {
+                format!("{logs_len} - {}", i.name)
+            }
+        })
+    }
+
+    /// select first log line of the selected service
+    pub fn log_start(&mut self) {
+        if let Some(i) = self.get_mut_selected_service() {
+            i.logs.start();
+        }
+    }
+
+    /// select next log line of the selected service
+    pub fn log_next(&mut self) {
+        if let Some(i) = self.get_mut_selected_service() {
+            i.logs.next();
+        }
+    }
+
+    /// select previous log line of the selected service
+    pub fn log_previous(&mut self) {
+        if let Some(i) = self.get_mut_selected_service() {
+            i.logs.previous();
+        }
+    }
+
+    /// select last log line of the selected service
+    pub fn log_end(&mut self) {
+        if let Some(i) = self.get_mut_selected_service() {
+            i.logs.end();
+        }
+    }
+
+    /// Get the log lines of the currently selected service
+    pub fn get_logs(&self) -> Vec<ListItem<'static>> {
+        self.get_selected_service()
+            .map_or(vec![], |i| i.logs.to_vec())
+    }
+
+    /// Get mutable Option of the currently selected service logs state
+    pub fn get_log_state(&mut self) -> Option<&mut ListState> {
+        self.get_mut_selected_service().map(|i| i.logs.state())
+    }
+
+    /// Update the logs of a service, each line is `timestamp details message`, the details contain the id of the task that logged the line
+    /// Each line is prefixed with the name of the task, colored by the task, so the output of each replica can be told apart
+    pub fn update_logs(&mut self, id: &str, logs: Vec<String>, args: CliArgs) {
+        let Some(service) = self.services.items.iter_mut().find(|i| i.id == id) else {
+            return;
+        };
+        service.last_updated = ContainerData::get_systemtime();
+        let current_len = service.logs.len();
+
+        let mut names = service
+            .tasks
+            .items
+            .iter()
+            .map(|i| i.name.clone())
+            .collect::<Vec<_>>();
+        names.dedup();
+        let tag_width = names.iter().map(|i| i.chars().count()).max().unwrap_or(12);
+
+        for line in logs {
+            let (timestamp, rest) = line.split_once(' ').unwrap_or(("", &line));
+            let (task_id, message) = match rest.split_once(' ') {
+                Some((details, message)) if details.contains("com.docker.swarm.") => (
+                    details
+                        .split(',')
+                        .find_map(|i| i.strip_prefix("com.docker.swarm.task.id="))
+                        .unwrap_or_default(),
+                    message,
+                ),
+                _ => ("", rest),
+            };
+            let task = service.tasks.items.iter().find(|i| i.id == task_id);
+            let (tag, color) = task.map_or_else(
+                || (task_id.chars().take(12).collect(), Color::DarkGray),
+                |task| {
+                    let index = names
+                        .iter()
+                        .position(|i| i == &task.name)
+                        .unwrap_or_default();
+                    (task.name.clone(), TASK_COLORS[index % TASK_COLORS.len()])
+                },
+            );
+
+            let text = if args.timestamp {
+                format!("{timestamp} {message}")
+            } else {
+                message.to_owned()
+            };
+            let mut lines = if args.color {
+                log_sanitizer::colorize_logs(&text)
+            } else if args.raw {
+                log_sanitizer::raw(&text)
+            } else {
+                log_sanitizer::remove_ansi(&text)
+            };
+            if let Some(first) = lines.first_mut() {
+                first.spans.insert(
+                    0,
+                    Span::styled(format!("{tag:<tag_width$} | "), Style::default().fg(color)),
+                );
+            }
+            // Two tasks can log at the same instant, so the task is part of what makes each line unique
+            service.logs.insert(
+                ListItem::new(lines),
+                LogsTz::from(&format!("{timestamp}{task_id} ")),
+            );
+        }
+
+        // Keep following the logs, unless the user has scrolled up
+        if service.logs.state().selected().is_none()
+            || service.logs.state().selected().map_or(1, |f| f + 1) == current_len
+        {
+            service.logs.end();
+        }
+    }
+
+    // Update related methods
+
+    /// Clear the services, and set the reason they can't be listed
+    pub fn set_unavailable(&mut self, reason: String) {
+        self.services = StatefulList::new(vec![]);
+        self.unavailable = Some(reason);
+    }
+
+    /// Convert a task into a TaskItem, `nodes` is the hostname of each node, by node id
+    fn task_item(service: &str, task: &Task, nodes: &HashMap<String, String>) -> TaskItem {
+        let node_id = task.node_id.clone().unwrap_or_default();
+        let node = nodes
+            .get(&node_id)
+            .cloned()
+            .unwrap_or_else(|| node_id.chars().take(12).collect());
+        let status = task.status.as_ref();
+        TaskItem {
+            id: task.id.clone().unwrap_or_default(),
+            name: task.slot.map_or_else(
+                || format!("{service}.{node}"),
+                |slot| format!("{service}.{slot}"),
+            ),
+            node,
+            state: status
+                .and_then(|i| i.state)
+                .map(|i| i.to_string())
+                .unwrap_or_default(),
+            desired_state: task
+                .desired_state
+                .map(|i| i.to_string())
+                .unwrap_or_default(),
+            error: status.and_then(|i| i.err.clone()).unwrap_or_default(),
+            updated: status
+                .and_then(|i| i.timestamp.clone())
+                .map(|i| i.chars().take(19).collect::<String>().replace('T', " "))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Update, or insert, services, sorted by name, with their tasks, sorted by name, and then newest first
+    /// If the tasks couldn't be listed, `all_tasks` is empty, and `tasks_unavailable` is the reason
+    pub fn update_services(
+        &mut self,
+        all_services: &[Service],
+        all_tasks: &[Task],
+        nodes: &HashMap<String, String>,
+        tasks_unavailable: Option<String>,
+    ) {
+        self.unavailable = None;
+        self.tasks_unavailable = tasks_unavailable;
+        let selected_name = self.get_selected_service_name();
+
+        // Remove any services that no longer exist
+        self.services
+            .items
+            .retain(|i| all_services.iter().any(|x| x.id.as_ref() == Some(&i.id)));
+
+        for i in all_services {
+            let (Some(id), Some(spec)) = (i.id.as_ref(), i.spec.as_ref()) else {
+                continue;
+            };
+            let name = spec.name.clone().unwrap_or_default();
+            let image = spec
+                .task_template
+                .as_ref()
+                .and_then(|i| i.container_spec.as_ref())
+                .and_then(|i| i.image.as_ref())
+                .and_then(|i| i.split('@').next())
+                .unwrap_or_default()
+                .to_owned();
+            let replicas = spec
+                .mode
+                .as_ref()
+                .and_then(|i| i.replicated.as_ref())
+                .map(|i| i.replicas.and_then(|i| u64::try_from(i).ok()).unwrap_or(1));
+            let ports = i
+                .endpoint
+                .as_ref()
+                .and_then(|i| i.ports.as_ref())
+                .map_or(vec![], |ports| {
+                    ports
+                        .iter()
+                        .map(|i| {
+                            format!(
+                                "{}->{}/{}",
+                                i.published_port.unwrap_or_default(),
+                                i.target_port.unwrap_or_default(),
+                                i.protocol.map(|i| i.to_string()).unwrap_or_default()
+                            )
+                        })
+                        .collect()
+                })
+                .join(", ");
+
+            let mut tasks = all_tasks
+                .iter()
+                .filter(|task| task.service_id.as_ref() == Some(id))
+                .map(|task| Self::task_item(&name, task, nodes))
+                .collect::<Vec<_>>();
+            tasks.sort_by(|a, b| a.name.cmp(&b.name).then(b.updated.cmp(&a.updated)));
+
+            let index = if let Some(index) = self.services.items.iter().position(|i| &i.id == id) {
+                let item = &mut self.services.items[index];
+                item.name.clone_from(&name);
+                item.image = image;
+                item.replicas = replicas;
+                item.ports = ports;
+                index
+            } else {
+                self.services.items.push(ServiceItem::new(
+                    id.clone(),
+                    name,
+                    image,
+                    replicas,
+                    ports,
+                ));
+                self.services.items.len() - 1
+            };
+            let item = &mut self.services.items[index];
+
+            // Keep the same task selected, by id
+            let selected_task = item
+                .tasks
+                .state
+                .selected()
+                .and_then(|i| item.tasks.items.get(i))
+                .map(|i| i.id.clone());
+            let position = tasks
+                .iter()
+                .position(|i| Some(&i.id) == selected_task.as_ref());
+            item.tasks.items = tasks;
+            item.tasks
+                .state
+                .select(position.or_else(|| (!item.tasks.items.is_empty()).then_some(0)));
+        }
+        self.services.items.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Keep the same service selected, or if removed, select the first one
+        let position = self
+            .services
+            .items
+            .iter()
+            .position(|i| selected_name.as_ref() == Some(&i.name));
+        if position.is_some() {
+            self.services.state.select(position);
+        } else if self.services.items.is_empty() {
+            self.services.state.select(None);
+        } else {
+            self.services.start();
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use crate::app_data::{
+    container_state::Logs, service_data::ServiceHeader, statefull_list::StatefulList,
+};
+
+/// A task of a service, `name` is the service name with either the replica slot, or for a global service, the node
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TaskItem {
+    pub id: String,
+    pub name: String,
+    pub node: String,
+    pub state: String,
+    pub desired_state: String,
+    pub error: String,
+    pub updated: String,
+}
+
+impl TaskItem {
+    pub fn is_running(&self) -> bool {
+        self.state == "running"
+    }
+}
+
+/// Info for each swarm service, `replicas` is None for a global service
+#[derive(Debug, Clone)]
+pub struct ServiceItem {
+    pub id: String,
+    pub name: String,
+    pub image: String,
+    pub replicas: Option<u64>,
+    pub ports: String,
+    pub tasks: StatefulList<TaskItem>,
+    pub logs: Logs,
+    pub last_updated: u64,
+}
+
+impl ServiceItem {
+    /// Create a new service item, with no tasks
+    pub fn new(
+        id: String,
+        name: String,
+        image: String,
+        replicas: Option<u64>,
+        ports: String,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            image,
+            replicas,
+            ports,
+            tasks: StatefulList::new(vec![]),
+            logs: Logs::default(),
+            last_updated: 0,
+        }
+    }
+
+    /// The scheduling mode, as shown by the docker cli
+    pub const fn mode(&self) -> &'static str {
+        if self.replicas.is_some() {
+            "replicated"
+        } else {
+            "global"
+        }
+    }
+
+    /// Running tasks, out of the desired number of tasks, a global service wants a task on each node that is running one
+    pub fn replicas_text(&self) -> String {
+        let running = self.tasks.items.iter().filter(|i| i.is_running()).count();
+        let desired = self.replicas.map_or_else(
+            || {
+                self.tasks
+                    .items
+                    .iter()
+                    .filter(|i| i.desired_state == "running")
+                    .count()
+            },
+            |i| usize::try_from(i).unwrap_or_default(),
+        );
+        format!("{running}/{desired}")
+    }
+}
+
+/// Service information panel headings + widths, for nice pretty formatting
+#[derive(Debug, Clone, Copy)]
+pub struct ServiceColumns {
+    pub name: (ServiceHeader, u8),
+    pub mode: (ServiceHeader, u8),
+    pub replicas: (ServiceHeader, u8),
+    pub image: (ServiceHeader, u8),
+    pub ports: (ServiceHeader, u8),
+}
+
+impl ServiceColumns {
+    /// (Column titles, minimum header string length)
+    pub const fn new() -> Self {
+        Self {
+            name: (ServiceHeader::Name, 4),
+            mode: (ServiceHeader::Mode, 4),
+            replicas: (ServiceHeader::Replicas, 8),
+            image: (ServiceHeader::Image, 5),
+            ports: (ServiceHeader::Ports, 5),
+        }
+    }
+}
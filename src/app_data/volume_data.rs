@@ -0,0 +1,232 @@
+use core::fmt;
+use std::collections::HashMap;
+
+use bollard::models::Volume;
+use ratatui::widgets::{ListItem, ListState};
+
+use crate::app_data::volume_state::{VolumeColumns, VolumeItem};
+
+use super::statefull_list::StatefulList;
+
+/// All volumes, stored in the global app_state
+#[derive(Debug, Clone)]
+pub struct VolumeData {
+    volumes: StatefulList<VolumeItem>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum VolumeHeader {
+    Name,
+    Driver,
+    Mountpoint,
+    Containers,
+    Labels,
+}
+
+/// Convert VolumeHeader enum into strings to display
+impl fmt::Display for VolumeHeader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Name => "name",
+            Self::Driver => "driver",
+            Self::Mountpoint => "mountpoint",
+            Self::Containers => "containers",
+            Self::Labels => "labels",
+        };
+        write!(f, "{disp:>x$}", x = f.width().unwrap_or(1))
+    }
+}
+
+impl VolumeData {
+    /// Generate a default volume_state
+    pub fn new() -> Self {
+        Self {
+            volumes: StatefulList::new(vec![]),
+        }
+    }
+
+    // Volume state methods
+
+    /// Get title for volumes section
+    pub fn volume_title(&self) -> String {
+        self.volumes.get_state_title()
+    }
+
+    /// Select the first volume
+    pub fn volumes_start(&mut self) {
+        self.volumes.start();
+    }
+
+    /// select the last volume
+    pub fn volumes_end(&mut self) {
+        self.volumes.end();
+    }
+
+    /// Select the next volume
+    pub fn volumes_next(&mut self) {
+        self.volumes.next();
+    }
+
+    /// select the previous volume
+    pub fn volumes_previous(&mut self) {
+        self.volumes.previous();
+    }
+
+    /// Get Volume items
+    pub const fn get_volume_items(&self) -> &Vec<VolumeItem> {
+        &self.volumes.items
+    }
+
+    /// Get Option of the current selected volume
+    pub fn get_selected_volume(&self) -> Option<&VolumeItem> {
+        self.volumes
+            .state
+            .selected()
+            .and_then(|i| self.volumes.items.get(i))
+    }
+
+    /// Get mutable Option of the current selected volume
+    fn get_mut_selected_volume(&mut self) -> Option<&mut VolumeItem> {
+        self.volumes
+            .state
+            .selected()
+            .and_then(|i| self.volumes.items.get_mut(i))
+    }
+
+    /// Get the name of the currently selected volume
+    pub fn get_selected_volume_name(&self) -> Option<String> {
+        self.get_selected_volume().map(|i| i.name.clone())
+    }
+
+    /// Get ListState of volumes
+    pub const fn get_volume_state(&mut self) -> &mut ListState {
+        &mut self.volumes.state
+    }
+
+    // Info related methods
+
+    /// select first selected info line
+    pub fn info_start(&mut self) {
+        if let Some(i) = self.get_mut_selected_volume() {
+            i.info.start();
+        }
+    }
+
+    /// select next selected info line
+    pub fn info_next(&mut self) {
+        if let Some(i) = self.get_mut_selected_volume() {
+            i.info.next();
+        }
+    }
+
+    /// select previous selected info line
+    pub fn info_previous(&mut self) {
+        if let Some(i) = self.get_mut_selected_volume() {
+            i.info.previous();
+        }
+    }
+
+    /// select last selected info line
+    pub fn info_end(&mut self) {
+        if let Some(i) = self.get_mut_selected_volume() {
+            i.info.end();
+        }
+    }
+
+    /// Get mutable Option of the currently selected volume Info state
+    pub fn get_info_state(&mut self) -> Option<&mut ListState> {
+        self.get_mut_selected_volume().map(|i| &mut i.info.state)
+    }
+
+    /// Replace the info of a given volume with the lines of a yaml string
+    pub fn update_infos(&mut self, name: &str, info: &str) {
+        if let Some(volume) = self.get_volume_by_name(name) {
+            volume.info = StatefulList::new(
+                info.lines()
+                    .map(|l| ListItem::new(l.to_owned()))
+                    .collect::<Vec<_>>(),
+            );
+        }
+    }
+
+    /// Get the info lines of the currently selected volume
+    pub fn get_infos(&self) -> Vec<ListItem<'static>> {
+        self.get_selected_volume()
+            .map_or(vec![], |i| i.info.items.clone())
+    }
+
+    /// Find the widths for the strings in the volumes panel.
+    /// So can display nicely and evenly
+    pub fn get_width(&self) -> VolumeColumns {
+        let mut columns = VolumeColumns::new();
+        let count = |x: &str| u8::try_from(x.chars().count()).unwrap_or(64);
+
+        for volume in &self.volumes.items {
+            columns.name.1 = columns.name.1.max(count(&volume.name));
+            columns.driver.1 = columns.driver.1.max(count(&volume.driver));
+            columns.mountpoint.1 = columns.mountpoint.1.max(count(&volume.mountpoint));
+            columns.containers.1 = columns.containers.1.max(count(&volume.containers_text()));
+        }
+        columns
+    }
+
+    // Update related methods
+
+    /// return a mutable volume by given name
+    fn get_volume_by_name(&mut self, name: &str) -> Option<&mut VolumeItem> {
+        self.volumes.items.iter_mut().find(|i| i.name == name)
+    }
+
+    /// Update, or insert, volumes, sorted by name, `usage` is the names of the containers mounting each volume
+    pub fn update_volumes(&mut self, all_volumes: &[Volume], usage: &HashMap<String, Vec<String>>) {
+        let selected_name = self.get_selected_volume_name();
+
+        // Remove any volumes that no longer exist
+        self.volumes
+            .items
+            .retain(|i| all_volumes.iter().any(|x| x.name == i.name));
+
+        for i in all_volumes {
+            let containers = usage.get(&i.name).cloned().unwrap_or_default();
+            if let Some(item) = self.get_volume_by_name(&i.name) {
+                item.containers = containers;
+                item.driver.clone_from(&i.driver);
+                item.labels.clone_from(&i.labels);
+                item.mountpoint.clone_from(&i.mountpoint);
+            } else {
+                self.volumes.items.push(VolumeItem::new(
+                    containers,
+                    i.driver.clone(),
+                    i.labels.clone(),
+                    i.mountpoint.clone(),
+                    i.name.clone(),
+                ));
+            }
+        }
+        self.volumes.items.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Keep the same volume selected, or if removed, select the first one
+        let position = self
+            .volumes
+            .items
+            .iter()
+            .position(|i| selected_name.as_ref() == Some(&i.name));
+        if position.is_some() {
+            self.volumes.state.select(position);
+        } else if self.volumes.items.is_empty() {
+            self.volumes.state.select(None);
+        } else {
+            self.volumes.start();
+        }
+    }
+
+    /// Get the volumes that would be removed by a prune, those not mounted by any container
+    pub fn prune_preview(&self) -> Vec<VolumeItem> {
+        self.volumes
+            .items
+            .iter()
+            .filter(|i| i.containers.is_empty())
+            .cloned()
+            .collect()
+    }
+}
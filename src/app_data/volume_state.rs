@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use ratatui::widgets::ListItem;
+
+use crate::app_data::statefull_list::StatefulList;
+use crate::app_data::volume_data::VolumeHeader;
+
+/// Info for each volume
+#[derive(Debug, Clone)]
+pub struct VolumeItem {
+    pub containers: Vec<String>,
+    pub driver: String,
+    pub info: StatefulList<ListItem<'static>>,
+    pub labels: HashMap<String, String>,
+    pub mountpoint: String,
+    pub name: String,
+}
+
+impl VolumeItem {
+    /// Create a new volume item
+    pub fn new(
+        containers: Vec<String>,
+        driver: String,
+        labels: HashMap<String, String>,
+        mountpoint: String,
+        name: String,
+    ) -> Self {
+        Self {
+            containers,
+            driver,
+            info: StatefulList::new(vec![]),
+            labels,
+            mountpoint,
+            name,
+        }
+    }
+
+    /// Names of the containers that mount this volume, comma separated, or `-` if unused
+    pub fn containers_text(&self) -> String {
+        if self.containers.is_empty() {
+            String::from("-")
+        } else {
+            self.containers.join(", ")
+        }
+    }
+
+    /// Labels as `key=value`, comma separated, sorted by key
+    pub fn labels_text(&self) -> String {
+        let mut labels = self
+            .labels
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>();
+        labels.sort();
+        labels.join(", ")
+    }
+}
+
+/// Volume information panel headings + widths, for nice pretty formatting
+#[derive(Debug, Clone, Copy)]
+pub struct VolumeColumns {
+    pub name: (VolumeHeader, u8),
+    pub driver: (VolumeHeader, u8),
+    pub mountpoint: (VolumeHeader, u8),
+    pub containers: (VolumeHeader, u8),
+}
+
+impl VolumeColumns {
+    /// (Column titles, minimum header string length)
+    pub const fn new() -> Self {
+        Self {
+            name: (VolumeHeader::Name, 6),
+            driver: (VolumeHeader::Driver, 6),
+            mountpoint: (VolumeHeader::Mountpoint, 10),
+            containers: (VolumeHeader::Containers, 10),
+        }
+    }
+}
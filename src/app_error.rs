@@ -7,6 +7,8 @@ pub enum AppError {
     DockerConnect,
     DockerInterval,
     Docker,
+    ImageLoad,
+    ImageSave,
     InputPoll,
     MouseCapture(bool),
     Terminal,
@@ -19,6 +21,8 @@ impl fmt::Display for AppError {
             Self::Docker => write!(f, "Docker error"),
             Self::DockerConnect => write!(f, "Unable to access docker daemon"),
             Self::DockerInterval => write!(f, "Docker update interval needs to be greater than 0"),
+            Self::ImageLoad => write!(f, "Unable to load images from file"),
+            Self::ImageSave => write!(f, "Unable to save images to file"),
             Self::InputPoll => write!(f, "Unable to poll user input"),
             Self::MouseCapture(x) => {
                 let reason = if *x { "en" } else { "dis" };
@@ -0,0 +1,432 @@
+use std::{
+    collections::HashSet,
+    fs::{self, File},
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+};
+
+use bollard::{
+    container::{DownloadFromContainerOptions, UploadToContainerOptions},
+    Docker,
+};
+use futures_util::StreamExt;
+use hyper::{body::Bytes, Body};
+use serde_json::{Map, Value};
+use tokio::{io::AsyncWriteExt, sync::mpsc, task::JoinHandle};
+
+use super::dockerignore::DockerIgnore;
+use crate::app_data::{
+    container_state::ByteStats,
+    file_state::{FileEntry, FileKind},
+    image_state::Created,
+};
+
+const MANIFEST: &str = "manifest.json";
+const REPOSITORIES: &str = "repositories";
+const INDEX: &str = "index.json";
+
+/// Size of each chunk of an archive sent when uploading to docker
+const UPLOAD_CHUNK: usize = 64 * 1024;
+
+/// Read the whole of a tar entry as json
+fn read_json(entry: &mut impl Read) -> io::Result<Value> {
+    let mut buf = vec![];
+    entry.read_to_end(&mut buf)?;
+    serde_json::from_slice(&buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Add a json file to the end of the output archive
+fn append_json(builder: &mut tar::Builder<File>, name: &str, value: &Value) -> io::Result<()> {
+    let data = value.to_string();
+    let mut header = tar::Header::new_gnu();
+    header.set_size(u64::try_from(data.len()).unwrap_or_default());
+    header.set_mode(0o644);
+    header.set_cksum();
+    builder.append_data(&mut header, name, data.as_bytes())
+}
+
+/// Merge multiple `docker save` archives, each of a single image, into a single archive that `docker load` will accept
+/// Layers and configs are content addressed, so any shared between images are only included once
+/// The manifest, repositories, and OCI index files are combined from each archive
+pub fn merge_image_archives(parts: &[PathBuf], output: &Path) -> io::Result<()> {
+    let mut builder = tar::Builder::new(File::create(output)?);
+    let mut seen = HashSet::new();
+    let mut manifest = vec![];
+    let mut repositories = Map::new();
+    let mut index = None::<Value>;
+
+    for part in parts {
+        let mut archive = tar::Archive::new(File::open(part)?);
+        for entry in archive.entries()? {
+            let mut entry = entry?;
+            let path = entry.path()?.to_string_lossy().to_string();
+            match path.as_str() {
+                MANIFEST => {
+                    if let Value::Array(items) = read_json(&mut entry)? {
+                        manifest.extend(items);
+                    }
+                }
+                REPOSITORIES => {
+                    if let Value::Object(items) = read_json(&mut entry)? {
+                        repositories.extend(items);
+                    }
+                }
+                INDEX => {
+                    let value = read_json(&mut entry)?;
+                    let manifests = value.get("manifests").cloned();
+                    match (index.as_mut(), manifests) {
+                        (Some(Value::Object(existing)), Some(Value::Array(items))) => {
+                            if let Some(Value::Array(all)) = existing.get_mut("manifests") {
+                                all.extend(items);
+                            }
+                        }
+                        _ => index = Some(value),
+                    }
+                }
+                _ => {
+                    if seen.insert(path.clone()) {
+                        // append_data re-writes the path, so long paths are kept intact
+                        let mut header = entry.header().clone();
+                        builder.append_data(&mut header, &path, &mut entry)?;
+                    }
+                }
+            }
+        }
+    }
+
+    append_json(&mut builder, MANIFEST, &Value::Array(manifest))?;
+    if !repositories.is_empty() {
+        append_json(&mut builder, REPOSITORIES, &Value::Object(repositories))?;
+    }
+    if let Some(index) = index {
+        append_json(&mut builder, INDEX, &index)?;
+    }
+    builder.finish()
+}
+
+/// Blocking reader over the chunks of an archive as they are downloaded, so that the tar crate can parse an archive without it all being held in memory
+pub struct ChunkReader {
+    rx: mpsc::Receiver<Vec<u8>>,
+    current: Vec<u8>,
+    position: usize,
+}
+
+impl Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position >= self.current.len() {
+            match self.rx.blocking_recv() {
+                Some(chunk) => {
+                    self.current = chunk;
+                    self.position = 0;
+                }
+                None => return Ok(0),
+            }
+        }
+        let len = buf.len().min(self.current.len() - self.position);
+        buf[..len].copy_from_slice(&self.current[self.position..self.position + len]);
+        self.position += len;
+        Ok(len)
+    }
+}
+
+/// Blocking writer that sends what's written to it, in chunks, over a channel, so that an archive can be uploaded whilst it's being built
+/// If the receiver has been dropped, e.g. docker rejected the upload, writing errors, so the archive stops being built
+pub struct ChunkWriter {
+    tx: mpsc::Sender<io::Result<Bytes>>,
+    buf: Vec<u8>,
+    sent: u64,
+    progress: Box<dyn Fn(u64) + Send>,
+}
+
+impl Write for ChunkWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buf.extend_from_slice(buf);
+        if self.buf.len() >= UPLOAD_CHUNK {
+            self.flush()?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.buf.is_empty() {
+            return Ok(());
+        }
+        let chunk = Bytes::from(std::mem::take(&mut self.buf));
+        self.sent += u64::try_from(chunk.len()).unwrap_or_default();
+        self.tx
+            .blocking_send(Ok(chunk))
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "upload closed"))?;
+        (self.progress)(self.sent);
+        Ok(())
+    }
+}
+
+/// Build a tar archive on a blocking thread, returning a body that streams the archive as it's built, and a handle that resolves to the size of the archive
+/// `progress` is called, on the blocking thread, with the bytes sent so far after each chunk
+/// If the archive can't be built, the body ends with the error, so docker doesn't receive a truncated archive as if it were complete
+pub fn stream_archive<F>(
+    build: F,
+    progress: impl Fn(u64) + Send + 'static,
+) -> (Body, JoinHandle<io::Result<u64>>)
+where
+    F: FnOnce(&mut tar::Builder<ChunkWriter>) -> io::Result<()> + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel(16);
+    let error_tx = tx.clone();
+    let handle = tokio::task::spawn_blocking(move || {
+        let mut builder = tar::Builder::new(ChunkWriter {
+            tx,
+            buf: vec![],
+            sent: 0,
+            progress: Box::new(progress),
+        });
+        let result = build(&mut builder)
+            .and_then(|()| builder.into_inner())
+            .and_then(|mut writer| writer.flush().map(|()| writer.sent));
+        if let Err(e) = &result {
+            error_tx
+                .blocking_send(Err(io::Error::new(e.kind(), e.to_string())))
+                .ok();
+        }
+        result
+    });
+    let chunks = futures_util::stream::unfold(rx, |mut rx| async move {
+        rx.recv().await.map(|chunk| (chunk, rx))
+    });
+    (Body::wrap_stream(chunks), handle)
+}
+
+/// Add the contents of a build context directory to an archive, leaving out the paths excluded by its `.dockerignore`
+/// Symlinks are added as symlinks, rather than followed, the same as `docker build`
+pub fn append_context(
+    builder: &mut tar::Builder<impl Write>,
+    context: &Path,
+    dockerfile: &str,
+) -> io::Result<()> {
+    let ignore = DockerIgnore::read(context, dockerfile)?;
+    builder.follow_symlinks(false);
+    append_context_dir(builder, context, "", &ignore)
+}
+
+/// Add the entries of a single directory of a build context, by their path relative to the root of the context, recursing into each directory
+fn append_context_dir(
+    builder: &mut tar::Builder<impl Write>,
+    context: &Path,
+    directory: &str,
+    ignore: &DockerIgnore,
+) -> io::Result<()> {
+    let mut entries = fs::read_dir(context.join(directory))?.collect::<io::Result<Vec<_>>>()?;
+    entries.sort_by_key(fs::DirEntry::file_name);
+    for entry in entries {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let path = if directory.is_empty() {
+            name
+        } else {
+            format!("{directory}/{name}")
+        };
+        let excluded = ignore.is_excluded(&path);
+        if entry.file_type()?.is_dir() {
+            if !excluded {
+                builder.append_dir(&path, entry.path())?;
+            }
+            if !excluded || !ignore.can_skip(&path) {
+                append_context_dir(builder, context, &path, ignore)?;
+            }
+        } else if !excluded {
+            builder.append_path_with_name(entry.path(), &path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Download a path from a container as a tar archive, and parse it on a blocking thread as it arrives
+/// If the parser finishes early, the rest of the download is abandoned
+pub async fn read_archive<T, F>(
+    docker: &Docker,
+    container: &str,
+    path: &str,
+    parse: F,
+) -> io::Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce(tar::Archive<ChunkReader>) -> io::Result<T> + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel(16);
+    let handle = tokio::task::spawn_blocking(move || {
+        parse(tar::Archive::new(ChunkReader {
+            rx,
+            current: vec![],
+            position: 0,
+        }))
+    });
+
+    let mut stream =
+        docker.download_from_container(container, Some(DownloadFromContainerOptions { path }));
+    let mut error = None;
+    while let Some(chunk) = stream.next().await {
+        match chunk {
+            Ok(chunk) => {
+                if tx.send(chunk.to_vec()).await.is_err() {
+                    break;
+                }
+            }
+            Err(e) => {
+                error = Some(io::Error::other(e));
+                break;
+            }
+        }
+    }
+    drop(tx);
+    let result = handle.await.map_err(io::Error::other)?;
+    error.map_or(result, Err)
+}
+
+/// Download a path from a container, writing the tar archive, as is, to a local file, returns the number of bytes written
+pub async fn download_archive(
+    docker: &Docker,
+    container: &str,
+    path: &str,
+    output: &str,
+) -> io::Result<u64> {
+    let mut file = tokio::fs::File::create(output).await?;
+    let mut stream =
+        docker.download_from_container(container, Some(DownloadFromContainerOptions { path }));
+    let mut written = 0;
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(io::Error::other)?;
+        file.write_all(&chunk).await?;
+        written += u64::try_from(chunk.len()).unwrap_or_default();
+    }
+    file.flush().await?;
+    Ok(written)
+}
+
+/// List the direct children of the archived directory, as the whole directory is archived this is only used when it can't be listed inside the container
+/// An archive of the root directory has no entry for the directory itself, so its children are at the top of the archive
+pub fn list_directory(
+    mut archive: tar::Archive<impl Read>,
+    is_root: bool,
+) -> io::Result<Vec<FileEntry>> {
+    let mut entries = vec![];
+    for entry in archive.entries()? {
+        let entry = entry?;
+        let path = entry.path()?.to_string_lossy().to_string();
+        let path = path
+            .trim_start_matches("./")
+            .trim_start_matches('/')
+            .trim_end_matches('/');
+        if is_root && (path.is_empty() || path == ".") {
+            continue;
+        }
+        // Otherwise the first component is the archived directory itself
+        let mut parts = path.splitn(3, '/').skip(usize::from(!is_root));
+        let (Some(name), None) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        let header = entry.header();
+        let kind = match header.entry_type() {
+            tar::EntryType::Directory => FileKind::Directory,
+            tar::EntryType::Regular | tar::EntryType::Continuous | tar::EntryType::Link => {
+                FileKind::File
+            }
+            tar::EntryType::Symlink => FileKind::Symlink(
+                header
+                    .link_name()?
+                    .map(|i| i.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+            ),
+            _ => FileKind::Other,
+        };
+        entries.push(FileEntry {
+            name: name.to_owned(),
+            kind,
+            mode: header.mode()?,
+            modified: Created::new(header.mtime()?),
+            size: ByteStats::new(header.size()?),
+        });
+    }
+    Ok(entries)
+}
+
+/// Read the archived file as text, erroring if it's larger than the limit, or isn't text
+pub fn read_text(mut archive: tar::Archive<impl Read>, limit: u64) -> io::Result<String> {
+    let mut entry = archive
+        .entries()?
+        .next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "empty archive"))??;
+    if entry.header().entry_type() != tar::EntryType::Regular {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "not a file"));
+    }
+    if entry.size() > limit {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("larger than {}", ByteStats::new(limit)),
+        ));
+    }
+    let mut buf = vec![];
+    entry.read_to_end(&mut buf)?;
+    String::from_utf8(buf)
+        .ok()
+        .filter(|i| !i.contains('\0'))
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "not a text file"))
+}
+
+/// Extract the archived file to a local file
+pub fn save_file(mut archive: tar::Archive<impl Read>, output: &Path) -> io::Result<u64> {
+    let mut entry = archive
+        .entries()?
+        .next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "empty archive"))??;
+    io::copy(&mut entry, &mut File::create(output)?)
+}
+
+/// Extract every entry of the archive into a local directory, calling `progress` with the total bytes extracted so far after each entry
+/// Entries that would be written outside of the directory are skipped by the tar crate
+pub fn unpack(
+    mut archive: tar::Archive<impl Read>,
+    output: &Path,
+    progress: impl Fn(u64),
+) -> io::Result<u64> {
+    let mut total = 0;
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        total += entry.size();
+        entry.unpack_in(output)?;
+        progress(total);
+    }
+    Ok(total)
+}
+
+/// Add a local file, or directory, to an archive, named after the last component of its path
+pub fn pack(builder: &mut tar::Builder<impl Write>, input: &Path) -> io::Result<()> {
+    let name = input
+        .canonicalize()?
+        .file_name()
+        .map(ToOwned::to_owned)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no file name"))?;
+    if input.is_dir() {
+        builder.append_dir_all(name, input)
+    } else {
+        builder.append_path_with_name(input, name)
+    }
+}
+
+/// Upload an archive to a container, extracting it into a directory, the body is usually streamed from `stream_archive`
+pub async fn upload_archive(
+    docker: &Docker,
+    container: &str,
+    path: &str,
+    body: Body,
+) -> io::Result<()> {
+    docker
+        .upload_to_container(
+            container,
+            Some(UploadToContainerOptions {
+                path,
+                no_overwrite_dir_non_dir: "",
+            }),
+            body,
+        )
+        .await
+        .map_err(io::Error::other)
+}
@@ -0,0 +1,930 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+use bollard::{
+    container::{
+        Config, CreateContainerOptions, ListContainersOptions, NetworkingConfig,
+        RemoveContainerOptions, StartContainerOptions,
+    },
+    network::{
+        ConnectNetworkOptions, CreateNetworkOptions, InspectNetworkOptions, ListNetworksOptions,
+    },
+    service::{
+        ContainerSummary, EndpointIpamConfig, EndpointSettings, HostConfig, PortBinding,
+        RestartPolicy, RestartPolicyNameEnum,
+    },
+    volume::CreateVolumeOptions,
+    Docker,
+};
+use serde::Serialize;
+use serde_yaml::Value;
+use sha2::{Digest, Sha256};
+
+use super::DockerData;
+use crate::app_data::container_state::{
+    parse_bytes, parse_cpus, split_command, COMPOSE_CONFIG_FILES, COMPOSE_PROJECT, COMPOSE_SERVICE,
+};
+
+/// Label with the hash of the service config a container was created from, used to decide if a container needs to be recreated
+/// This isn't the `com.docker.compose.config-hash` label, as docker compose hashes the config differently, so each would always recreate the containers of the other
+const CONFIG_HASH: &str = "d5r.config-hash";
+const COMPOSE_WORKING_DIR: &str = "com.docker.compose.project.working_dir";
+const COMPOSE_NETWORK: &str = "com.docker.compose.network";
+const COMPOSE_VOLUME: &str = "com.docker.compose.volume";
+const DEFAULT_NETWORK: &str = "default";
+
+/// Convert a scalar yaml value into a string, numbers and bools are often used unquoted in compose files
+fn string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(x) => Some(x.clone()),
+        Value::Number(x) => Some(x.to_string()),
+        Value::Bool(x) => Some(x.to_string()),
+        _ => None,
+    }
+}
+
+/// A command, or entrypoint, either a string to split, or a list of arguments
+fn command(value: Option<&Value>) -> Option<Vec<String>> {
+    match value? {
+        Value::String(x) => Some(split_command(x)),
+        Value::Sequence(x) => Some(x.iter().filter_map(string).collect()),
+        _ => None,
+    }
+}
+
+/// A list of strings, a single string is treated as a list of one
+fn string_list(value: Option<&Value>) -> Vec<String> {
+    match value {
+        Some(Value::Sequence(x)) => x.iter().filter_map(string).collect(),
+        Some(x) => string(x).into_iter().collect(),
+        None => vec![],
+    }
+}
+
+/// Environment, or labels, either a map, or a list of `KEY=VALUE`
+/// A key without a value is None, for environment this means the value is taken from the host
+fn key_values(value: Option<&Value>) -> BTreeMap<String, Option<String>> {
+    match value {
+        Some(Value::Mapping(x)) => x
+            .iter()
+            .filter_map(|(key, value)| Some((string(key)?, string(value))))
+            .collect(),
+        Some(Value::Sequence(x)) => x
+            .iter()
+            .filter_map(string)
+            .map(|i| match i.split_once('=') {
+                Some((key, value)) => (key.to_owned(), Some(value.to_owned())),
+                None => (i, None),
+            })
+            .collect(),
+        _ => BTreeMap::new(),
+    }
+}
+
+/// Replace `$VAR`, `${VAR}`, `${VAR:-default}`, and `${VAR-default}` with values from the environment, `$$` is a literal `$`
+fn interpolate(input: &str, env: &HashMap<String, String>) -> String {
+    let lookup = |name: &str| env.get(name).cloned().or_else(|| std::env::var(name).ok());
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            output.push(c);
+            continue;
+        }
+        match chars.peek() {
+            Some('$') => {
+                chars.next();
+                output.push('$');
+            }
+            Some('{') => {
+                chars.next();
+                let expression = chars.by_ref().take_while(|i| *i != '}').collect::<String>();
+                let value = if let Some((name, default)) = expression.split_once(":-") {
+                    lookup(name)
+                        .filter(|i| !i.is_empty())
+                        .unwrap_or_else(|| default.to_owned())
+                } else if let Some((name, default)) = expression.split_once('-') {
+                    lookup(name).unwrap_or_else(|| default.to_owned())
+                } else {
+                    lookup(expression.trim_end_matches('?')).unwrap_or_default()
+                };
+                output.push_str(&value);
+            }
+            Some(x) if x.is_ascii_alphabetic() || *x == '_' => {
+                let mut name = String::new();
+                while let Some(x) = chars.next_if(|i| i.is_ascii_alphanumeric() || *i == '_') {
+                    name.push(x);
+                }
+                output.push_str(&lookup(&name).unwrap_or_default());
+            }
+            _ => output.push(c),
+        }
+    }
+    output
+}
+
+/// Parse a service volume, either the short syntax `[source:]target[:mode]`, or the long syntax map
+/// Returns the source, if any, the target, and if the volume is read only
+fn parse_volume(value: &Value) -> (Option<String>, String, bool) {
+    match value {
+        Value::Mapping(x) => {
+            let get = |key: &str| x.get(key).and_then(string);
+            (
+                get("source"),
+                get("target").unwrap_or_default(),
+                x.get("read_only") == Some(&Value::Bool(true)),
+            )
+        }
+        x => {
+            let text = string(x).unwrap_or_default();
+            match text.split(':').collect::<Vec<_>>().as_slice() {
+                [target] => (None, (*target).to_owned(), false),
+                [source, target] => (Some((*source).to_owned()), (*target).to_owned(), false),
+                [source, target, mode, ..] => (
+                    Some((*source).to_owned()),
+                    (*target).to_owned(),
+                    mode.split(',').any(|i| i == "ro"),
+                ),
+                [] => (None, String::new(), false),
+            }
+        }
+    }
+}
+
+/// A restart policy, `on-failure` can have a maximum retry count, e.g. `on-failure:3`
+pub fn restart_policy(restart: &str) -> RestartPolicy {
+    let (name, count) = restart
+        .split_once(':')
+        .map_or((restart, None), |(name, count)| {
+            (name, count.parse::<i64>().ok())
+        });
+    let name = match name {
+        "always" => RestartPolicyNameEnum::ALWAYS,
+        "unless-stopped" => RestartPolicyNameEnum::UNLESS_STOPPED,
+        "on-failure" => RestartPolicyNameEnum::ON_FAILURE,
+        _ => RestartPolicyNameEnum::NO,
+    };
+    RestartPolicy {
+        name: Some(name),
+        maximum_retry_count: count,
+    }
+}
+
+/// Read the `.env` file next to the compose file, if there is one
+fn dotenv(dir: &Path) -> HashMap<String, String> {
+    std::fs::read_to_string(dir.join(".env"))
+        .unwrap_or_default()
+        .lines()
+        .map(str::trim)
+        .filter(|i| !i.starts_with('#'))
+        .filter_map(|i| i.split_once('='))
+        .map(|(key, value)| {
+            (
+                key.trim().to_owned(),
+                value.trim().trim_matches(['"', '\'']).to_owned(),
+            )
+        })
+        .collect()
+}
+
+/// A top level network, or volume, `name` is the name of the docker object, `key` is the name used in the compose file
+#[derive(Debug, Clone)]
+struct ComposeResource {
+    key: String,
+    name: String,
+    driver: Option<String>,
+    external: bool,
+}
+
+impl ComposeResource {
+    /// Read a top level network, or volume, by default named with the project as a prefix, unless external
+    fn new(project: &str, key: &str, value: &Value) -> Self {
+        let external = value
+            .get("external")
+            .is_some_and(|i| i != &Value::Bool(false));
+        let name = value.get("name").and_then(string).unwrap_or_else(|| {
+            if external {
+                key.to_owned()
+            } else {
+                format!("{project}_{key}")
+            }
+        });
+        Self {
+            key: key.to_owned(),
+            name,
+            driver: value.get("driver").and_then(string),
+            external,
+        }
+    }
+
+    /// Read all of the networks, or volumes, from a top level section
+    fn all(project: &str, value: Option<&Value>) -> Vec<Self> {
+        value.and_then(Value::as_mapping).map_or(vec![], |x| {
+            x.iter()
+                .filter_map(|(key, value)| Some(Self::new(project, &string(key)?, value)))
+                .collect()
+        })
+    }
+}
+
+/// A published, or exposed, port of a service
+#[derive(Debug, Clone, Serialize)]
+struct ServicePort {
+    container: String,
+    host_ip: Option<String>,
+    host_port: Option<String>,
+    published: bool,
+}
+
+impl ServicePort {
+    /// Parse the short syntax `[[host_ip:]host_port:]container_port[/protocol]`, or the long syntax map
+    fn new(value: &Value) -> Result<Self, String> {
+        if let Some(x) = value.as_mapping() {
+            let get = |key: &str| x.get(key).and_then(string);
+            let protocol = get("protocol").unwrap_or_else(|| String::from("tcp"));
+            return Ok(Self {
+                container: format!(
+                    "{}/{protocol}",
+                    get("target").ok_or("port is missing a target")?
+                ),
+                host_ip: get("host_ip"),
+                host_port: get("published"),
+                published: true,
+            });
+        }
+        let text = string(value).ok_or("invalid port")?;
+        let (text, protocol) = text.split_once('/').unwrap_or((&text, "tcp"));
+        let mut parts = text.rsplitn(3, ':');
+        let container = parts.next().unwrap_or_default().to_owned();
+        if container.contains('-') {
+            return Err(format!("port ranges are not supported: {text}"));
+        }
+        Ok(Self {
+            container: format!("{container}/{protocol}"),
+            host_port: parts.next().map(ToOwned::to_owned),
+            host_ip: parts.next().map(ToOwned::to_owned),
+            published: true,
+        })
+    }
+}
+
+/// A network a service is attached to, by the docker network name
+#[derive(Debug, Clone, Serialize)]
+struct ServiceNetwork {
+    name: String,
+    aliases: Vec<String>,
+    ipv4_address: Option<String>,
+}
+
+impl ServiceNetwork {
+    /// The endpoint settings to attach a container to this network, the service name is always an alias
+    fn endpoint(&self, service: &str) -> EndpointSettings {
+        let mut aliases = vec![service.to_owned()];
+        aliases.extend(self.aliases.iter().cloned());
+        EndpointSettings {
+            aliases: Some(aliases),
+            ipam_config: self.ipv4_address.as_ref().map(|ip| EndpointIpamConfig {
+                ipv4_address: Some(ip.clone()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+/// A service of a compose file, only the settings that map to a container config are read, anything else is ignored
+#[derive(Debug, Clone, Serialize)]
+pub struct ComposeService {
+    pub name: String,
+    container_name: Option<String>,
+    image: String,
+    command: Option<Vec<String>>,
+    entrypoint: Option<Vec<String>>,
+    environment: BTreeMap<String, String>,
+    labels: BTreeMap<String, String>,
+    ports: Vec<ServicePort>,
+    binds: Vec<String>,
+    anonymous_volumes: Vec<String>,
+    networks: Vec<ServiceNetwork>,
+    network_mode: Option<String>,
+    restart: Option<String>,
+    depends_on: Vec<String>,
+    working_dir: Option<String>,
+    user: Option<String>,
+    hostname: Option<String>,
+    ipc: Option<String>,
+    shm_size: Option<i64>,
+    memory: Option<i64>,
+    nano_cpus: Option<i64>,
+    tty: bool,
+    stdin_open: bool,
+}
+
+impl ComposeService {
+    /// Parse a single service, named volumes and networks must be declared in the top level sections
+    #[allow(clippy::too_many_lines)]
+    fn new(
+        name: &str,
+        value: &Value,
+        dir: &Path,
+        networks: &[ComposeResource],
+        volumes: &[ComposeResource],
+    ) -> Result<Self, String> {
+        let get = |key: &str| value.get(key);
+        let get_string = |key: &str| get(key).and_then(string);
+        let image = get_string("image").ok_or_else(|| {
+            format!(
+                "service {name} has no image, building images from a compose file is not supported"
+            )
+        })?;
+
+        let environment = key_values(get("environment"))
+            .into_iter()
+            .filter_map(|(key, value)| {
+                value
+                    .or_else(|| std::env::var(&key).ok())
+                    .map(|value| (key, value))
+            })
+            .collect();
+        let labels = key_values(get("labels"))
+            .into_iter()
+            .map(|(key, value)| (key, value.unwrap_or_default()))
+            .collect();
+
+        let mut ports = get("ports")
+            .and_then(Value::as_sequence)
+            .map_or(Ok(vec![]), |x| {
+                x.iter()
+                    .map(ServicePort::new)
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .map_err(|e| format!("service {name}: {e}"))?;
+        ports.extend(string_list(get("expose")).into_iter().map(|i| ServicePort {
+            container: if i.contains('/') {
+                i
+            } else {
+                format!("{i}/tcp")
+            },
+            host_ip: None,
+            host_port: None,
+            published: false,
+        }));
+
+        let mut binds = vec![];
+        let mut anonymous_volumes = vec![];
+        for volume in get("volumes")
+            .and_then(Value::as_sequence)
+            .into_iter()
+            .flatten()
+        {
+            let (source, target, read_only) = parse_volume(volume);
+            let mode = if read_only { ":ro" } else { "" };
+            match source {
+                None => anonymous_volumes.push(target),
+                Some(source) if source.starts_with(['.', '/', '~']) => {
+                    let source = source.strip_prefix('~').map_or_else(
+                        || dir.join(&source).to_string_lossy().to_string(),
+                        |home| format!("{}{home}", std::env::var("HOME").unwrap_or_default()),
+                    );
+                    binds.push(format!("{source}:{target}{mode}"));
+                }
+                Some(source) => {
+                    let volume = volumes
+                        .iter()
+                        .find(|i| i.key == source)
+                        .ok_or_else(|| format!("service {name} uses undefined volume {source}"))?;
+                    binds.push(format!("{}:{target}{mode}", volume.name));
+                }
+            }
+        }
+
+        let network_mode = get_string("network_mode");
+        let service_networks = match get("networks") {
+            Some(Value::Mapping(x)) => x
+                .iter()
+                .filter_map(|(key, value)| {
+                    Some((
+                        string(key)?,
+                        string_list(value.get("aliases")),
+                        value.get("ipv4_address").and_then(string),
+                    ))
+                })
+                .collect(),
+            Some(x) => string_list(Some(x))
+                .into_iter()
+                .map(|i| (i, vec![], None))
+                .collect(),
+            None if network_mode.is_none() => vec![(DEFAULT_NETWORK.to_owned(), vec![], None)],
+            None => vec![],
+        };
+        let networks = service_networks
+            .into_iter()
+            .map(|(key, aliases, ipv4_address)| {
+                networks
+                    .iter()
+                    .find(|i| i.key == key)
+                    .map(|i| ServiceNetwork {
+                        name: i.name.clone(),
+                        aliases,
+                        ipv4_address,
+                    })
+                    .ok_or_else(|| format!("service {name} uses undefined network {key}"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let depends_on = match get("depends_on") {
+            Some(Value::Mapping(x)) => x.keys().filter_map(string).collect(),
+            x => string_list(x),
+        };
+
+        let limits = get("deploy")
+            .and_then(|i| i.get("resources"))
+            .and_then(|i| i.get("limits"));
+        let memory = get("mem_limit")
+            .or_else(|| limits.and_then(|i| i.get("memory")))
+            .and_then(string)
+            .and_then(|i| parse_bytes(&i));
+        let nano_cpus = get("cpus")
+            .or_else(|| limits.and_then(|i| i.get("cpus")))
+            .and_then(string)
+            .and_then(|i| parse_cpus(&i));
+
+        Ok(Self {
+            name: name.to_owned(),
+            container_name: get_string("container_name"),
+            image,
+            command: command(get("command")),
+            entrypoint: command(get("entrypoint")),
+            environment,
+            labels,
+            ports,
+            binds,
+            anonymous_volumes,
+            networks,
+            network_mode,
+            restart: get_string("restart"),
+            depends_on,
+            working_dir: get_string("working_dir"),
+            user: get_string("user"),
+            hostname: get_string("hostname"),
+            ipc: get_string("ipc"),
+            shm_size: get_string("shm_size").and_then(|i| parse_bytes(&i)),
+            memory,
+            nano_cpus,
+            tty: get("tty") == Some(&Value::Bool(true)),
+            stdin_open: get("stdin_open") == Some(&Value::Bool(true)),
+        })
+    }
+
+    /// Sha256 hash of the service settings, serialized as json, if this changes, the container needs to be recreated
+    /// Fields are serialized in the order they're declared, and maps are sorted, so the hash is the same on every run
+    fn config_hash(&self) -> String {
+        let json = serde_json::to_vec(self).unwrap_or_default();
+        Sha256::digest(json)
+            .iter()
+            .fold(String::new(), |mut output, byte| {
+                let _ = write!(output, "{byte:02x}");
+                output
+            })
+    }
+
+    /// Container name, either as set in the compose file, or the same as docker compose would use
+    fn container_name(&self, project: &str) -> String {
+        self.container_name
+            .clone()
+            .unwrap_or_else(|| format!("{project}-{}-1", self.name))
+    }
+
+    /// The config to create the container with, only the first network can be set at creation, the rest are connected afterwards
+    #[allow(clippy::zero_sized_map_values)]
+    fn config(&self, project: &ComposeProject) -> Config<String> {
+        let mut labels = self
+            .labels
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect::<HashMap<_, _>>();
+        for (key, value) in [
+            (COMPOSE_PROJECT, project.name.clone()),
+            (COMPOSE_SERVICE, self.name.clone()),
+            (CONFIG_HASH, self.config_hash()),
+            (
+                COMPOSE_CONFIG_FILES,
+                project.file.to_string_lossy().to_string(),
+            ),
+            (
+                COMPOSE_WORKING_DIR,
+                project.dir.to_string_lossy().to_string(),
+            ),
+            ("com.docker.compose.container-number", String::from("1")),
+            ("com.docker.compose.oneoff", String::from("False")),
+        ] {
+            labels.insert(key.to_owned(), value);
+        }
+
+        let mut port_bindings = HashMap::<String, Option<Vec<PortBinding>>>::new();
+        for port in self.ports.iter().filter(|i| i.published) {
+            port_bindings
+                .entry(port.container.clone())
+                .or_default()
+                .get_or_insert_with(Vec::new)
+                .push(PortBinding {
+                    host_ip: port.host_ip.clone(),
+                    host_port: port.host_port.clone(),
+                });
+        }
+        let empty = || HashMap::<(), ()>::new();
+        let first_network = self.networks.first();
+
+        Config {
+            image: Some(self.image.clone()),
+            cmd: self.command.clone(),
+            entrypoint: self.entrypoint.clone(),
+            env: Some(
+                self.environment
+                    .iter()
+                    .map(|(key, value)| format!("{key}={value}"))
+                    .collect(),
+            ),
+            labels: Some(labels),
+            exposed_ports: Some(
+                self.ports
+                    .iter()
+                    .map(|i| (i.container.clone(), empty()))
+                    .collect(),
+            ),
+            volumes: Some(
+                self.anonymous_volumes
+                    .iter()
+                    .map(|i| (i.clone(), empty()))
+                    .collect(),
+            ),
+            working_dir: self.working_dir.clone(),
+            user: self.user.clone(),
+            hostname: self.hostname.clone(),
+            tty: Some(self.tty),
+            open_stdin: Some(self.stdin_open),
+            host_config: Some(HostConfig {
+                binds: Some(self.binds.clone()),
+                port_bindings: Some(port_bindings),
+                restart_policy: self.restart.as_deref().map(restart_policy),
+                memory: self.memory,
+                nano_cpus: self.nano_cpus,
+                shm_size: self.shm_size,
+                ipc_mode: self.ipc.clone(),
+                network_mode: self
+                    .network_mode
+                    .clone()
+                    .or_else(|| first_network.map(|i| i.name.clone())),
+                ..Default::default()
+            }),
+            networking_config: first_network.map(|i| NetworkingConfig {
+                endpoints_config: HashMap::from([(i.name.clone(), i.endpoint(&self.name))]),
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+/// A compose file, read from disk, with the services in the order they need to be started
+#[derive(Debug, Clone)]
+pub struct ComposeProject {
+    pub name: String,
+    file: PathBuf,
+    dir: PathBuf,
+    services: Vec<ComposeService>,
+    networks: Vec<ComposeResource>,
+    volumes: Vec<ComposeResource>,
+}
+
+impl ComposeProject {
+    /// Read, and parse, a compose file
+    /// The project name is either set in the file, or is the name of the directory that contains the file
+    pub fn load(file: &str) -> Result<Self, String> {
+        let path =
+            std::fs::canonicalize(file).map_err(|e| format!("unable to read {file}: {e}"))?;
+        let dir = path.parent().map_or_else(PathBuf::new, Path::to_path_buf);
+        let text =
+            std::fs::read_to_string(&path).map_err(|e| format!("unable to read {file}: {e}"))?;
+        let root = serde_yaml::from_str::<Value>(&interpolate(&text, &dotenv(&dir)))
+            .map_err(|e| format!("unable to parse {file}: {e}"))?;
+
+        let name = root
+            .get("name")
+            .and_then(string)
+            .or_else(|| dir.file_name().map(|i| i.to_string_lossy().to_string()))
+            .unwrap_or_default()
+            .to_lowercase()
+            .chars()
+            .filter(|i| i.is_ascii_alphanumeric() || *i == '_' || *i == '-')
+            .collect::<String>();
+        if name.is_empty() {
+            return Err(format!("unable to find a project name for {file}"));
+        }
+
+        let mut networks = ComposeResource::all(&name, root.get("networks"));
+        if !networks.iter().any(|i| i.key == DEFAULT_NETWORK) {
+            networks.push(ComposeResource::new(&name, DEFAULT_NETWORK, &Value::Null));
+        }
+        let volumes = ComposeResource::all(&name, root.get("volumes"));
+
+        let services = root
+            .get("services")
+            .and_then(Value::as_mapping)
+            .ok_or_else(|| format!("no services found in {file}"))?
+            .iter()
+            .filter_map(|(key, value)| Some((string(key)?, value)))
+            .map(|(key, value)| ComposeService::new(&key, value, &dir, &networks, &volumes))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Only the networks used by a service are created
+        networks.retain(|network| {
+            services
+                .iter()
+                .any(|i| i.networks.iter().any(|i| i.name == network.name))
+        });
+
+        Ok(Self {
+            name,
+            file: path,
+            dir,
+            services: Self::dependency_order(services)?,
+            networks,
+            volumes,
+        })
+    }
+
+    /// Order the services so that each service comes after the services it depends on
+    fn dependency_order(mut services: Vec<ComposeService>) -> Result<Vec<ComposeService>, String> {
+        let mut ordered = Vec::<ComposeService>::with_capacity(services.len());
+        while !services.is_empty() {
+            let ready = services.iter().position(|service| {
+                service.depends_on.iter().all(|dependency| {
+                    ordered.iter().any(|i| &i.name == dependency)
+                        || !services.iter().any(|i| &i.name == dependency)
+                })
+            });
+            let Some(index) = ready else {
+                let names = services.iter().map(|i| i.name.as_str()).collect::<Vec<_>>();
+                return Err(format!("circular depends_on between {}", names.join(", ")));
+            };
+            ordered.push(services.remove(index));
+        }
+        Ok(ordered)
+    }
+
+    /// Filter for docker objects labelled with this project
+    fn filters(&self) -> HashMap<String, Vec<String>> {
+        HashMap::from([(
+            String::from("label"),
+            vec![format!("{COMPOSE_PROJECT}={}", self.name)],
+        )])
+    }
+
+    /// All of the containers, running or not, labelled with this project
+    async fn containers(&self, docker: &Docker) -> Result<Vec<ContainerSummary>, String> {
+        docker
+            .list_containers(Some(ListContainersOptions {
+                all: true,
+                filters: self.filters(),
+                ..Default::default()
+            }))
+            .await
+            .map_err(|e| format!("unable to list containers of {}: {e}", self.name))
+    }
+
+    /// The names of the containers, labelled with this project, that `down` would remove
+    pub async fn container_names(&self, docker: &Docker) -> Result<Vec<String>, String> {
+        Ok(self
+            .containers(docker)
+            .await?
+            .into_iter()
+            .filter_map(|i| {
+                i.names?
+                    .first()
+                    .map(|i| i.trim_start_matches('/').to_owned())
+            })
+            .collect())
+    }
+
+    /// Create any networks and volumes that don't exist yet, external ones must already exist
+    async fn create_resources(&self, docker: &Docker) -> Result<(), String> {
+        for network in &self.networks {
+            if docker
+                .inspect_network(&network.name, None::<InspectNetworkOptions<String>>)
+                .await
+                .is_ok()
+            {
+                continue;
+            }
+            if network.external {
+                return Err(format!("external network {} not found", network.name));
+            }
+            docker
+                .create_network(CreateNetworkOptions {
+                    name: network.name.clone(),
+                    check_duplicate: true,
+                    driver: network
+                        .driver
+                        .clone()
+                        .unwrap_or_else(|| String::from("bridge")),
+                    labels: HashMap::from([
+                        (COMPOSE_PROJECT.to_owned(), self.name.clone()),
+                        (COMPOSE_NETWORK.to_owned(), network.key.clone()),
+                    ]),
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| format!("unable to create network {}: {e}", network.name))?;
+        }
+        for volume in &self.volumes {
+            if docker.inspect_volume(&volume.name).await.is_ok() {
+                continue;
+            }
+            if volume.external {
+                return Err(format!("external volume {} not found", volume.name));
+            }
+            docker
+                .create_volume(CreateVolumeOptions {
+                    name: volume.name.clone(),
+                    driver: volume
+                        .driver
+                        .clone()
+                        .unwrap_or_else(|| String::from("local")),
+                    labels: HashMap::from([
+                        (COMPOSE_PROJECT.to_owned(), self.name.clone()),
+                        (COMPOSE_VOLUME.to_owned(), volume.key.clone()),
+                    ]),
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| format!("unable to create volume {}: {e}", volume.name))?;
+        }
+        Ok(())
+    }
+
+    /// Create, and start, the container of a service
+    async fn create_service(
+        &self,
+        docker: &Docker,
+        service: &ComposeService,
+    ) -> Result<(), String> {
+        DockerData::pull_missing_image(docker, &service.image).await?;
+        let name = service.container_name(&self.name);
+        let id = docker
+            .create_container(
+                Some(CreateContainerOptions {
+                    name: name.clone(),
+                    platform: None,
+                }),
+                service.config(self),
+            )
+            .await
+            .map_err(|e| format!("unable to create {name}: {e}"))?
+            .id;
+        for network in service.networks.iter().skip(1) {
+            docker
+                .connect_network(
+                    &network.name,
+                    ConnectNetworkOptions {
+                        container: id.clone(),
+                        endpoint_config: network.endpoint(&service.name),
+                    },
+                )
+                .await
+                .map_err(|e| format!("unable to connect {name} to {}: {e}", network.name))?;
+        }
+        docker
+            .start_container(&id, None::<StartContainerOptions<String>>)
+            .await
+            .map_err(|e| format!("unable to start {name}: {e}"))
+    }
+
+    /// Stop, and remove, a container
+    async fn remove_container(docker: &Docker, container: &ContainerSummary) -> Result<(), String> {
+        let id = container.id.clone().unwrap_or_default();
+        docker.stop_container(&id, None).await.ok();
+        docker
+            .remove_container(
+                &id,
+                Some(RemoveContainerOptions {
+                    force: true,
+                    ..Default::default()
+                }),
+            )
+            .await
+            .map_err(|e| format!("unable to remove {id}: {e}"))
+    }
+
+    /// The value of a label of a container
+    fn label<'a>(container: &'a ContainerSummary, label: &str) -> Option<&'a str> {
+        container
+            .labels
+            .as_ref()
+            .and_then(|i| i.get(label))
+            .map(String::as_str)
+    }
+
+    /// The equivalent of `docker compose up -d`
+    /// Containers with an unchanged config are started if needed, changed ones are recreated, missing ones are created
+    pub async fn up(&self, docker: &Docker, status: impl Fn(String)) -> Result<String, String> {
+        self.create_resources(docker).await?;
+        let containers = self.containers(docker).await?;
+        let (mut created, mut recreated, mut started, mut unchanged) = (0, 0, 0, 0);
+        for service in &self.services {
+            status(format!("compose up {}", service.name));
+            let existing = containers
+                .iter()
+                .find(|i| Self::label(i, COMPOSE_SERVICE) == Some(service.name.as_str()));
+            match existing {
+                Some(container)
+                    if Self::label(container, CONFIG_HASH)
+                        == Some(service.config_hash().as_str()) =>
+                {
+                    if container.state.as_deref() == Some("running") {
+                        unchanged += 1;
+                    } else {
+                        let id = container.id.clone().unwrap_or_default();
+                        docker
+                            .start_container(&id, None::<StartContainerOptions<String>>)
+                            .await
+                            .map_err(|e| format!("unable to start {}: {e}", service.name))?;
+                        started += 1;
+                    }
+                }
+                Some(container) => {
+                    Self::remove_container(docker, container).await?;
+                    self.create_service(docker, service).await?;
+                    recreated += 1;
+                }
+                None => {
+                    self.create_service(docker, service).await?;
+                    created += 1;
+                }
+            }
+        }
+        Ok(format!(
+            "{}: {created} created, {recreated} recreated, {started} started, {unchanged} up to date",
+            self.name
+        ))
+    }
+
+    /// The equivalent of `docker compose down`, remove the containers and networks of the project, volumes are kept
+    pub async fn down(&self, docker: &Docker, status: impl Fn(String)) -> Result<String, String> {
+        let containers = self.containers(docker).await?;
+        for container in &containers {
+            status(format!(
+                "compose down {}",
+                Self::label(container, COMPOSE_SERVICE).unwrap_or_default()
+            ));
+            Self::remove_container(docker, container).await?;
+        }
+        let networks = docker
+            .list_networks(Some(ListNetworksOptions {
+                filters: self.filters(),
+            }))
+            .await
+            .map_err(|e| format!("unable to list networks of {}: {e}", self.name))?;
+        for network in &networks {
+            let name = network.name.clone().unwrap_or_default();
+            docker
+                .remove_network(&name)
+                .await
+                .map_err(|e| format!("unable to remove network {name}: {e}"))?;
+        }
+        Ok(format!(
+            "{}: removed {} containers, {} networks",
+            self.name,
+            containers.len(),
+            networks.len()
+        ))
+    }
+
+    /// Remove the container of a single service, and create it again, with the current config from the compose file
+    pub async fn recreate(
+        &self,
+        docker: &Docker,
+        service: &str,
+        status: impl Fn(String),
+    ) -> Result<String, String> {
+        let compose_service = self
+            .services
+            .iter()
+            .find(|i| i.name == service)
+            .ok_or_else(|| format!("service {service} not found in {}", self.name))?;
+        status(format!("compose recreate {service}"));
+        self.create_resources(docker).await?;
+        for container in self
+            .containers(docker)
+            .await?
+            .iter()
+            .filter(|i| Self::label(i, COMPOSE_SERVICE) == Some(service))
+        {
+            Self::remove_container(docker, container).await?;
+        }
+        self.create_service(docker, compose_service).await?;
+        Ok(format!("{}: recreated {service}", self.name))
+    }
+}
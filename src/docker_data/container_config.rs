@@ -0,0 +1,478 @@
+use std::collections::HashMap;
+
+use bollard::{
+    container::{Config, NetworkingConfig},
+    service::{
+        ContainerConfig, ContainerInspectResponse, EndpointSettings, HostConfig,
+        MountPointTypeEnum, MountTypeEnum, PortBinding, RestartPolicyNameEnum,
+    },
+};
+
+use serde_yaml::{Mapping, Value};
+
+use super::compose;
+use crate::app_data::container_state::{ContainerId, ContainerRun, RunPort};
+
+/// Set the parts of a container config that a ContainerRun covers, anything else in the config is left as is
+/// Ports are added to the exposed ports, and a volume without a source is added as an anonymous volume
+#[allow(clippy::zero_sized_map_values)]
+pub fn apply_run(config: &mut Config<String>, run: &ContainerRun) {
+    let mut port_bindings = HashMap::<String, Option<Vec<PortBinding>>>::new();
+    for port in &run.ports {
+        port_bindings
+            .entry(port.container.clone())
+            .or_default()
+            .get_or_insert_with(Vec::new)
+            .push(PortBinding {
+                host_ip: port.host_ip.clone(),
+                host_port: port.host_port.clone(),
+            });
+    }
+    let (binds, anonymous) = run
+        .volumes
+        .iter()
+        .cloned()
+        .partition::<Vec<_>, _>(|i| i.contains(':'));
+
+    config.image = Some(run.image.clone());
+    if !run.command.is_empty() {
+        config.cmd = Some(run.command.clone());
+    }
+    config.env = Some(run.env.clone());
+    config
+        .exposed_ports
+        .get_or_insert_with(HashMap::new)
+        .extend(port_bindings.keys().map(|i| (i.clone(), HashMap::new())));
+    config
+        .volumes
+        .get_or_insert_with(HashMap::new)
+        .extend(anonymous.into_iter().map(|i| (i, HashMap::new())));
+
+    let host_config = config.host_config.get_or_insert_with(HostConfig::default);
+    host_config.binds = Some(binds);
+    host_config.port_bindings = Some(port_bindings);
+    if run.network.is_some() {
+        host_config.network_mode.clone_from(&run.network);
+    }
+    host_config.restart_policy = Some(compose::restart_policy(&run.restart));
+    host_config.memory = run.memory;
+    host_config.nano_cpus = run.nano_cpus;
+}
+
+/// Read the parts of a container config that a ContainerRun covers, so they can be edited
+pub fn to_run(config: &Config<String>) -> ContainerRun {
+    let host_config = config.host_config.clone().unwrap_or_default();
+    let not_empty = |i: &Option<String>| i.clone().filter(|i| !i.is_empty());
+    let mut ports = host_config
+        .port_bindings
+        .unwrap_or_default()
+        .into_iter()
+        .flat_map(|(container, bindings)| {
+            bindings
+                .unwrap_or_default()
+                .into_iter()
+                .map(move |binding| RunPort {
+                    container: container.clone(),
+                    host_ip: not_empty(&binding.host_ip),
+                    host_port: not_empty(&binding.host_port),
+                })
+        })
+        .collect::<Vec<_>>();
+    ports.sort_by_key(ToString::to_string);
+
+    let restart = host_config.restart_policy.map_or_else(
+        || String::from("no"),
+        |policy| match (policy.name, policy.maximum_retry_count) {
+            (Some(RestartPolicyNameEnum::ON_FAILURE), Some(count)) if count > 0 => {
+                format!("on-failure:{count}")
+            }
+            (Some(name), _) if name != RestartPolicyNameEnum::EMPTY => name.to_string(),
+            _ => String::from("no"),
+        },
+    );
+
+    ContainerRun {
+        image: config.image.clone().unwrap_or_default(),
+        name: None,
+        command: config.cmd.clone().unwrap_or_default(),
+        env: config.env.clone().unwrap_or_default(),
+        ports,
+        volumes: host_config.binds.unwrap_or_default(),
+        network: host_config.network_mode,
+        restart,
+        memory: host_config.memory.filter(|i| *i > 0),
+        nano_cpus: host_config.nano_cpus.filter(|i| *i > 0),
+    }
+}
+
+/// Everything needed to create an identical copy of a container, from its inspect
+#[derive(Debug, Clone)]
+pub struct Recreate {
+    pub name: String,
+    pub running: bool,
+    pub config: Config<String>,
+    pub networks: HashMap<String, EndpointSettings>,
+}
+
+impl Recreate {
+    /// Volumes, including anonymous ones, are mounted by name, so that the copy keeps the same data
+    /// Only the network of the network mode can be set at creation, the rest of the networks are connected afterwards
+    pub fn new(id: &ContainerId, info: ContainerInspectResponse) -> Self {
+        let name = info
+            .name
+            .unwrap_or_default()
+            .trim_start_matches('/')
+            .to_owned();
+        let running = info.state.and_then(|i| i.running).unwrap_or_default();
+
+        let mut config = Config::from(info.config.unwrap_or_default());
+        // The default hostname is the short id, which would be wrong for the copy
+        if config
+            .hostname
+            .as_ref()
+            .is_some_and(|i| id.get().starts_with(i.as_str()))
+        {
+            config.hostname = None;
+        }
+
+        let mut host_config = info.host_config.unwrap_or_default();
+        host_config.binds = Some(
+            info.mounts
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|mount| {
+                    let source = match mount.typ? {
+                        MountPointTypeEnum::BIND => mount.source?,
+                        MountPointTypeEnum::VOLUME => mount.name?,
+                        _ => return None,
+                    };
+                    let mode = if mount.rw == Some(false) { ":ro" } else { "" };
+                    Some(format!("{source}:{}{mode}", mount.destination?))
+                })
+                .collect(),
+        );
+        // Bind and volume mounts are now all binds, so only keep the other mounts, e.g. tmpfs
+        host_config.mounts = host_config.mounts.map(|mounts| {
+            mounts
+                .into_iter()
+                .filter(|i| !matches!(i.typ, Some(MountTypeEnum::BIND | MountTypeEnum::VOLUME)))
+                .collect()
+        });
+        // The network mode of the default bridge network is `default`
+        let network_mode = match host_config.network_mode.as_deref() {
+            Some("default") | None => String::from("bridge"),
+            Some(x) => x.to_owned(),
+        };
+        config.host_config = Some(host_config);
+
+        // The short id alias, and the addresses, belong to the original container
+        let endpoint = |settings: EndpointSettings| EndpointSettings {
+            aliases: settings.aliases.map(|aliases| {
+                aliases
+                    .into_iter()
+                    .filter(|i| !id.get().starts_with(i.as_str()))
+                    .collect()
+            }),
+            ipam_config: settings.ipam_config,
+            links: settings.links,
+            ..Default::default()
+        };
+        let mut networks = info
+            .network_settings
+            .and_then(|i| i.networks)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, settings)| (name, endpoint(settings)))
+            .collect::<HashMap<_, _>>();
+        config.networking_config = networks
+            .remove_entry(&network_mode)
+            .map(|(name, settings)| NetworkingConfig {
+                endpoints_config: HashMap::from([(name, settings)]),
+            });
+
+        Self {
+            name,
+            running,
+            config,
+            networks,
+        }
+    }
+}
+
+/// Quote a value for a shell, only if it has characters that a shell would treat specially
+fn quote(value: &str) -> String {
+    let safe = |c: char| c.is_ascii_alphanumeric() || "_-./:=@,%+".contains(c);
+    if !value.is_empty() && value.chars().all(safe) {
+        value.to_owned()
+    } else {
+        format!("'{}'", value.replace('\'', r"'\''"))
+    }
+}
+
+/// A network mode that isn't the default bridge, and so needs to be given when creating the container
+fn custom_network(network_mode: Option<&str>) -> Option<&str> {
+    network_mode.filter(|i| !matches!(*i, "" | "default" | "bridge"))
+}
+
+/// Remove everything from a container config that the container would get from its image anyway, and the labels added by compose
+/// If the entrypoint has been changed, the image command isn't used, so the command is always kept
+fn without_image_defaults(config: &Config<String>, image: &ContainerConfig) -> Config<String> {
+    let image_env = image.env.clone().unwrap_or_default();
+    let image_labels = image.labels.clone().unwrap_or_default();
+    let image_ports = image.exposed_ports.clone().unwrap_or_default();
+    let changed = |value: &Option<String>, default: &Option<String>| {
+        value
+            .clone()
+            .filter(|i| !i.is_empty() && Some(i) != default.as_ref())
+    };
+    let entrypoint_changed = config.entrypoint != image.entrypoint;
+    Config {
+        hostname: config.hostname.clone(),
+        user: changed(&config.user, &image.user),
+        working_dir: changed(&config.working_dir, &image.working_dir),
+        entrypoint: config.entrypoint.clone().filter(|_| entrypoint_changed),
+        cmd: config
+            .cmd
+            .clone()
+            .filter(|i| entrypoint_changed || Some(i) != image.cmd.as_ref()),
+        env: config.env.as_ref().map(|env| {
+            env.iter()
+                .filter(|i| !image_env.contains(i))
+                .cloned()
+                .collect()
+        }),
+        labels: config.labels.as_ref().map(|labels| {
+            labels
+                .iter()
+                .filter(|(key, value)| {
+                    !key.starts_with("com.docker.compose.")
+                        && image_labels.get(*key) != Some(*value)
+                })
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect()
+        }),
+        exposed_ports: config.exposed_ports.as_ref().map(|ports| {
+            ports
+                .iter()
+                .filter(|(port, _)| !image_ports.contains_key(*port))
+                .map(|(port, value)| (port.clone(), value.clone()))
+                .collect()
+        }),
+        tty: config.tty,
+        open_stdin: config.open_stdin,
+        ..Default::default()
+    }
+}
+
+/// Sorted `KEY=VALUE` labels
+fn sorted_labels(config: &Config<String>) -> Vec<String> {
+    let mut labels = config
+        .labels
+        .as_ref()
+        .map(|labels| {
+            labels
+                .iter()
+                .map(|(key, value)| format!("{key}={value}"))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    labels.sort();
+    labels
+}
+
+/// Convert a container into an equivalent `docker run` command, any networks past the first are connected with `docker network connect`
+pub fn run_command(recreate: &Recreate, image: &ContainerConfig) -> String {
+    let config = without_image_defaults(&recreate.config, image);
+    let host_config = recreate.config.host_config.clone().unwrap_or_default();
+    let run = to_run(&recreate.config);
+    let flag = |name: &str, value: &str| format!("--{name} {}", quote(value));
+
+    let mut args = vec![String::from("docker run -d"), flag("name", &recreate.name)];
+    if let Some(hostname) = &config.hostname {
+        args.push(flag("hostname", hostname));
+    }
+    if let Some(user) = &config.user {
+        args.push(flag("user", user));
+    }
+    if let Some(working_dir) = &config.working_dir {
+        args.push(flag("workdir", working_dir));
+    }
+    // Only the first argument of the entrypoint can be given, the rest are passed before the command
+    let mut entrypoint = config.entrypoint.clone().unwrap_or_default().into_iter();
+    if let Some(first) = entrypoint.next() {
+        args.push(flag("entrypoint", &first));
+    }
+    if config.tty == Some(true) {
+        args.push(String::from("-t"));
+    }
+    if config.open_stdin == Some(true) {
+        args.push(String::from("-i"));
+    }
+    if host_config.privileged == Some(true) {
+        args.push(String::from("--privileged"));
+    }
+    for cap in host_config.cap_add.unwrap_or_default() {
+        args.push(flag("cap-add", &cap));
+    }
+    for cap in host_config.cap_drop.unwrap_or_default() {
+        args.push(flag("cap-drop", &cap));
+    }
+    for env in config.env.clone().unwrap_or_default() {
+        args.push(format!("-e {}", quote(&env)));
+    }
+    for label in sorted_labels(&config) {
+        args.push(flag("label", &label));
+    }
+    for port in &run.ports {
+        args.push(format!("-p {}", quote(&port.to_string())));
+    }
+    let mut exposed = config
+        .exposed_ports
+        .clone()
+        .unwrap_or_default()
+        .into_keys()
+        .filter(|i| !run.ports.iter().any(|port| &port.container == i))
+        .collect::<Vec<_>>();
+    exposed.sort();
+    for port in exposed {
+        args.push(flag("expose", &port));
+    }
+    for volume in &run.volumes {
+        args.push(format!("-v {}", quote(volume)));
+    }
+    if let Some(network) = custom_network(run.network.as_deref()) {
+        args.push(flag("network", network));
+    }
+    if run.restart != "no" {
+        args.push(flag("restart", &run.restart));
+    }
+    let memory = run.memory_text();
+    if !memory.is_empty() {
+        args.push(flag("memory", &memory));
+    }
+    let cpus = run.cpus_text();
+    if !cpus.is_empty() {
+        args.push(flag("cpus", &cpus));
+    }
+    args.push(quote(&run.image));
+    let command = entrypoint
+        .chain(config.cmd.unwrap_or_default())
+        .map(|i| quote(&i))
+        .collect::<Vec<_>>();
+    if !command.is_empty() {
+        args.push(command.join(" "));
+    }
+
+    let mut lines = vec![args.join(" \\\n  ")];
+    let mut networks = recreate.networks.keys().collect::<Vec<_>>();
+    networks.sort();
+    for network in networks {
+        lines.push(format!(
+            "docker network connect {} {}",
+            quote(network),
+            quote(&recreate.name)
+        ));
+    }
+    lines.join("\n")
+}
+
+/// Convert a container into an equivalent compose file, with a single service, networks other than the default are declared as external
+pub fn compose_service(recreate: &Recreate, image: &ContainerConfig) -> String {
+    let config = without_image_defaults(&recreate.config, image);
+    let host_config = recreate.config.host_config.clone().unwrap_or_default();
+    let run = to_run(&recreate.config);
+    let list = |items: Vec<String>| Value::Sequence(items.into_iter().map(Value::from).collect());
+
+    let mut service = Mapping::new();
+    service.insert("image".into(), run.image.clone().into());
+    service.insert("container_name".into(), recreate.name.clone().into());
+    for (key, value) in [
+        ("hostname", &config.hostname),
+        ("user", &config.user),
+        ("working_dir", &config.working_dir),
+    ] {
+        if let Some(value) = value {
+            service.insert(key.into(), value.clone().into());
+        }
+    }
+    if let Some(entrypoint) = config.entrypoint.clone() {
+        service.insert("entrypoint".into(), list(entrypoint));
+    }
+    if let Some(cmd) = config.cmd.clone() {
+        service.insert("command".into(), list(cmd));
+    }
+    for (key, value) in [
+        ("tty", config.tty),
+        ("stdin_open", config.open_stdin),
+        ("privileged", host_config.privileged),
+    ] {
+        if value == Some(true) {
+            service.insert(key.into(), true.into());
+        }
+    }
+    for (key, value) in [
+        ("cap_add", host_config.cap_add.unwrap_or_default()),
+        ("cap_drop", host_config.cap_drop.unwrap_or_default()),
+        ("environment", config.env.clone().unwrap_or_default()),
+        ("labels", sorted_labels(&config)),
+        ("ports", run.ports.iter().map(ToString::to_string).collect()),
+        ("volumes", run.volumes.clone()),
+    ] {
+        if !value.is_empty() {
+            service.insert(key.into(), list(value));
+        }
+    }
+
+    // Compose can't attach to the default bridge network by name, so it's only given as a network mode
+    let mut networks = recreate
+        .networks
+        .keys()
+        .filter(|i| i.as_str() != "bridge")
+        .cloned()
+        .collect::<Vec<_>>();
+    match custom_network(run.network.as_deref()) {
+        Some(mode) if mode == "host" || mode == "none" || mode.starts_with("container:") => {
+            service.insert("network_mode".into(), mode.into());
+            networks.clear();
+        }
+        Some(mode) => networks.push(mode.to_owned()),
+        None => (),
+    }
+    networks.sort();
+    if !networks.is_empty() {
+        service.insert("networks".into(), list(networks.clone()));
+    }
+    if run.restart != "no" {
+        service.insert("restart".into(), run.restart.clone().into());
+    }
+    let memory = run.memory_text();
+    if !memory.is_empty() {
+        service.insert("mem_limit".into(), memory.into());
+    }
+    let cpus = run.cpus_text();
+    if !cpus.is_empty() {
+        service.insert("cpus".into(), cpus.into());
+    }
+
+    let mut compose = Mapping::new();
+    compose.insert(
+        "services".into(),
+        Value::Mapping(Mapping::from_iter([(
+            recreate.name.clone().into(),
+            Value::Mapping(service),
+        )])),
+    );
+    if !networks.is_empty() {
+        let external = Mapping::from_iter([("external".into(), true.into())]);
+        compose.insert(
+            "networks".into(),
+            Value::Mapping(
+                networks
+                    .into_iter()
+                    .map(|i| (i.into(), Value::Mapping(external.clone())))
+                    .collect(),
+            ),
+        );
+    }
+    serde_yaml::to_string(&compose).unwrap_or_default()
+}
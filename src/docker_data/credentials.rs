@@ -0,0 +1,74 @@
+use std::path::PathBuf;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use bollard::auth::DockerCredentials;
+use serde_json::Value;
+
+const DOCKER_HUB: &str = "https://index.docker.io/v1/";
+
+/// Location of the docker cli config file, `$DOCKER_CONFIG/config.json`, else `~/.docker/config.json`
+fn config_path() -> Option<PathBuf> {
+    std::env::var_os("DOCKER_CONFIG")
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME")
+                .or_else(|| std::env::var_os("USERPROFILE"))
+                .map(|home| PathBuf::from(home).join(".docker"))
+        })
+        .map(|dir| dir.join("config.json"))
+}
+
+/// The registry an image belongs to, the first part of the name if it looks like a host, else docker hub
+fn registry(image: &str) -> String {
+    match image.split_once('/') {
+        Some((host, _)) if host.contains(['.', ':']) || host == "localhost" => host.to_owned(),
+        _ => DOCKER_HUB.to_owned(),
+    }
+}
+
+/// Strip the scheme and any path from an auths key, so `https://host/v1/` matches `host`
+fn normalise(key: &str) -> &str {
+    let key = key
+        .strip_prefix("https://")
+        .or_else(|| key.strip_prefix("http://"))
+        .unwrap_or(key);
+    key.split('/').next().unwrap_or(key)
+}
+
+/// Find the credentials for the registry of an image, from the `auths` section of the docker config file
+/// Credential stores and helpers aren't supported, so those registries will be pushed to anonymously
+pub fn registry_credentials(image: &str) -> Option<DockerCredentials> {
+    let config = std::fs::read_to_string(config_path()?).ok()?;
+    let config = serde_json::from_str::<Value>(&config).ok()?;
+    let serveraddress = registry(image);
+    let (_, entry) = config
+        .get("auths")?
+        .as_object()?
+        .iter()
+        .find(|(key, _)| normalise(key) == normalise(&serveraddress))?;
+    let field = |name: &str| {
+        entry
+            .get(name)
+            .and_then(Value::as_str)
+            .map(ToOwned::to_owned)
+    };
+
+    let (username, password) = field("auth")
+        .and_then(|auth| STANDARD.decode(auth).ok())
+        .and_then(|auth| String::from_utf8(auth).ok())
+        .and_then(|auth| {
+            auth.split_once(':')
+                .map(|(user, pass)| (user.to_owned(), pass.to_owned()))
+        })
+        .map_or_else(
+            || (field("username"), field("password")),
+            |(user, pass)| (Some(user), Some(pass)),
+        );
+    Some(DockerCredentials {
+        username,
+        password,
+        identitytoken: field("identitytoken"),
+        serveraddress: Some(serveraddress),
+        ..Default::default()
+    })
+}
@@ -0,0 +1,155 @@
+use std::{fs, io, path::Path};
+
+/// Split a path into its components, ignoring empty and `.` components, and resolving `..`, the same as Go's `filepath.Clean`
+fn clean(path: &str) -> Vec<String> {
+    let mut output = vec![];
+    for part in path.split('/') {
+        match part {
+            "" | "." => (),
+            ".." => {
+                output.pop();
+            }
+            _ => output.push(part.to_owned()),
+        }
+    }
+    output
+}
+
+/// Match a single path component against a pattern component, `*` matches any characters, `?` a single character, `[...]` a class of characters, and `\` escapes
+fn match_name(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) => (0..=name.len()).any(|i| match_name(rest, &name[i..])),
+        Some(('?', rest)) => name
+            .split_first()
+            .is_some_and(|(_, name)| match_name(rest, name)),
+        Some(('[', rest)) => name.split_first().is_some_and(|(c, name)| {
+            match_class(rest, *c).is_some_and(|(matched, rest)| matched && match_name(rest, name))
+        }),
+        Some(('\\', [c, rest @ ..]) | (c, rest)) => name
+            .split_first()
+            .is_some_and(|(first, name)| first == c && match_name(rest, name)),
+    }
+}
+
+/// Match a character against a `[...]` class, a leading `^` negates the class, returns if it matched, and the rest of the pattern after the class
+/// None if the class is never closed
+fn match_class(pattern: &[char], c: char) -> Option<(bool, &[char])> {
+    let (negated, mut rest) = match pattern.split_first() {
+        Some(('^', rest)) => (true, rest),
+        _ => (false, pattern),
+    };
+    let mut matched = false;
+    let mut first = true;
+    loop {
+        let (start, after) = match rest {
+            [']', after @ ..] if !first => return Some((matched != negated, after)),
+            ['\\', start, after @ ..] | [start, after @ ..] => (*start, after),
+            [] => return None,
+        };
+        let (end, after) = match after {
+            ['-', end, after @ ..] if *end != ']' => (*end, after),
+            _ => (start, after),
+        };
+        matched |= (start..=end).contains(&c);
+        first = false;
+        rest = after;
+    }
+}
+
+/// A single line of a `.dockerignore` file, an `exception` starts with `!`, and re-includes paths excluded by an earlier pattern
+#[derive(Debug, Clone)]
+struct Pattern {
+    components: Vec<Vec<char>>,
+    exception: bool,
+}
+
+impl Pattern {
+    fn new(line: &str) -> Option<Self> {
+        let (exception, line) = line
+            .strip_prefix('!')
+            .map_or((false, line), |i| (true, i.trim()));
+        let components = clean(line)
+            .iter()
+            .map(|i| i.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        (!components.is_empty()).then_some(Self {
+            components,
+            exception,
+        })
+    }
+
+    /// Match the components of a pattern against those of a path, `**` matches any number of components
+    fn match_components(pattern: &[Vec<char>], path: &[Vec<char>]) -> bool {
+        match pattern.split_first() {
+            None => path.is_empty(),
+            Some((first, rest)) if first == &['*', '*'] => {
+                (0..=path.len()).any(|i| Self::match_components(rest, &path[i..]))
+            }
+            Some((first, rest)) => path.split_first().is_some_and(|(name, path)| {
+                match_name(first, name) && Self::match_components(rest, path)
+            }),
+        }
+    }
+
+    /// A pattern that matches a directory also matches everything within it
+    fn matches(&self, path: &[Vec<char>]) -> bool {
+        (1..=path.len()).any(|i| Self::match_components(&self.components, &path[..i]))
+    }
+}
+
+/// The patterns of the `.dockerignore` file of a build context, a path is excluded if the last pattern that matches it isn't an exception
+/// The Dockerfile, and the `.dockerignore` file itself, are never excluded, as docker needs them to build the image, the same as `docker build`
+#[derive(Debug, Clone)]
+pub struct DockerIgnore {
+    patterns: Vec<Pattern>,
+    keep: Vec<String>,
+}
+
+impl DockerIgnore {
+    /// Read the `.dockerignore` file at the root of a build context, if there isn't one, nothing is excluded
+    pub fn read(context: &Path, dockerfile: &str) -> io::Result<Self> {
+        let text = match fs::read_to_string(context.join(".dockerignore")) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        Ok(Self {
+            patterns: text
+                .lines()
+                .map(str::trim)
+                .filter(|i| !i.is_empty() && !i.starts_with('#'))
+                .filter_map(Pattern::new)
+                .collect(),
+            keep: [".dockerignore", dockerfile]
+                .iter()
+                .map(|i| clean(i).join("/"))
+                .collect(),
+        })
+    }
+
+    /// If a path, relative to the root of the build context, should be left out of the build context
+    pub fn is_excluded(&self, path: &str) -> bool {
+        if self.keep.iter().any(|i| i == path) {
+            return false;
+        }
+        let path = clean(path)
+            .iter()
+            .map(|i| i.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        self.patterns.iter().fold(false, |excluded, pattern| {
+            if pattern.matches(&path) {
+                !pattern.exception
+            } else {
+                excluded
+            }
+        })
+    }
+
+    /// If an excluded directory can be skipped entirely, rather than walked, as nothing within it can be re-included
+    pub fn can_skip(&self, directory: &str) -> bool {
+        let prefix = format!("{directory}/");
+        !self.patterns.iter().any(|i| i.exception)
+            && !self.keep.iter().any(|i| i.starts_with(&prefix))
+    }
+}
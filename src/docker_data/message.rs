@@ -1,4 +1,14 @@
-use crate::app_data::container_state::ContainerId;
+use crate::app_data::{
+    change_state::ChangeFilter,
+    container_state::{
+        ComposeAction, ContainerBackup, ContainerCommit, ContainerId, ContainerRun, Signal,
+    },
+    export_state::ExportFormat,
+    image_state::{ImageBuild, ImageId, ImagePrune},
+    network_state::{NetworkConnect, NetworkCreate},
+    node_state::NodeChange,
+    process_state::ProcessTarget,
+};
 
 #[derive(Debug, Clone)]
 pub enum DockerMessage {
@@ -8,9 +18,67 @@ pub enum DockerMessage {
     RestartContainer(ContainerId),
     StartContainer(ContainerId),
     StopContainer(ContainerId),
+    KillContainer(ContainerId, Signal),
     UnpauseContainer(ContainerId),
     InfosContainer(ContainerId),
     ShellContainer(ContainerId),
+    RestartProject(String),
+    StartProject(String),
+    StopProject(String),
+    Compose(String, ComposeAction),
+    ConfirmComposeDown(String),
+    DeleteImage(ImageId),
+    ForceDeleteImage(ImageId),
+    InfosImage(ImageId),
+    HistoryImage(ImageId),
+    PullImage(String),
+    TagImage(ImageId, String),
+    UntagImage(String),
+    PushImage(String),
+    BuildImage(ImageBuild),
+    SaveImages(Vec<String>, String),
+    LoadImages(String),
+    InfosVolume(String),
+    ConfirmDeleteVolume(String),
+    DeleteVolume(String),
+    ConfirmPruneVolumes,
+    PruneVolumes,
+    BrowseVolume(String),
+    BrowseContainer(ContainerId),
+    ListFiles(String),
+    ViewFile(String),
+    SaveFile(String, String),
+    SaveDirectory(String, String),
+    CloseFiles,
+    ContainerChanges(ContainerId),
+    FilterChanges(ChangeFilter),
+    ContainerProcesses(ContainerId),
+    SignalProcess(ContainerId, ProcessTarget, Signal),
+    DownloadFiles(ContainerId, String, String),
+    UploadFiles(ContainerId, String, String),
+    ConnectNetwork(NetworkConnect),
+    DisconnectNetwork(String, String),
+    CreateNetwork(NetworkCreate),
+    ConfirmDeleteNetwork(String),
+    DeleteNetwork(String),
+    ScaleService(String, u64),
+    ForceUpdateService(String),
+    ConfirmDeleteService(String),
+    DeleteService(String),
+    ConfirmUpdateNode(String, NodeChange),
+    RunContainer(ContainerRun),
+    CommitForm(ContainerId),
+    CommitContainer(ContainerId, ContainerCommit),
+    RecreateForm(ContainerId),
+    RecreateContainer(ContainerId, ContainerRun),
+    RollbackContainer(ContainerBackup),
+    ExportContainer(ContainerId),
+    SaveExport(ExportFormat, String),
+    UpdateNode(String, NodeChange),
+    CancelBuild,
+    ConfirmPruneImages(ImagePrune),
+    PruneImages(ImagePrune),
+    CancelProgress,
     Quit,
     Update,
 }
@@ -1,39 +1,120 @@
 use std::{
     collections::HashMap,
+    path::PathBuf,
     process::{Command, Stdio},
     sync::{atomic::AtomicBool, Arc},
+    time::Duration,
 };
 
 use bollard::{
     container::{
-        ListContainersOptions, LogsOptions, RemoveContainerOptions, StartContainerOptions, Stats,
-        StatsOptions,
+        Config, CreateContainerOptions, KillContainerOptions, ListContainersOptions, LogOutput,
+        LogsOptions, RemoveContainerOptions, RenameContainerOptions, StartContainerOptions, Stats,
+        StatsOptions, TopOptions,
     },
-    service::ContainerSummary,
+    exec::{CreateExecOptions, StartExecResults},
+    image::{
+        BuildImageOptions, CommitContainerOptions, CreateImageOptions, ImportImageOptions,
+        ListImagesOptions, PruneImagesOptions, PushImageOptions, RemoveImageOptions,
+        TagImageOptions,
+    },
+    network::{
+        ConnectNetworkOptions, CreateNetworkOptions, DisconnectNetworkOptions, ListNetworksOptions,
+    },
+    service::{
+        ContainerConfig, ContainerSummary, EndpointIpamConfig, EndpointSettings, HostConfig,
+        InspectServiceOptions, Ipam, IpamConfig, ListServicesOptions, ServiceSpec,
+        UpdateServiceOptions,
+    },
+    volume::{ListVolumesOptions, PruneVolumesOptions, RemoveVolumeOptions},
     Docker,
 };
 use futures_util::StreamExt;
+use hyper::Body;
 use parking_lot::Mutex;
-use tokio::{sync::mpsc::Receiver, task::JoinHandle};
+use tokio::{io::AsyncWriteExt, sync::mpsc::Receiver, task::JoinHandle};
+use tokio_util::io::ReaderStream;
 use uuid::Uuid;
 
+use compose::ComposeProject;
+use container_config::Recreate;
 pub use message::DockerMessage;
+use swarm::SwarmApi;
 
-use crate::app_data::container_state::ContainerId;
+use crate::app_data::{
+    change_state::{ChangeItem, ContainerChanges},
+    container_state::{
+        ByteStats, ComposeAction, ContainerBackup, ContainerCommit, ContainerId, ContainerRun,
+        Signal, State,
+    },
+    export_state::{ContainerExport, ExportFormat},
+    file_state::{parse_listing, FileBrowser},
+    image_state::{split_repo_tag, ImageBuild, ImageId, ImagePrune},
+    network_state::{NetworkConnect, NetworkCreate},
+    node_state::NodeChange,
+    process_state::ProcessTarget,
+    progress_state::{BuildLog, Progress, ProgressState},
+};
 use crate::{
     app_data::AppData,
     app_error::AppError,
     parse_args::CliArgs,
-    ui::{GuiState, Status},
+    ui::{Confirm, Form, GuiState, NavPanel, Status},
     ENTRY_POINT,
 };
 
+/// The docker socket, the same default bollard uses, shared by the bollard client and the swarm client so that both talk to the same daemon
+#[cfg(unix)]
+const DOCKER_SOCKET: &str = "/var/run/docker.sock";
+/// Seconds before a request to docker times out, the same default bollard uses
+#[cfg(unix)]
+const DOCKER_TIMEOUT: u64 = 120;
+/// Label added to the helper containers created to browse volumes, so they can be hidden from the containers panel
+const HELPER_LABEL: &str = "d5r.helper";
+/// Appended to the name of a container, while it's being recreated, so the new container can have its name
+const BACKUP_SUFFIX: &str = "-d5r-backup";
+/// Image pulled to create a volume helper container, if no suitable image is available locally
+const HELPER_IMAGE: &str = "busybox:latest";
+/// Arguments given to `ps` when listing the processes of a container
+const PS_ARGS: &str = "-eo pid,user,pcpu,pmem,args";
+/// Largest file that will be shown in the file viewer
+const VIEW_LIMIT: u64 = 512 * 1024;
+/// Shell script run inside a container to send a signal to a process, the arguments are the signal number, then the command, index, and count, of a `ProcessTarget`
+/// Whitespace in each command line is collapsed, to match the command listed by `docker top`
+const SIGNAL_SCRIPT: &str = r#"sig=$1 cmd=$2 index=$3 count=$4
+pids=$(for dir in /proc/[0-9]*; do
+    c=$(tr -s '\000\t\n ' ' ' 2>/dev/null < "$dir/cmdline")
+    c=${c# }
+    [ "${c% }" = "$cmd" ] && echo "${dir#/proc/}"
+done | sort -n)
+if [ "$(echo "$pids" | grep -c .)" != "$count" ]; then
+    echo "its processes have changed, refresh and try again" >&2
+    exit 1
+fi
+kill -"$sig" "$(echo "$pids" | sed -n "${index}p")""#;
+/// Shell script run inside a container to list only the direct children of the directory given as its first argument, see `parse_listing`
+const LIST_SCRIPT: &str = r#"cd "$1" || exit 1
+for f in * .[!.]* ..?*; do
+    [ -e "$f" ] || [ -L "$f" ] || continue
+    printf '%s\0%s\0%s\0' "$(stat -c '%f %s %Y' "./$f")" "$f" "$(readlink "./$f")"
+done
+exit 0"#;
+
+mod archive;
+mod compose;
+mod container_config;
+mod credentials;
+mod dockerignore;
 mod message;
+mod swarm;
 
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
 enum SpawnId {
     Stats((ContainerId, Binate)),
     Log(ContainerId),
+    ServiceLog(String),
+    Progress,
+    Build,
 }
 
 /// Cpu & Mem stats take twice as long as the update interval to get a value, so will have two being executed at the same time
@@ -65,6 +146,7 @@ pub struct DockerData {
     is_running: Arc<AtomicBool>,
     receiver: Receiver<DockerMessage>,
     spawns: Arc<Mutex<HashMap<SpawnId, JoinHandle<()>>>>,
+    swarm: SwarmApi,
 }
 
 impl DockerData {
@@ -110,6 +192,37 @@ impl DockerData {
         app_data.lock().container_data.update_infos(&id, &y_info)
     }
 
+    /// Inspect a single volume, and store the output, as yaml, in the volume info
+    async fn update_volume_info(app_data: Arc<Mutex<AppData>>, docker: Arc<Docker>, name: String) {
+        let info = docker.inspect_volume(&name).await.unwrap_or_default();
+
+        let y_info = serde_yaml::to_string(&info).unwrap_or_default();
+
+        app_data.lock().volume_data.update_infos(&name, &y_info);
+    }
+
+    /// Inspect a single image, and store the output, as yaml, in the image info
+    async fn update_image_info(app_data: Arc<Mutex<AppData>>, docker: Arc<Docker>, id: ImageId) {
+        let info = docker.inspect_image(id.get()).await.unwrap_or_default();
+
+        let y_info = serde_yaml::to_string(&info).unwrap_or_default();
+
+        app_data.lock().image_data.update_infos(&id, &y_info);
+    }
+
+    /// Get the layer history of a single image
+    async fn update_image_history(
+        app_data: Arc<Mutex<AppData>>,
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        id: ImageId,
+    ) {
+        match docker.image_history(id.get()).await {
+            Ok(history) => app_data.lock().image_data.update_history(&id, &history),
+            Err(_) => Self::set_error(&app_data, &gui_state),
+        }
+    }
+
     /// Get a single docker stat in order to update mem and cpu usage
     /// don't take &self, so that can tokio::spawn into it's own thread
     /// remove if from spawns hashmap when complete
@@ -225,6 +338,11 @@ impl DockerData {
                 }
                 None => None,
             })
+            .filter(|f| {
+                f.labels
+                    .as_ref()
+                    .is_none_or(|i| !i.contains_key(HELPER_LABEL))
+            })
             .collect::<Vec<ContainerSummary>>();
 
         self.app_data
@@ -247,6 +365,380 @@ impl DockerData {
             .collect::<Vec<_>>()
     }
 
+    /// Get all local images, and count how many of the known containers are using each one
+    pub async fn update_all_images(&self) {
+        let images = self
+            .docker
+            .list_images(Some(ListImagesOptions::<String> {
+                all: false,
+                ..Default::default()
+            }))
+            .await
+            .unwrap_or_default();
+
+        let mut app_data = self.app_data.lock();
+        let usage = app_data.container_data.get_image_usage();
+        app_data.image_data.update_images(&images, &usage);
+    }
+
+    /// Get all volumes, and the names of the known containers mounting each one
+    pub async fn update_all_volumes(&self) {
+        let volumes = self
+            .docker
+            .list_volumes(None::<ListVolumesOptions<String>>)
+            .await
+            .map(|i| i.volumes.unwrap_or_default())
+            .unwrap_or_default();
+
+        let mut app_data = self.app_data.lock();
+        let usage = app_data.container_data.get_volume_usage();
+        app_data.volume_data.update_volumes(&volumes, &usage);
+    }
+
+    /// Get all networks, and the known containers attached to each one
+    pub async fn update_all_networks(&self) {
+        let networks = self
+            .docker
+            .list_networks(None::<ListNetworksOptions<String>>)
+            .await
+            .unwrap_or_default();
+
+        let mut app_data = self.app_data.lock();
+        let usage = app_data.container_data.get_network_usage();
+        app_data.network_data.update_networks(&networks, &usage);
+    }
+
+    /// The message of an error response from docker, e.g. that the node isn't a swarm manager, rather than the whole error
+    fn error_message(error: bollard::errors::Error) -> String {
+        match error {
+            bollard::errors::Error::DockerResponseServerError { message, .. } => message,
+            e => e.to_string(),
+        }
+    }
+
+    /// Get all swarm services, with their tasks, and all swarm nodes, the hostname of each node is shown with each task
+    /// If the swarm can't be listed, e.g. the daemon isn't a swarm manager, the reason is shown in the services and nodes panels
+    /// If only the tasks, or the nodes, can't be listed, e.g. on a platform without unix sockets, the reason is shown in just the tasks, or nodes, panel
+    pub async fn update_swarm(&self) {
+        let services = match self
+            .docker
+            .list_services(None::<ListServicesOptions<String>>)
+            .await
+        {
+            Ok(services) => services,
+            Err(e) => {
+                let reason = Self::error_message(e);
+                let mut app_data = self.app_data.lock();
+                app_data.node_data.set_unavailable(reason.clone());
+                app_data.service_data.set_unavailable(reason);
+                return;
+            }
+        };
+        // The services are still shown, and can be changed, if the tasks or nodes can't be listed, only those panels show the reason
+        let (tasks, nodes) = tokio::join!(self.swarm.list_tasks(), self.swarm.list_nodes());
+        let hostnames = nodes.as_ref().map_or_else(
+            |_| HashMap::new(),
+            |nodes| {
+                nodes
+                    .iter()
+                    .filter_map(|i| {
+                        Some((i.id.clone()?, i.description.as_ref()?.hostname.clone()?))
+                    })
+                    .collect::<HashMap<_, _>>()
+            },
+        );
+        let (tasks, tasks_unavailable) = match tasks {
+            Ok(tasks) => (tasks, None),
+            Err(reason) => (vec![], Some(reason)),
+        };
+        let mut app_data = self.app_data.lock();
+        app_data
+            .service_data
+            .update_services(&services, &tasks, &hostnames, tasks_unavailable);
+        match nodes {
+            Ok(nodes) => app_data.node_data.update_nodes(&nodes),
+            Err(reason) => app_data.node_data.set_unavailable(reason),
+        }
+    }
+
+    /// Pull an image, streaming the progress of each layer into the app_data progress
+    /// remove it from spawns hashmap when complete
+    async fn pull_image(
+        app_data: Arc<Mutex<AppData>>,
+        docker: Arc<Docker>,
+        image: String,
+        spawns: Arc<Mutex<HashMap<SpawnId, JoinHandle<()>>>>,
+    ) {
+        let (from_image, tag) = split_repo_tag(&image);
+        let mut stream = docker.create_image(
+            Some(CreateImageOptions {
+                from_image,
+                tag,
+                ..Default::default()
+            }),
+            None,
+            None,
+        );
+
+        let mut result = (ProgressState::Complete, format!("pulled {image}"));
+        while let Some(value) = stream.next().await {
+            match value {
+                Ok(info) => {
+                    let detail = info.progress_detail.unwrap_or_default();
+                    if let Some(progress) = app_data.lock().get_mut_progress() {
+                        progress.update(
+                            info.id.as_deref(),
+                            info.status.as_deref(),
+                            detail.current,
+                            detail.total,
+                        );
+                    }
+                }
+                Err(e) => {
+                    result = (ProgressState::Failed, e.to_string());
+                    break;
+                }
+            }
+        }
+        if let Some(progress) = app_data.lock().get_mut_progress() {
+            progress.finish(result.0, result.1);
+        }
+        spawns.lock().remove(&SpawnId::Progress);
+    }
+
+    /// Push an image to its registry, using the credentials from the docker config file, streaming the status into the app_data progress
+    /// Docker doesn't include the layer ids in a push stream, so only the general status is shown
+    /// remove it from spawns hashmap when complete
+    async fn push_image(
+        app_data: Arc<Mutex<AppData>>,
+        docker: Arc<Docker>,
+        image: String,
+        spawns: Arc<Mutex<HashMap<SpawnId, JoinHandle<()>>>>,
+    ) {
+        let (repo, tag) = split_repo_tag(&image);
+        let mut stream = docker.push_image(
+            &repo,
+            Some(PushImageOptions { tag }),
+            credentials::registry_credentials(&image),
+        );
+
+        let mut result = (ProgressState::Complete, format!("pushed {image}"));
+        while let Some(value) = stream.next().await {
+            match value {
+                Ok(info) => {
+                    if let Some(error) = info.error {
+                        result = (ProgressState::Failed, error);
+                        break;
+                    }
+                    let status = format!(
+                        "{} {}",
+                        info.status.unwrap_or_default(),
+                        info.progress.unwrap_or_default()
+                    );
+                    if let Some(progress) = app_data.lock().get_mut_progress() {
+                        progress.update(None, Some(status.trim()), None, None);
+                    }
+                }
+                Err(e) => {
+                    result = (ProgressState::Failed, e.to_string());
+                    break;
+                }
+            }
+        }
+        if let Some(progress) = app_data.lock().get_mut_progress() {
+            progress.finish(result.0, result.1);
+        }
+        spawns.lock().remove(&SpawnId::Progress);
+    }
+
+    /// Build an image, streaming the output into the app_data build log, the build context is archived whilst it's being uploaded
+    /// remove it from spawns hashmap when complete
+    async fn build_image(
+        app_data: Arc<Mutex<AppData>>,
+        docker: Arc<Docker>,
+        build: ImageBuild,
+        spawns: Arc<Mutex<HashMap<SpawnId, JoinHandle<()>>>>,
+    ) {
+        let args = app_data.lock().args;
+        let context = PathBuf::from(&build.context);
+        let dockerfile = build.dockerfile.clone();
+        let (body, packing) = archive::stream_archive(
+            move |builder| archive::append_context(builder, &context, &dockerfile),
+            |_| (),
+        );
+        let mut stream = docker.build_image(
+            BuildImageOptions {
+                dockerfile: build.dockerfile.clone(),
+                t: build.tag.clone(),
+                buildargs: build.build_args.clone(),
+                rm: true,
+                ..Default::default()
+            },
+            None,
+            Some(body),
+        );
+        let mut result = (ProgressState::Complete, format!("built {}", build.tag));
+        while let Some(value) = stream.next().await {
+            match value {
+                Ok(info) => {
+                    if let Some(error) = info.error {
+                        result = (ProgressState::Failed, error);
+                        break;
+                    }
+                    let output = info
+                        .stream
+                        .or_else(|| {
+                            info.status.map(|status| {
+                                format!("{status} {}", info.progress.unwrap_or_default())
+                            })
+                        })
+                        .unwrap_or_default();
+                    if let Some(build) = app_data.lock().get_mut_build() {
+                        build.push(&output, args);
+                    }
+                }
+                Err(e) => {
+                    result = (ProgressState::Failed, e.to_string());
+                    break;
+                }
+            }
+        }
+        drop(stream);
+        // If the context couldn't be read, that's the cause of any build error, unless docker closed the upload first
+        match packing
+            .await
+            .unwrap_or_else(|e| Err(std::io::Error::other(e)))
+        {
+            Err(e) if e.kind() != std::io::ErrorKind::BrokenPipe => {
+                result = (
+                    ProgressState::Failed,
+                    format!("unable to read context {}: {e}", build.context),
+                );
+            }
+            _ => (),
+        }
+        if let Some(build) = app_data.lock().get_mut_build() {
+            build.finish(result.0, result.1);
+        }
+        spawns.lock().remove(&SpawnId::Build);
+    }
+
+    /// Export a single image, writing the tarball to a file, and showing the total bytes written in the loading status
+    async fn export_image(
+        docker: &Docker,
+        gui_state: &Arc<Mutex<GuiState>>,
+        image: &str,
+        path: &PathBuf,
+        written: &mut u64,
+    ) -> Result<(), AppError> {
+        let mut file = tokio::fs::File::create(path)
+            .await
+            .map_err(|_| AppError::ImageSave)?;
+        let mut stream = docker.export_image(image);
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|_| AppError::ImageSave)?;
+            file.write_all(&chunk)
+                .await
+                .map_err(|_| AppError::ImageSave)?;
+            *written += u64::try_from(chunk.len()).unwrap_or_default();
+            gui_state
+                .lock()
+                .set_loading_status(Some(format!("saving {}", ByteStats::new(*written))));
+        }
+        file.flush().await.map_err(|_| AppError::ImageSave)
+    }
+
+    /// Save images to a tar file
+    /// `docker save` accepts multiple images, but bollard can only export a single image at a time, so multiple images are each exported to a temporary file, and then merged into one archive
+    async fn save_images(
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        images: Vec<String>,
+        file: String,
+    ) -> Result<(), AppError> {
+        let output = PathBuf::from(&file);
+        let parts = if images.len() == 1 {
+            vec![output.clone()]
+        } else {
+            (0..images.len())
+                .map(|i| PathBuf::from(format!("{file}.{i}.part")))
+                .collect::<Vec<_>>()
+        };
+
+        let mut written = 0;
+        let mut result = Ok(());
+        for (image, part) in images.iter().zip(&parts) {
+            result = Self::export_image(&docker, &gui_state, image, part, &mut written).await;
+            if result.is_err() {
+                break;
+            }
+        }
+
+        if result.is_ok() && parts.len() > 1 {
+            gui_state
+                .lock()
+                .set_loading_status(Some(String::from("merging images")));
+            let merge_parts = parts.clone();
+            result = tokio::task::spawn_blocking(move || {
+                archive::merge_image_archives(&merge_parts, &output)
+            })
+            .await
+            .map_or(Err(AppError::ImageSave), |i| {
+                i.map_err(|_| AppError::ImageSave)
+            });
+        }
+
+        // Remove the temporary files, or, if the save failed, the incomplete output
+        if result.is_err() || parts.len() > 1 {
+            for part in &parts {
+                tokio::fs::remove_file(part).await.ok();
+            }
+        }
+        result
+    }
+
+    /// Load images from a tar file, streaming the file to docker rather than reading it all into memory, returns the names of the loaded images
+    async fn load_images(
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        file: String,
+    ) -> Result<Vec<String>, AppError> {
+        let file = tokio::fs::File::open(&file)
+            .await
+            .map_err(|_| AppError::ImageLoad)?;
+        let mut stream = docker.import_image(
+            ImportImageOptions { quiet: false },
+            Body::wrap_stream(ReaderStream::new(file)),
+            None,
+        );
+
+        let mut loaded = vec![];
+        while let Some(value) = stream.next().await {
+            let info = value.map_err(|_| AppError::ImageLoad)?;
+            if info.error.is_some() {
+                return Err(AppError::ImageLoad);
+            }
+            if let Some(output) = info.stream {
+                loaded.extend(output.lines().filter_map(|i| {
+                    i.strip_prefix("Loaded image: ")
+                        .or_else(|| i.strip_prefix("Loaded image ID: "))
+                        .map(ToOwned::to_owned)
+                }));
+            }
+            if let Some(detail) = info.progress_detail {
+                let bytes = |x: Option<i64>| {
+                    ByteStats::new(x.and_then(|x| u64::try_from(x).ok()).unwrap_or_default())
+                };
+                gui_state.lock().set_loading_status(Some(format!(
+                    "loading {}/{}",
+                    bytes(detail.current),
+                    bytes(detail.total)
+                )));
+            }
+        }
+        Ok(loaded)
+    }
+
     /// Update single container logs
     /// remove it from spawns hashmap when complete
     async fn update_log(
@@ -276,6 +768,22 @@ impl DockerData {
         app_data.lock().container_data.update_log_by_id(output, &id);
     }
 
+    /// Update the logs of a single service, from all of its tasks
+    /// remove it from spawns hashmap when complete
+    async fn update_service_log(
+        app_data: Arc<Mutex<AppData>>,
+        swarm: SwarmApi,
+        id: String,
+        since: u64,
+        spawns: Arc<Mutex<HashMap<SpawnId, JoinHandle<()>>>>,
+    ) {
+        let output = swarm.service_logs(&id, since).await.unwrap_or_default();
+        spawns.lock().remove(&SpawnId::ServiceLog(id.clone()));
+        let mut app_data = app_data.lock();
+        let args = app_data.args;
+        app_data.service_data.update_logs(&id, output, args);
+    }
+
     /// Update all logs, spawn each container into own tokio::spawn thread
     fn init_all_logs(&mut self, all_ids: &[(bool, ContainerId)]) {
         for (_, id) in all_ids {
@@ -308,6 +816,48 @@ impl DockerData {
         };
         self.update_all_container_stats(&all_ids);
         self.app_data.lock().container_data.sort_containers();
+        self.update_all_images().await;
+        self.update_all_volumes().await;
+        self.update_all_networks().await;
+        self.update_swarm().await;
+        let processes = self.app_data.lock().process_data.get_container_id();
+        if let Some(id) =
+            processes.filter(|_| self.gui_state.lock().get_current_nav() == &NavPanel::Processes)
+        {
+            Self::update_processes(
+                Arc::clone(&self.app_data),
+                Arc::clone(&self.docker),
+                Arc::clone(&self.gui_state),
+                id,
+            )
+            .await;
+        }
+        let service_logs = self.gui_state.lock().get_current_nav() == &NavPanel::ServiceLogs;
+        if let Some(service) = self
+            .app_data
+            .lock()
+            .service_data
+            .get_selected_service()
+            .filter(|_| service_logs)
+        {
+            let last_updated = service.last_updated;
+            self.spawns
+                .lock()
+                .entry(SpawnId::ServiceLog(service.id.clone()))
+                .or_insert_with(|| {
+                    let app_data = Arc::clone(&self.app_data);
+                    let swarm = self.swarm.clone();
+                    let id = service.id.clone();
+                    let spawns = Arc::clone(&self.spawns);
+                    tokio::spawn(Self::update_service_log(
+                        app_data,
+                        swarm,
+                        id,
+                        last_updated,
+                        spawns,
+                    ))
+                });
+        };
     }
 
     /// Animate the loading icon
@@ -331,18 +881,66 @@ impl DockerData {
         gui_state.lock().remove_loading(loading_uuid);
     }
 
+    /// Connect to docker over its socket, on unix this is `DOCKER_SOCKET`, which the swarm client also uses
+    pub fn connect() -> Result<Docker, bollard::errors::Error> {
+        #[cfg(unix)]
+        return Docker::connect_with_socket(
+            DOCKER_SOCKET,
+            DOCKER_TIMEOUT,
+            bollard::API_DEFAULT_VERSION,
+        );
+        #[cfg(not(unix))]
+        Docker::connect_with_socket_defaults()
+    }
+
+    /// Remove any volume helper containers left behind, e.g. if a previous run crashed, or was killed, whilst browsing a volume
+    /// Helpers are hidden from the containers panel, so otherwise they would never be removed
+    async fn remove_stale_helpers(&self) {
+        let Ok(helpers) = self
+            .docker
+            .list_containers(Some(ListContainersOptions {
+                all: true,
+                filters: HashMap::from([("label", vec![HELPER_LABEL])]),
+                ..Default::default()
+            }))
+            .await
+        else {
+            return;
+        };
+        for id in helpers.into_iter().filter_map(|i| i.id) {
+            self.docker
+                .remove_container(
+                    &id,
+                    Some(RemoveContainerOptions {
+                        force: true,
+                        ..Default::default()
+                    }),
+                )
+                .await
+                .ok();
+        }
+    }
+
     /// Initialize docker container data, before any messages are received
     async fn initialise_container_data(&mut self) {
         self.gui_state.lock().status_push(Status::Init);
         let loading_uuid = Uuid::new_v4();
         let loading_spin = Self::loading_spin(loading_uuid, &Arc::clone(&self.gui_state)).await;
 
+        self.remove_stale_helpers().await;
+
         let all_ids = self.update_all_containers().await;
 
         self.update_all_container_stats(&all_ids);
 
         self.init_all_logs(&all_ids);
 
+        self.update_all_images().await;
+
+        self.update_all_volumes().await;
+        self.update_all_networks().await;
+        self.update_swarm().await;
+
         // wait until all logs have initialised
         while !self.app_data.lock().container_data.initialised(&all_ids) {
             tokio::time::sleep(std::time::Duration::from_millis(100)).await;
@@ -353,57 +951,1299 @@ impl DockerData {
 
     /// Set the global error as the docker error, and set gui_state to error
     fn set_error(app_data: &Arc<Mutex<AppData>>, gui_state: &Arc<Mutex<GuiState>>) {
-        app_data.lock().set_error(AppError::Docker);
+        Self::set_app_error(app_data, gui_state, AppError::Docker);
+    }
+
+    /// Set the global error, and set gui_state to error
+    fn set_app_error(
+        app_data: &Arc<Mutex<AppData>>,
+        gui_state: &Arc<Mutex<GuiState>>,
+        error: AppError,
+    ) {
+        app_data.lock().set_error(error);
         gui_state.lock().status_push(Status::Error);
     }
 
-    /// Handle incoming messages, container controls & all container information update
-    /// Spawn Docker commands off into own thread
-    async fn message_handler(&mut self) {
-        while let Some(message) = self.receiver.recv().await {
-            let docker = Arc::clone(&self.docker);
-            let gui_state = Arc::clone(&self.gui_state);
-            let app_data = Arc::clone(&self.app_data);
-            let uuid = Uuid::new_v4();
-            // TODO need to refactor these
-            match message {
-                DockerMessage::PauseContainer(id) => {
-                    tokio::spawn(async move {
-                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
-                        if docker.pause_container(id.get()).await.is_err() {
-                            Self::set_error(&app_data, &gui_state);
-                        }
-                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
-                    });
-                    self.update_everything().await;
-                }
-                DockerMessage::RestartContainer(id) => {
-                    tokio::spawn(async move {
-                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
-                        if docker.restart_container(id.get(), None).await.is_err() {
-                            Self::set_error(&app_data, &gui_state);
-                        }
-                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
-                    });
-                    self.update_everything().await;
-                }
-                DockerMessage::StartContainer(id) => {
-                    tokio::spawn(async move {
-                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
-                        if docker
-                            .start_container(id.get(), None::<StartContainerOptions<String>>)
-                            .await
-                            .is_err()
-                        {
-                            Self::set_error(&app_data, &gui_state);
-                        }
-                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
-                    });
-                    self.update_everything().await;
+    /// Show a message in the info box, and then remove it after a few seconds
+    fn set_info(gui_state: &Arc<Mutex<GuiState>>, text: String) {
+        gui_state.lock().set_info_box(text);
+        let gui_state = Arc::clone(gui_state);
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_secs(4)).await;
+            gui_state.lock().reset_info_box();
+        });
+    }
+
+    /// Refresh the images, and open a confirm dialog listing the images that the prune would remove, and the space that would be reclaimed
+    async fn confirm_prune_images(&self, prune: ImagePrune) {
+        self.update_all_images().await;
+        let (images, size) = self.app_data.lock().image_data.prune_preview(&prune);
+        if images.is_empty() {
+            Self::set_info(&self.gui_state, String::from("no images to prune"));
+            return;
+        }
+        let mut lines = vec![format!(
+            "remove {} image{}, reclaiming {size}",
+            images.len(),
+            if images.len() == 1 { "" } else { "s" }
+        )];
+        lines.extend(
+            images
+                .iter()
+                .map(|i| format!("{}  {:>9}  {}", i.id.short(), i.size, i.name())),
+        );
+        self.gui_state.lock().set_confirm(Some(Confirm::new(
+            String::from(" Confirm Prune "),
+            lines,
+            DockerMessage::PruneImages(prune),
+        )));
+    }
+
+    /// Refresh the volumes, and open a confirm dialog listing the volumes that aren't mounted by any container
+    async fn confirm_prune_volumes(&self) {
+        self.update_all_volumes().await;
+        let volumes = self.app_data.lock().volume_data.prune_preview();
+        if volumes.is_empty() {
+            Self::set_info(&self.gui_state, String::from("no volumes to prune"));
+            return;
+        }
+        let mut lines = vec![format!(
+            "remove {} unused volume{}",
+            volumes.len(),
+            if volumes.len() == 1 { "" } else { "s" }
+        )];
+        lines.extend(volumes.iter().map(|i| i.name.clone()));
+        self.gui_state.lock().set_confirm(Some(Confirm::new(
+            String::from(" Confirm Prune "),
+            lines,
+            DockerMessage::PruneVolumes,
+        )));
+    }
+
+    /// Open a confirm dialog to remove a single volume, listing any containers that mount it
+    fn confirm_delete_volume(&self, name: String) {
+        let containers = self
+            .app_data
+            .lock()
+            .container_data
+            .get_volume_usage()
+            .remove(&name)
+            .unwrap_or_default();
+        let mut lines = vec![format!("Are you sure you want to delete volume: {name}")];
+        if !containers.is_empty() {
+            lines.push(format!("mounted by: {}", containers.join(", ")));
+        }
+        self.gui_state.lock().set_confirm(Some(Confirm::new(
+            String::from(" Confirm Delete "),
+            lines,
+            DockerMessage::DeleteVolume(name),
+        )));
+    }
+
+    /// Open a confirm dialog to remove a network, docker won't remove a network that still has containers attached, so list them
+    fn confirm_delete_network(&self, name: String) {
+        let containers = self
+            .app_data
+            .lock()
+            .container_data
+            .get_network_usage()
+            .remove(&name)
+            .unwrap_or_default();
+        let mut lines = vec![format!("Are you sure you want to delete network: {name}")];
+        if !containers.is_empty() {
+            lines.push(format!(
+                "attached to: {}",
+                containers
+                    .iter()
+                    .map(|i| i.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        self.gui_state.lock().set_confirm(Some(Confirm::new(
+            String::from(" Confirm Delete "),
+            lines,
+            DockerMessage::DeleteNetwork(name),
+        )));
+    }
+
+    /// Read a compose file, and open a confirm dialog listing the containers that `down` would remove
+    async fn confirm_compose_down(&self, file: String) {
+        let project = match ComposeProject::load(&file) {
+            Ok(project) => project,
+            Err(e) => return Self::set_info(&self.gui_state, e),
+        };
+        let containers = match project.container_names(&self.docker).await {
+            Ok(containers) => containers,
+            Err(e) => return Self::set_info(&self.gui_state, e),
+        };
+        let mut lines = vec![format!(
+            "remove the containers and networks of project: {}",
+            project.name
+        )];
+        if containers.is_empty() {
+            lines.push(String::from("no containers to remove"));
+        } else {
+            lines.push(containers.join(", "));
+        }
+        self.gui_state.lock().set_confirm(Some(Confirm::new(
+            String::from(" Confirm Down "),
+            lines,
+            DockerMessage::Compose(file, ComposeAction::Down),
+        )));
+    }
+
+    /// Read a compose file, and bring its services up, down, or recreate a single service
+    /// Progress is shown in the loading area, and the result, or error, in the info box
+    async fn compose(
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        file: String,
+        action: ComposeAction,
+    ) {
+        let status = |text: String| gui_state.lock().set_loading_status(Some(text));
+        let result = match ComposeProject::load(&file) {
+            Ok(project) => match action {
+                ComposeAction::Up => project.up(&docker, status).await,
+                ComposeAction::Down => project.down(&docker, status).await,
+                ComposeAction::Recreate(service) => {
+                    project.recreate(&docker, &service, status).await
                 }
-                DockerMessage::StopContainer(id) => {
-                    tokio::spawn(async move {
-                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+            },
+            Err(e) => Err(e),
+        };
+        gui_state.lock().set_loading_status(None);
+        Self::set_info(&gui_state, result.unwrap_or_else(|e| e));
+    }
+
+    /// Open a confirm dialog to remove a service, and all of its tasks
+    fn confirm_delete_service(&self, name: String) {
+        let tasks = self
+            .app_data
+            .lock()
+            .service_data
+            .get_tasks()
+            .iter()
+            .filter(|i| i.is_running())
+            .count();
+        self.gui_state.lock().set_confirm(Some(Confirm::new(
+            String::from(" Confirm Delete "),
+            vec![
+                format!("Are you sure you want to delete service: {name}"),
+                format!("{tasks} running tasks will be stopped"),
+            ],
+            DockerMessage::DeleteService(name),
+        )));
+    }
+
+    /// Open a confirm dialog to change a node, draining moves all of its tasks to other nodes, and demoting can lose the quorum of managers
+    fn confirm_update_node(&self, id: String, change: NodeChange) {
+        let hostname = self
+            .app_data
+            .lock()
+            .node_data
+            .get_node_items()
+            .iter()
+            .find(|i| i.id == id)
+            .map_or_else(|| id.clone(), |i| i.hostname.clone());
+        let warning = match change {
+            NodeChange::Drain => "all tasks will be moved to other nodes",
+            NodeChange::Demote => "make sure enough managers remain to keep the quorum",
+            _ => "",
+        };
+        self.gui_state.lock().set_confirm(Some(Confirm::new(
+            format!(" Confirm {} ", change.to_string().to_uppercase()),
+            vec![
+                format!("Are you sure you want to {change} node: {hostname}"),
+                warning.to_owned(),
+            ],
+            DockerMessage::UpdateNode(id, change),
+        )));
+    }
+
+    /// Change the spec of a service, the current version is sent with the update, so docker rejects it if the service was changed in the meantime
+    /// If the change can't be made to the spec, the error is returned, and the service isn't updated
+    async fn update_service(
+        docker: &Docker,
+        name: &str,
+        change: impl FnOnce(&mut ServiceSpec) -> Result<(), String> + Send,
+    ) -> Result<(), String> {
+        let service = docker
+            .inspect_service(name, None::<InspectServiceOptions>)
+            .await
+            .map_err(Self::error_message)?;
+        let version = service.version.and_then(|i| i.index).unwrap_or_default();
+        let mut spec = service.spec.unwrap_or_default();
+        change(&mut spec)?;
+        docker
+            .update_service(
+                name,
+                spec,
+                UpdateServiceOptions {
+                    version,
+                    ..Default::default()
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(Self::error_message)
+    }
+
+    /// Connect a container to a network, with an optional alias and static address
+    async fn connect_network(
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        connect: NetworkConnect,
+    ) {
+        let ipam_config = connect.ip.map(|ip| EndpointIpamConfig {
+            ipv4_address: Some(ip.to_string()).filter(|_| ip.is_ipv4()),
+            ipv6_address: Some(ip.to_string()).filter(|_| ip.is_ipv6()),
+            ..Default::default()
+        });
+        let options = ConnectNetworkOptions {
+            container: connect.container.as_str(),
+            endpoint_config: EndpointSettings {
+                aliases: connect.alias.clone().map(|i| vec![i]),
+                ipam_config,
+                ..Default::default()
+            },
+        };
+        let text = match docker.connect_network(&connect.network, options).await {
+            Ok(()) => format!("connected {} to {}", connect.container, connect.network),
+            Err(e) => format!(
+                "unable to connect {} to {}: {e}",
+                connect.container, connect.network
+            ),
+        };
+        Self::set_info(&gui_state, text);
+    }
+
+    /// Disconnect a container from a network
+    async fn disconnect_network(
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        container: String,
+        network: String,
+    ) {
+        let options = DisconnectNetworkOptions {
+            container: container.as_str(),
+            force: false,
+        };
+        let text = match docker.disconnect_network(&network, options).await {
+            Ok(()) => format!("disconnected {container} from {network}"),
+            Err(e) => format!("unable to disconnect {container} from {network}: {e}"),
+        };
+        Self::set_info(&gui_state, text);
+    }
+
+    /// Pull an image, if it's not available locally, used before creating a container from it
+    async fn pull_missing_image(docker: &Docker, image: &str) -> Result<(), String> {
+        if docker.inspect_image(image).await.is_ok() {
+            return Ok(());
+        }
+        let (from_image, tag) = split_repo_tag(image);
+        let mut stream = docker.create_image(
+            Some(CreateImageOptions {
+                from_image,
+                tag,
+                ..Default::default()
+            }),
+            None,
+            None,
+        );
+        while let Some(value) = stream.next().await {
+            value.map_err(|e| format!("unable to pull {image}: {e}"))?;
+        }
+        Ok(())
+    }
+
+    /// Create, and start, a new container, pulling its image first if needed
+    /// The new container is selected once it appears in the containers list
+    async fn run_container(
+        app_data: Arc<Mutex<AppData>>,
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        run: ContainerRun,
+    ) {
+        let result = async {
+            gui_state
+                .lock()
+                .set_loading_status(Some(format!("pulling {}", run.image)));
+            Self::pull_missing_image(&docker, &run.image).await?;
+            gui_state
+                .lock()
+                .set_loading_status(Some(format!("creating {}", run.image)));
+            let mut config = Config::default();
+            container_config::apply_run(&mut config, &run);
+            let id = docker
+                .create_container(
+                    run.name.as_ref().map(|name| CreateContainerOptions {
+                        name: name.as_str(),
+                        platform: None,
+                    }),
+                    config,
+                )
+                .await
+                .map_err(|e| format!("unable to create container: {}", Self::error_message(e)))?
+                .id;
+            app_data
+                .lock()
+                .container_data
+                .select_when_present(ContainerId::from(id.as_str()));
+            docker
+                .start_container(&id, None::<StartContainerOptions<String>>)
+                .await
+                .map_err(|e| {
+                    format!(
+                        "created, but unable to start, container: {}",
+                        Self::error_message(e)
+                    )
+                })?;
+            Ok::<_, String>(run.name.clone().unwrap_or(id))
+        }
+        .await;
+        gui_state.lock().set_loading_status(None);
+        let text = match result {
+            Ok(name) => format!("started {name}"),
+            Err(e) => e,
+        };
+        Self::set_info(&gui_state, text);
+    }
+
+    /// Inspect a container, and open the commit form, prefilled with the config of the container
+    async fn commit_form(docker: Arc<Docker>, gui_state: Arc<Mutex<GuiState>>, id: ContainerId) {
+        match docker.inspect_container(id.get(), None).await {
+            Ok(info) => {
+                let config = info.config.unwrap_or_default();
+                let name = info.name.unwrap_or_default();
+                let commit = ContainerCommit {
+                    tag: format!("{}:latest", name.trim_start_matches('/').to_lowercase()),
+                    cmd: config.cmd.unwrap_or_default(),
+                    entrypoint: config.entrypoint.unwrap_or_default(),
+                    env: config.env.unwrap_or_default(),
+                    labels: config.labels.unwrap_or_default(),
+                    exposed_ports: config
+                        .exposed_ports
+                        .map(|i| i.into_keys().collect())
+                        .unwrap_or_default(),
+                    recreate: false,
+                };
+                gui_state
+                    .lock()
+                    .set_form(Some(Form::commit_container(id, &commit)));
+            }
+            Err(e) => Self::set_info(
+                &gui_state,
+                format!("unable to inspect container: {}", Self::error_message(e)),
+            ),
+        }
+    }
+
+    /// Commit a container to a new image, and if wanted, recreate the container from the new image
+    #[allow(clippy::zero_sized_map_values)]
+    async fn commit_container(
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        id: ContainerId,
+        commit: ContainerCommit,
+    ) {
+        let (repo, tag) = split_repo_tag(&commit.tag);
+        let some = |i: &Vec<String>| Some(i.clone()).filter(|i| !i.is_empty());
+        let options = CommitContainerOptions {
+            container: id.get().to_owned(),
+            repo,
+            tag,
+            pause: true,
+            ..Default::default()
+        };
+        let config = Config {
+            cmd: some(&commit.cmd),
+            entrypoint: some(&commit.entrypoint),
+            env: some(&commit.env),
+            labels: Some(commit.labels.clone()).filter(|i| !i.is_empty()),
+            exposed_ports: Some(
+                commit
+                    .exposed_ports
+                    .iter()
+                    .map(|i| (i.clone(), HashMap::new()))
+                    .collect(),
+            )
+            .filter(|i: &HashMap<_, _>| !i.is_empty()),
+            ..Default::default()
+        };
+        let text = match docker.commit_container(options, config).await {
+            Ok(_) if commit.recreate => {
+                gui_state
+                    .lock()
+                    .set_loading_status(Some(format!("recreating from {}", commit.tag)));
+                // The committed image has the container's config, with the changes from the commit form, so use its values rather than the copied ones
+                let result = Self::recreate_container(&docker, &gui_state, &id, |config| {
+                    config.image = Some(commit.tag.clone());
+                    config.cmd = None;
+                    config.entrypoint = None;
+                    config.env = None;
+                    config.labels = None;
+                    config.exposed_ports = None;
+                })
+                .await;
+                gui_state.lock().set_loading_status(None);
+                match result {
+                    Ok(name) => format!("committed {}, and recreated {name}", commit.tag),
+                    Err(e) => format!("committed {}, but {e}", commit.tag),
+                }
+            }
+            Ok(_) => format!("committed {}", commit.tag),
+            Err(e) => format!("unable to commit container: {}", Self::error_message(e)),
+        };
+        Self::set_info(&gui_state, text);
+    }
+
+    /// Inspect a container, and open the recreate form, prefilled with the config of the container
+    async fn recreate_form(docker: Arc<Docker>, gui_state: Arc<Mutex<GuiState>>, id: ContainerId) {
+        match docker.inspect_container(id.get(), None).await {
+            Ok(info) => {
+                let recreate = Recreate::new(&id, info);
+                let run = container_config::to_run(&recreate.config);
+                gui_state
+                    .lock()
+                    .set_form(Some(Form::recreate_container(id, &recreate.name, &run)));
+            }
+            Err(e) => Self::set_info(
+                &gui_state,
+                format!("unable to inspect container: {}", Self::error_message(e)),
+            ),
+        }
+    }
+
+    /// Replace a container with a copy, with the same name, config, and networks, the config can be changed before the copy is created
+    /// The original is stopped, and renamed as a backup, if the copy can't be created it's restored, and if the copy can't start, a rollback is offered
+    /// The copy is only started if the original was running, returns the name of the container
+    async fn recreate_container(
+        docker: &Docker,
+        gui_state: &Arc<Mutex<GuiState>>,
+        id: &ContainerId,
+        change: impl FnOnce(&mut Config<String>) + Send,
+    ) -> Result<String, String> {
+        let info = docker
+            .inspect_container(id.get(), None)
+            .await
+            .map_err(|e| format!("unable to inspect container: {}", Self::error_message(e)))?;
+        let Recreate {
+            name,
+            running,
+            mut config,
+            networks,
+        } = Recreate::new(id, info);
+        change(&mut config);
+        let backup = format!("{name}{BACKUP_SUFFIX}");
+
+        docker
+            .rename_container(
+                id.get(),
+                RenameContainerOptions {
+                    name: backup.as_str(),
+                },
+            )
+            .await
+            .map_err(|e| {
+                format!(
+                    "unable to rename {name} to {backup}: {}",
+                    Self::error_message(e)
+                )
+            })?;
+        let mut container_backup = ContainerBackup {
+            name: name.clone(),
+            backup: backup.clone(),
+            new: id.clone(),
+            running,
+        };
+        if running {
+            if let Err(e) = docker.stop_container(id.get(), None).await {
+                Self::restore_backup(docker, &container_backup, false).await;
+                return Err(format!("unable to stop {name}: {}", Self::error_message(e)));
+            }
+        }
+
+        let new_id = match docker
+            .create_container(
+                Some(CreateContainerOptions {
+                    name: name.as_str(),
+                    platform: None,
+                }),
+                config,
+            )
+            .await
+        {
+            Ok(response) => ContainerId::from(response.id),
+            Err(e) => {
+                Self::restore_backup(docker, &container_backup, false).await;
+                return Err(format!(
+                    "unable to create {name}, restored the original: {}",
+                    Self::error_message(e)
+                ));
+            }
+        };
+        container_backup.new = new_id.clone();
+        for (network, endpoint_config) in networks {
+            let options = ConnectNetworkOptions {
+                container: new_id.get(),
+                endpoint_config,
+            };
+            if let Err(e) = docker.connect_network(&network, options).await {
+                Self::restore_backup(docker, &container_backup, true).await;
+                return Err(format!(
+                    "unable to connect {name} to {network}, restored the original: {}",
+                    Self::error_message(e)
+                ));
+            }
+        }
+
+        if running {
+            if let Err(e) = docker
+                .start_container(new_id.get(), None::<StartContainerOptions<String>>)
+                .await
+            {
+                let e = Self::error_message(e);
+                gui_state.lock().set_confirm(Some(Confirm::new(
+                    String::from(" Roll back "),
+                    vec![
+                        format!("Unable to start the new {name}: {e}"),
+                        format!(
+                            "Do you want to remove it, and restore the original from {backup}?"
+                        ),
+                    ],
+                    DockerMessage::RollbackContainer(container_backup),
+                )));
+                return Err(format!("unable to start {name}: {e}"));
+            }
+        }
+        docker.remove_container(&backup, None).await.map_err(|e| {
+            format!(
+                "recreated {name}, but unable to remove {backup}: {}",
+                Self::error_message(e)
+            )
+        })?;
+        Ok(name)
+    }
+
+    /// Give the original container its name back, and start it again if it was running
+    /// If the new container was created, it's removed first, so that the name is free
+    async fn restore_backup(docker: &Docker, backup: &ContainerBackup, remove_new: bool) -> String {
+        let result = async {
+            if remove_new {
+                docker
+                    .remove_container(
+                        backup.new.get(),
+                        Some(RemoveContainerOptions {
+                            force: true,
+                            ..Default::default()
+                        }),
+                    )
+                    .await?;
+            }
+            docker
+                .rename_container(
+                    &backup.backup,
+                    RenameContainerOptions {
+                        name: backup.name.as_str(),
+                    },
+                )
+                .await?;
+            if backup.running {
+                docker
+                    .start_container(&backup.name, None::<StartContainerOptions<String>>)
+                    .await?;
+            }
+            Ok::<_, bollard::errors::Error>(())
+        }
+        .await;
+        match result {
+            Ok(()) => format!("restored {} from {}", backup.name, backup.backup),
+            Err(e) => format!(
+                "unable to restore {} from {}: {}",
+                backup.name,
+                backup.backup,
+                Self::error_message(e)
+            ),
+        }
+    }
+
+    /// Convert a container into an equivalent `docker run` command, and compose service, and show them in the export popup
+    /// The image is inspected too, so that anything the container gets from its image can be left out
+    async fn export_container(
+        app_data: Arc<Mutex<AppData>>,
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        id: ContainerId,
+    ) {
+        let info = match docker.inspect_container(id.get(), None).await {
+            Ok(info) => info,
+            Err(e) => {
+                Self::set_info(
+                    &gui_state,
+                    format!("unable to inspect container: {}", Self::error_message(e)),
+                );
+                return;
+            }
+        };
+        let image = match info.image.as_deref() {
+            Some(image) => docker
+                .inspect_image(image)
+                .await
+                .ok()
+                .and_then(|i| i.config)
+                .unwrap_or_default(),
+            None => ContainerConfig::default(),
+        };
+        let recreate = Recreate::new(&id, info);
+        let export = ContainerExport::new(
+            recreate.name.clone(),
+            container_config::run_command(&recreate, &image),
+            container_config::compose_service(&recreate, &image),
+        );
+        app_data.lock().set_export(Some(export));
+        gui_state.lock().status_push(Status::Export);
+    }
+
+    /// Save the run command, or the compose service, of the current export to a file
+    async fn save_export(
+        app_data: Arc<Mutex<AppData>>,
+        gui_state: Arc<Mutex<GuiState>>,
+        format: ExportFormat,
+        file: String,
+    ) {
+        let text = app_data.lock().get_export().map(|i| i.text(format));
+        let text = match text {
+            Some(text) => match tokio::fs::write(&file, text).await {
+                Ok(()) => format!("saved {file}"),
+                Err(e) => format!("unable to save {file}: {e}"),
+            },
+            None => String::from("nothing to save"),
+        };
+        Self::set_info(&gui_state, text);
+    }
+
+    /// Create a network, if a subnet is given it's used for the ipam config
+    async fn create_network(
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        create: NetworkCreate,
+    ) {
+        let options = CreateNetworkOptions {
+            name: create.name.as_str(),
+            check_duplicate: true,
+            driver: create.driver.as_str(),
+            ipam: Ipam {
+                config: create.subnet.clone().map(|subnet| {
+                    vec![IpamConfig {
+                        subnet: Some(subnet),
+                        ..Default::default()
+                    }]
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let text = match docker.create_network(options).await {
+            Ok(_) => format!("created network {}", create.name),
+            Err(e) => format!("unable to create network {}: {e}", create.name),
+        };
+        Self::set_info(&gui_state, text);
+    }
+
+    /// Prune all unused volumes, named as well as anonymous, matching the preview, and show how many were removed, and how much space was reclaimed
+    async fn prune_volumes(
+        app_data: Arc<Mutex<AppData>>,
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+    ) {
+        // Since API 1.42 only anonymous volumes are pruned, unless `all` is set, which daemons older than that reject, but they prune named volumes anyway
+        let all = PruneVolumesOptions {
+            filters: HashMap::from([("all", vec!["true"])]),
+        };
+        let result = match docker.prune_volumes(Some(all)).await {
+            Err(bollard::errors::Error::DockerResponseServerError {
+                status_code: 400, ..
+            }) => {
+                docker
+                    .prune_volumes(None::<PruneVolumesOptions<String>>)
+                    .await
+            }
+            result => result,
+        };
+        match result {
+            Ok(response) => {
+                let removed = response.volumes_deleted.unwrap_or_default().len();
+                let reclaimed = ByteStats::new(
+                    response
+                        .space_reclaimed
+                        .and_then(|i| u64::try_from(i).ok())
+                        .unwrap_or_default(),
+                );
+                Self::set_info(
+                    &gui_state,
+                    format!("pruned {removed} volumes, reclaimed {reclaimed}"),
+                );
+            }
+            Err(_) => Self::set_error(&app_data, &gui_state),
+        }
+    }
+
+    /// Prune images, and show how many were removed, and how much space was reclaimed
+    async fn prune_images(
+        app_data: Arc<Mutex<AppData>>,
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        prune: ImagePrune,
+    ) {
+        match docker
+            .prune_images(Some(PruneImagesOptions {
+                filters: prune.filters(),
+            }))
+            .await
+        {
+            Ok(response) => {
+                let removed = response
+                    .images_deleted
+                    .unwrap_or_default()
+                    .iter()
+                    .filter(|i| i.deleted.is_some())
+                    .count();
+                let reclaimed = ByteStats::new(
+                    response
+                        .space_reclaimed
+                        .and_then(|i| u64::try_from(i).ok())
+                        .unwrap_or_default(),
+                );
+                Self::set_info(
+                    &gui_state,
+                    format!("pruned {removed} images, reclaimed {reclaimed}"),
+                );
+            }
+            Err(_) => Self::set_error(&app_data, &gui_state),
+        }
+    }
+
+    /// Find an image to create a volume helper container from, preferring small images that can run a shell, pulling busybox if there are none
+    /// Only if busybox can't be pulled, e.g. when offline, is any other local image used
+    async fn helper_image(docker: &Docker) -> Result<String, bollard::errors::Error> {
+        let images = docker
+            .list_images(None::<ListImagesOptions<String>>)
+            .await?
+            .into_iter()
+            .flat_map(|i| i.repo_tags)
+            .filter(|i| i != "<none>:<none>")
+            .collect::<Vec<_>>();
+        if let Some(image) = images
+            .iter()
+            .find(|i| i.starts_with("busybox:") || i.starts_with("alpine:"))
+        {
+            return Ok(image.clone());
+        }
+        let mut stream = docker.create_image(
+            Some(CreateImageOptions {
+                from_image: HELPER_IMAGE,
+                ..Default::default()
+            }),
+            None,
+            None,
+        );
+        while let Some(value) = stream.next().await {
+            if let Err(e) = value {
+                return images.first().cloned().ok_or(e);
+            }
+        }
+        Ok(HELPER_IMAGE.to_owned())
+    }
+
+    /// Create, and start, a container with the volume mounted read only, so that its directories can be listed inside it, and its files downloaded as archives
+    /// If the helper image can't run `sleep`, the container won't start, but the files can still be downloaded
+    async fn create_volume_helper(
+        docker: &Docker,
+        volume: &str,
+    ) -> Result<ContainerId, bollard::errors::Error> {
+        let image = Self::helper_image(docker).await?;
+        let name = format!("d5r-volume-{}", Uuid::new_v4());
+        let response = docker
+            .create_container(
+                Some(CreateContainerOptions {
+                    name: name.as_str(),
+                    platform: None,
+                }),
+                Config {
+                    image: Some(image),
+                    cmd: Some(vec![String::from("sleep"), String::from("2147483647")]),
+                    labels: Some(HashMap::from([(
+                        HELPER_LABEL.to_owned(),
+                        volume.to_owned(),
+                    )])),
+                    host_config: Some(HostConfig {
+                        binds: Some(vec![format!("{volume}:/volume:ro")]),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        docker
+            .start_container(&response.id, None::<StartContainerOptions<String>>)
+            .await
+            .ok();
+        Ok(ContainerId::from(response.id))
+    }
+
+    /// Close the file browser, removing the helper container if one was created for it
+    async fn close_files(app_data: &Arc<Mutex<AppData>>, docker: &Docker) {
+        let files = app_data.lock().files.take();
+        if let Some(files) = files.filter(|i| i.helper) {
+            docker
+                .remove_container(
+                    files.container.get(),
+                    Some(RemoveContainerOptions {
+                        force: true,
+                        ..Default::default()
+                    }),
+                )
+                .await
+                .ok();
+        }
+    }
+
+    /// Open the file browser at the root of a volume, via a helper container
+    async fn browse_volume(
+        app_data: Arc<Mutex<AppData>>,
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        name: String,
+    ) {
+        Self::close_files(&app_data, &docker).await;
+        match Self::create_volume_helper(&docker, &name).await {
+            Ok(id) => {
+                app_data.lock().set_files(Some(FileBrowser::new(
+                    format!("volume {name}"),
+                    id,
+                    true,
+                    "/volume",
+                )));
+                Self::list_files(app_data, docker, gui_state, String::from("/volume")).await;
+            }
+            Err(e) => {
+                gui_state.lock().back_in_nav();
+                Self::set_info(&gui_state, format!("unable to browse {name}: {e}"));
+            }
+        }
+    }
+
+    /// Get the paths changed in the filesystem of a container, compared to its image
+    async fn update_changes(
+        app_data: Arc<Mutex<AppData>>,
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        id: ContainerId,
+    ) {
+        match docker.container_changes(id.get()).await {
+            Ok(changes) => {
+                let mut app_data = app_data.lock();
+                let name = app_data
+                    .container_data
+                    .get_container_name_by_id(&id)
+                    .unwrap_or_else(|| id.get().to_owned());
+                let changes = changes
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(ChangeItem::from)
+                    .collect();
+                app_data.set_changes(ContainerChanges::new(name, id, changes));
+            }
+            Err(e) => {
+                gui_state.lock().back_in_nav();
+                Self::set_info(
+                    &gui_state,
+                    format!(
+                        "unable to get changes of {}: {}",
+                        id.get(),
+                        Self::error_message(e)
+                    ),
+                );
+            }
+        }
+    }
+
+    /// List the processes of a container, if they can't be listed, e.g. the container has stopped, leave the processes panel
+    async fn update_processes(
+        app_data: Arc<Mutex<AppData>>,
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        id: ContainerId,
+    ) {
+        match docker
+            .top_processes(id.get(), Some(TopOptions { ps_args: PS_ARGS }))
+            .await
+        {
+            Ok(top) => app_data.lock().process_data.update_processes(top),
+            Err(e) => {
+                let mut gui_state_lock = gui_state.lock();
+                if gui_state_lock.get_current_nav() == &NavPanel::Processes {
+                    gui_state_lock.back_in_nav();
+                }
+                drop(gui_state_lock);
+                Self::set_info(
+                    &gui_state,
+                    format!(
+                        "unable to list processes of {}: {}",
+                        id.get(),
+                        Self::error_message(e)
+                    ),
+                );
+            }
+        }
+    }
+
+    /// Execute a command inside a running container, returning its exit code, stdout, and stderr
+    async fn exec(
+        docker: &Docker,
+        container: &str,
+        cmd: Vec<String>,
+    ) -> Result<(Option<i64>, String, String), bollard::errors::Error> {
+        let exec = docker
+            .create_exec(
+                container,
+                CreateExecOptions {
+                    attach_stdout: Some(true),
+                    attach_stderr: Some(true),
+                    cmd: Some(cmd),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let (mut stdout, mut stderr) = (vec![], vec![]);
+        if let StartExecResults::Attached { mut output, .. } =
+            docker.start_exec(&exec.id, None).await?
+        {
+            while let Some(Ok(line)) = output.next().await {
+                match line {
+                    LogOutput::StdErr { message } => stderr.extend_from_slice(&message),
+                    line => stdout.extend_from_slice(&line.into_bytes()),
+                }
+            }
+        }
+        let exit_code = docker.inspect_exec(&exec.id).await?.exit_code;
+        Ok((
+            exit_code,
+            String::from_utf8_lossy(&stdout).to_string(),
+            String::from_utf8_lossy(&stderr).to_string(),
+        ))
+    }
+
+    /// Send a signal to a process in a container, by executing a script inside the container that finds the process by its command, and then runs `kill`
+    async fn signal_process(
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        id: ContainerId,
+        target: ProcessTarget,
+        signal: Signal,
+    ) {
+        let pid = target.pid;
+        let cmd = vec![
+            String::from("sh"),
+            String::from("-c"),
+            SIGNAL_SCRIPT.to_owned(),
+            String::from("sh"),
+            signal.get().to_string(),
+            target.command,
+            target.index.to_string(),
+            target.count.to_string(),
+        ];
+        match Self::exec(&docker, id.get(), cmd).await {
+            Ok((Some(0), _, _)) => Self::set_info(&gui_state, format!("sent {signal} to {pid}")),
+            Ok((_, stdout, stderr)) => Self::set_info(
+                &gui_state,
+                format!(
+                    "unable to send {signal} to {pid}: {}{}",
+                    stdout.trim(),
+                    stderr.trim()
+                ),
+            ),
+            Err(e) => Self::set_info(
+                &gui_state,
+                format!(
+                    "unable to send {signal} to {pid}: {}",
+                    Self::error_message(e)
+                ),
+            ),
+        }
+    }
+
+    /// Open the file browser at the working directory of a container, or the root if it has none
+    async fn browse_container(
+        app_data: Arc<Mutex<AppData>>,
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        id: ContainerId,
+    ) {
+        Self::close_files(&app_data, &docker).await;
+        match docker.inspect_container(id.get(), None).await {
+            Ok(inspect) => {
+                let name = inspect.name.map_or_else(
+                    || id.get().to_owned(),
+                    |i| i.trim_start_matches('/').to_owned(),
+                );
+                let workdir = inspect
+                    .config
+                    .and_then(|i| i.working_dir)
+                    .filter(|i| !i.is_empty());
+                let mut files = FileBrowser::new(format!("container {name}"), id, false, "/");
+                files.workdir.clone_from(&workdir);
+                app_data.lock().set_files(Some(files));
+                Self::list_files(
+                    app_data,
+                    docker,
+                    gui_state,
+                    workdir.unwrap_or_else(|| String::from("/")),
+                )
+                .await;
+            }
+            Err(e) => {
+                gui_state.lock().back_in_nav();
+                Self::set_info(
+                    &gui_state,
+                    format!("unable to browse {}: {}", id.get(), Self::error_message(e)),
+                );
+            }
+        }
+    }
+
+    /// Get the id of the container of the open file browser
+    fn files_container(app_data: &Arc<Mutex<AppData>>) -> Option<String> {
+        app_data
+            .lock()
+            .get_mut_files()
+            .map(|i| i.container.get().to_owned())
+    }
+
+    /// List the entries of a directory in the file browser, only the direct children are listed, by running a script inside the container
+    async fn list_files(
+        app_data: Arc<Mutex<AppData>>,
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        path: String,
+    ) {
+        let Some(container) = Self::files_container(&app_data) else {
+            return;
+        };
+        let cmd = vec![
+            String::from("sh"),
+            String::from("-c"),
+            LIST_SCRIPT.to_owned(),
+            String::from("sh"),
+            path.clone(),
+        ];
+        let entries = match Self::exec(&docker, &container, cmd).await {
+            Ok((Some(0), stdout, _)) => Ok(parse_listing(&stdout)),
+            Ok((Some(1), _, stderr)) => Err(stderr.trim().to_owned()),
+            // The container isn't running, or doesn't have a shell, so list an archive of the whole directory instead
+            _ => {
+                let is_root = path.trim_end_matches('/').is_empty();
+                archive::read_archive(&docker, &container, &path, move |i| {
+                    archive::list_directory(i, is_root)
+                })
+                .await
+                .map_err(|e| e.to_string())
+            }
+        };
+        match entries {
+            Ok(entries) => {
+                if let Some(files) = app_data.lock().get_mut_files() {
+                    files.set_entries(path, entries);
+                }
+            }
+            Err(e) => Self::set_info(&gui_state, format!("unable to list {path}: {e}")),
+        }
+    }
+
+    /// Show a text file in the file viewer
+    async fn view_file(
+        app_data: Arc<Mutex<AppData>>,
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        path: String,
+    ) {
+        let Some(container) = Self::files_container(&app_data) else {
+            return;
+        };
+        match archive::read_archive(&docker, &container, &path, |i| {
+            archive::read_text(i, VIEW_LIMIT)
+        })
+        .await
+        {
+            Ok(text) => {
+                if let Some(files) = app_data.lock().get_mut_files() {
+                    files.set_viewer(path, &text);
+                }
+                gui_state.lock().append_nav(NavPanel::FileView);
+            }
+            Err(e) => Self::set_info(&gui_state, format!("unable to view {path}: {e}")),
+        }
+    }
+
+    /// Save a file from the file browser to the local filesystem, or if `directory`, save the whole directory as a tarball
+    async fn save_files(
+        app_data: Arc<Mutex<AppData>>,
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        path: String,
+        file: String,
+        directory: bool,
+    ) {
+        let Some(container) = Self::files_container(&app_data) else {
+            return;
+        };
+        let result = if directory {
+            archive::download_archive(&docker, &container, &path, &file).await
+        } else {
+            let output = PathBuf::from(&file);
+            archive::read_archive(&docker, &container, &path, move |i| {
+                archive::save_file(i, &output)
+            })
+            .await
+        };
+        match result {
+            Ok(size) => Self::set_info(
+                &gui_state,
+                format!("saved {path} to {file} ({})", ByteStats::new(size)),
+            ),
+            Err(e) => Self::set_info(&gui_state, format!("unable to save {path}: {e}")),
+        }
+    }
+
+    /// Download a path from a container, extracting it into a local directory, with the bytes extracted shown in the loading status
+    async fn download_files(
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        id: ContainerId,
+        path: String,
+        directory: String,
+    ) {
+        let output = PathBuf::from(&directory);
+        let status = Arc::clone(&gui_state);
+        let result = match tokio::fs::create_dir_all(&output).await {
+            Ok(()) => {
+                archive::read_archive(&docker, id.get(), &path, move |i| {
+                    archive::unpack(i, &output, |size| {
+                        status.lock().set_loading_status(Some(format!(
+                            "downloading {}",
+                            ByteStats::new(size)
+                        )));
+                    })
+                })
+                .await
+            }
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(size) => Self::set_info(
+                &gui_state,
+                format!(
+                    "downloaded {path} to {directory} ({})",
+                    ByteStats::new(size)
+                ),
+            ),
+            Err(e) => Self::set_info(&gui_state, format!("unable to download {path}: {e}")),
+        }
+    }
+
+    /// Upload a local file, or directory, into a directory of a container, archiving it whilst it's uploaded, with the bytes sent shown in the loading status
+    async fn upload_files(
+        docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        id: ContainerId,
+        path: String,
+        directory: String,
+    ) {
+        gui_state
+            .lock()
+            .set_loading_status(Some(format!("uploading {path}")));
+        let input = PathBuf::from(&path);
+        let progress_state = Arc::clone(&gui_state);
+        let (body, packing) = archive::stream_archive(
+            move |builder| archive::pack(builder, &input),
+            move |sent| {
+                progress_state
+                    .lock()
+                    .set_loading_status(Some(format!("uploading {}", ByteStats::new(sent))));
+            },
+        );
+        let (uploaded, packed) = tokio::join!(
+            archive::upload_archive(&docker, id.get(), &directory, body),
+            packing
+        );
+        match (
+            uploaded,
+            packed.unwrap_or_else(|e| Err(std::io::Error::other(e))),
+        ) {
+            (Ok(()), Ok(size)) => Self::set_info(
+                &gui_state,
+                format!("uploaded {path} to {directory} ({})", ByteStats::new(size)),
+            ),
+            // Unless docker closed the upload first, a failure to read the local files is the cause of the upload failing
+            (_, Err(e)) if e.kind() != std::io::ErrorKind::BrokenPipe => {
+                Self::set_info(&gui_state, format!("unable to read {path}: {e}"));
+            }
+            (Err(e), _) | (_, Err(e)) => Self::set_info(
+                &gui_state,
+                format!("unable to upload {path} to {directory}: {e}"),
+            ),
+        }
+    }
+
+    /// Handle incoming messages, container controls & all container information update
+    /// Spawn Docker commands off into own thread
+    async fn message_handler(&mut self) {
+        while let Some(message) = self.receiver.recv().await {
+            let docker = Arc::clone(&self.docker);
+            let gui_state = Arc::clone(&self.gui_state);
+            let app_data = Arc::clone(&self.app_data);
+            let uuid = Uuid::new_v4();
+            // TODO need to refactor these
+            match message {
+                DockerMessage::PauseContainer(id) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        if docker.pause_container(id.get()).await.is_err() {
+                            Self::set_error(&app_data, &gui_state);
+                        }
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::RestartContainer(id) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        if docker.restart_container(id.get(), None).await.is_err() {
+                            Self::set_error(&app_data, &gui_state);
+                        }
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::StartContainer(id) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        if docker
+                            .start_container(id.get(), None::<StartContainerOptions<String>>)
+                            .await
+                            .is_err()
+                        {
+                            Self::set_error(&app_data, &gui_state);
+                        }
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::StopContainer(id) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
                         if docker.stop_container(id.get(), None).await.is_err() {
                             Self::set_error(&app_data, &gui_state);
                         }
@@ -411,27 +2251,259 @@ impl DockerData {
                     });
                     self.update_everything().await;
                 }
-                DockerMessage::UnpauseContainer(id) => {
+                DockerMessage::RestartProject(project) => {
+                    let ids = app_data
+                        .lock()
+                        .container_data
+                        .get_project_ids(&project, |_| true);
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        for id in ids {
+                            if docker.restart_container(id.get(), None).await.is_err() {
+                                Self::set_error(&app_data, &gui_state);
+                                break;
+                            }
+                        }
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::StartProject(project) => {
+                    let ids = app_data
+                        .lock()
+                        .container_data
+                        .get_project_ids(&project, |state| {
+                            matches!(state, State::Dead | State::Exited | State::Unknown)
+                        });
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        for id in ids {
+                            if docker
+                                .start_container(id.get(), None::<StartContainerOptions<String>>)
+                                .await
+                                .is_err()
+                            {
+                                Self::set_error(&app_data, &gui_state);
+                                break;
+                            }
+                        }
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::StopProject(project) => {
+                    let ids = app_data
+                        .lock()
+                        .container_data
+                        .get_project_ids(&project, |state| {
+                            matches!(state, State::Running | State::Paused | State::Restarting)
+                        });
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        for id in ids {
+                            if docker.stop_container(id.get(), None).await.is_err() {
+                                Self::set_error(&app_data, &gui_state);
+                                break;
+                            }
+                        }
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::KillContainer(id, signal) => {
+                    let name = app_data
+                        .lock()
+                        .container_data
+                        .get_container_name_by_id(&id)
+                        .unwrap_or_else(|| id.get().to_owned());
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        match docker
+                            .kill_container(
+                                id.get(),
+                                Some(KillContainerOptions {
+                                    signal: signal.to_string(),
+                                }),
+                            )
+                            .await
+                        {
+                            Ok(()) => {
+                                Self::set_info(&gui_state, format!("sent {signal} to {name}"));
+                            }
+                            Err(e) => Self::set_info(
+                                &gui_state,
+                                format!(
+                                    "unable to send {signal} to {name}: {}",
+                                    Self::error_message(e)
+                                ),
+                            ),
+                        }
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::UnpauseContainer(id) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        if docker.unpause_container(id.get()).await.is_err() {
+                            Self::set_error(&app_data, &gui_state);
+                        }
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::DeleteContainer(id) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        if docker
+                            .remove_container(
+                                id.get(),
+                                Some(RemoveContainerOptions {
+                                    v: false,
+                                    force: true,
+                                    link: false,
+                                }),
+                            )
+                            .await
+                            .is_err()
+                        {
+                            Self::set_error(&app_data, &gui_state);
+                        }
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                    self.gui_state.lock().set_delete_container(None);
+                }
+                DockerMessage::ConfirmDeleteContainer(id) => {
+                    self.gui_state.lock().set_delete_container(Some(id));
+                }
+                DockerMessage::InfosContainer(id) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::update_container_info(app_data, docker, id).await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                        return;
+                    });
+
+                    self.update_everything().await;
+                }
+                DockerMessage::ShellContainer(id) => {
+                    self.gui_state.lock().status_push(Status::Shell);
+                    Command::new("docker")
+                        .args([
+                            "exec",
+                            "-it",
+                            &id.get().chars().take(8).collect::<String>(),
+                            "/bin/sh",
+                        ])
+                        .stdout(Stdio::inherit())
+                        .stdin(Stdio::inherit())
+                        .output();
+                    self.gui_state.lock().status_del(Status::Shell);
+
+                    self.update_everything().await;
+                }
+                DockerMessage::DeleteImage(id) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        if docker
+                            .remove_image(id.get(), Some(RemoveImageOptions::default()), None)
+                            .await
+                            .is_err()
+                        {
+                            Self::set_error(&app_data, &gui_state);
+                        }
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::ForceDeleteImage(id) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        if docker
+                            .remove_image(
+                                id.get(),
+                                Some(RemoveImageOptions {
+                                    force: true,
+                                    noprune: false,
+                                }),
+                                None,
+                            )
+                            .await
+                            .is_err()
+                        {
+                            Self::set_error(&app_data, &gui_state);
+                        }
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::InfosImage(id) => {
                     tokio::spawn(async move {
                         let loading_spin = Self::loading_spin(uuid, &gui_state).await;
-                        if docker.unpause_container(id.get()).await.is_err() {
+                        Self::update_image_info(app_data, docker, id).await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                }
+                DockerMessage::HistoryImage(id) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::update_image_history(app_data, docker, Arc::clone(&gui_state), id)
+                            .await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                }
+                DockerMessage::PullImage(image) => {
+                    if let Some(handle) = self.spawns.lock().remove(&SpawnId::Progress) {
+                        handle.abort();
+                    }
+                    app_data
+                        .lock()
+                        .set_progress(Some(Progress::new(format!("pulling {image}"))));
+                    gui_state.lock().status_push(Status::Progress);
+                    let spawns = Arc::clone(&self.spawns);
+                    self.spawns.lock().insert(
+                        SpawnId::Progress,
+                        tokio::spawn(Self::pull_image(app_data, docker, image, spawns)),
+                    );
+                }
+                DockerMessage::ConfirmPruneImages(prune) => {
+                    self.confirm_prune_images(prune).await;
+                }
+                DockerMessage::PruneImages(prune) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::prune_images(app_data, docker, Arc::clone(&gui_state), prune).await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::TagImage(id, image) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        let (repo, tag) = split_repo_tag(&image);
+                        if docker
+                            .tag_image(id.get(), Some(TagImageOptions { repo, tag }))
+                            .await
+                            .is_err()
+                        {
                             Self::set_error(&app_data, &gui_state);
                         }
                         Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
                     });
                     self.update_everything().await;
                 }
-                DockerMessage::DeleteContainer(id) => {
+                DockerMessage::UntagImage(image) => {
                     tokio::spawn(async move {
                         let loading_spin = Self::loading_spin(uuid, &gui_state).await;
                         if docker
-                            .remove_container(
-                                id.get(),
-                                Some(RemoveContainerOptions {
-                                    v: false,
-                                    force: true,
-                                    link: false,
+                            .remove_image(
+                                &image,
+                                Some(RemoveImageOptions {
+                                    force: false,
+                                    noprune: true,
                                 }),
+                                None,
                             )
                             .await
                             .is_err()
@@ -441,39 +2513,419 @@ impl DockerData {
                         Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
                     });
                     self.update_everything().await;
-                    self.gui_state.lock().set_delete_container(None);
                 }
-                DockerMessage::ConfirmDeleteContainer(id) => {
-                    self.gui_state.lock().set_delete_container(Some(id));
+                DockerMessage::PushImage(image) => {
+                    if let Some(handle) = self.spawns.lock().remove(&SpawnId::Progress) {
+                        handle.abort();
+                    }
+                    app_data
+                        .lock()
+                        .set_progress(Some(Progress::new(format!("pushing {image}"))));
+                    gui_state.lock().status_push(Status::Progress);
+                    let spawns = Arc::clone(&self.spawns);
+                    self.spawns.lock().insert(
+                        SpawnId::Progress,
+                        tokio::spawn(Self::push_image(app_data, docker, image, spawns)),
+                    );
                 }
-                DockerMessage::InfosContainer(id) => {
+                DockerMessage::BuildImage(build) => {
+                    if let Some(handle) = self.spawns.lock().remove(&SpawnId::Build) {
+                        handle.abort();
+                    }
+                    app_data
+                        .lock()
+                        .set_build(Some(BuildLog::new(format!("building {}", build.tag))));
+                    if gui_state.lock().get_current_nav() != &NavPanel::BuildLog {
+                        gui_state.lock().append_nav(NavPanel::BuildLog);
+                    }
+                    let spawns = Arc::clone(&self.spawns);
+                    self.spawns.lock().insert(
+                        SpawnId::Build,
+                        tokio::spawn(Self::build_image(app_data, docker, build, spawns)),
+                    );
+                }
+                DockerMessage::CancelBuild => {
+                    if let Some(handle) = self.spawns.lock().remove(&SpawnId::Build) {
+                        handle.abort();
+                    }
+                    if let Some(build) = app_data.lock().get_mut_build() {
+                        build.finish(ProgressState::Cancelled, String::from("cancelled"));
+                    }
+                }
+                DockerMessage::SaveImages(images, file) => {
                     tokio::spawn(async move {
                         let loading_spin = Self::loading_spin(uuid, &gui_state).await;
-                        Self::update_container_info(app_data, docker, id).await;
+                        match Self::save_images(
+                            docker,
+                            Arc::clone(&gui_state),
+                            images,
+                            file.clone(),
+                        )
+                        .await
+                        {
+                            Ok(()) => Self::set_info(&gui_state, format!("saved to {file}")),
+                            Err(e) => Self::set_app_error(&app_data, &gui_state, e),
+                        }
+                        gui_state.lock().set_loading_status(None);
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                }
+                DockerMessage::LoadImages(file) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        match Self::load_images(docker, Arc::clone(&gui_state), file).await {
+                            Ok(loaded) => {
+                                Self::set_info(&gui_state, format!("loaded {}", loaded.join(", ")));
+                            }
+                            Err(e) => Self::set_app_error(&app_data, &gui_state, e),
+                        }
+                        gui_state.lock().set_loading_status(None);
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                }
+                DockerMessage::InfosVolume(name) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::update_volume_info(app_data, docker, name).await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                }
+                DockerMessage::ConfirmDeleteVolume(name) => self.confirm_delete_volume(name),
+                DockerMessage::DeleteVolume(name) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        if docker
+                            .remove_volume(&name, Some(RemoveVolumeOptions { force: false }))
+                            .await
+                            .is_err()
+                        {
+                            Self::set_error(&app_data, &gui_state);
+                        }
                         Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
-                        return;
                     });
-
                     self.update_everything().await;
                 }
-                DockerMessage::ShellContainer(id) => {
-                    self.gui_state.lock().status_push(Status::Shell);
-                    Command::new("docker")
-                        .args([
-                            "exec",
-                            "-it",
-                            &id.get().chars().take(8).collect::<String>(),
-                            "/bin/sh",
-                        ])
-                        .stdout(Stdio::inherit())
-                        .stdin(Stdio::inherit())
-                        .output();
-                    self.gui_state.lock().status_del(Status::Shell);
-
+                DockerMessage::ConfirmPruneVolumes => self.confirm_prune_volumes().await,
+                DockerMessage::PruneVolumes => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::prune_volumes(app_data, docker, Arc::clone(&gui_state)).await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::BrowseVolume(name) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::browse_volume(app_data, docker, Arc::clone(&gui_state), name).await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                }
+                DockerMessage::ContainerProcesses(id) => {
+                    let name = app_data
+                        .lock()
+                        .container_data
+                        .get_container_name_by_id(&id)
+                        .unwrap_or_else(|| id.get().to_owned());
+                    app_data.lock().process_data.set_container(id.clone(), name);
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::update_processes(app_data, docker, Arc::clone(&gui_state), id).await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                }
+                DockerMessage::SignalProcess(id, target, signal) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::signal_process(docker, Arc::clone(&gui_state), id, target, signal)
+                            .await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::ContainerChanges(id) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::update_changes(app_data, docker, Arc::clone(&gui_state), id).await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                }
+                DockerMessage::FilterChanges(filter) => {
+                    if let Some(changes) = self.app_data.lock().get_mut_changes() {
+                        changes.set_filter(filter);
+                    }
+                }
+                DockerMessage::BrowseContainer(id) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::browse_container(app_data, docker, Arc::clone(&gui_state), id).await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                }
+                DockerMessage::ListFiles(path) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::list_files(app_data, docker, Arc::clone(&gui_state), path).await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                }
+                DockerMessage::ViewFile(path) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::view_file(app_data, docker, Arc::clone(&gui_state), path).await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                }
+                DockerMessage::SaveFile(path, file) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::save_files(
+                            app_data,
+                            docker,
+                            Arc::clone(&gui_state),
+                            path,
+                            file,
+                            false,
+                        )
+                        .await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                }
+                DockerMessage::SaveDirectory(path, file) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::save_files(
+                            app_data,
+                            docker,
+                            Arc::clone(&gui_state),
+                            path,
+                            file,
+                            true,
+                        )
+                        .await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                }
+                DockerMessage::DownloadFiles(id, path, directory) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::download_files(docker, Arc::clone(&gui_state), id, path, directory)
+                            .await;
+                        gui_state.lock().set_loading_status(None);
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                }
+                DockerMessage::UploadFiles(id, path, directory) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::upload_files(docker, Arc::clone(&gui_state), id, path, directory)
+                            .await;
+                        gui_state.lock().set_loading_status(None);
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                }
+                DockerMessage::CloseFiles => {
+                    tokio::spawn(async move {
+                        Self::close_files(&app_data, &docker).await;
+                    });
+                }
+                DockerMessage::ConnectNetwork(connect) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::connect_network(docker, Arc::clone(&gui_state), connect).await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::DisconnectNetwork(container, network) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::disconnect_network(
+                            docker,
+                            Arc::clone(&gui_state),
+                            container,
+                            network,
+                        )
+                        .await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::RunContainer(run) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::run_container(app_data, docker, Arc::clone(&gui_state), run).await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::CommitForm(id) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::commit_form(docker, Arc::clone(&gui_state), id).await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                }
+                DockerMessage::CommitContainer(id, commit) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::commit_container(docker, Arc::clone(&gui_state), id, commit).await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::RecreateForm(id) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::recreate_form(docker, Arc::clone(&gui_state), id).await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                }
+                DockerMessage::RecreateContainer(id, run) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        let result = Self::recreate_container(&docker, &gui_state, &id, |config| {
+                            container_config::apply_run(config, &run);
+                        })
+                        .await;
+                        let text = match result {
+                            Ok(name) => format!("recreated {name}"),
+                            Err(e) => e,
+                        };
+                        Self::set_info(&gui_state, text);
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::RollbackContainer(backup) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        let text = Self::restore_backup(&docker, &backup, true).await;
+                        Self::set_info(&gui_state, text);
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::ExportContainer(id) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::export_container(app_data, docker, Arc::clone(&gui_state), id).await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                }
+                DockerMessage::SaveExport(format, file) => {
+                    tokio::spawn(Self::save_export(app_data, gui_state, format, file));
+                }
+                DockerMessage::CreateNetwork(create) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::create_network(docker, Arc::clone(&gui_state), create).await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::ConfirmDeleteNetwork(name) => self.confirm_delete_network(name),
+                DockerMessage::ScaleService(name, replicas) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        let result = Self::update_service(&docker, &name, |spec| {
+                            let replicated = spec
+                                .mode
+                                .as_mut()
+                                .and_then(|i| i.replicated.as_mut())
+                                .ok_or("not a replicated service")?;
+                            replicated.replicas = i64::try_from(replicas).ok();
+                            Ok(())
+                        })
+                        .await;
+                        let text = match result {
+                            Ok(()) => format!("scaled {name} to {replicas} replicas"),
+                            Err(e) => format!("unable to scale {name}: {e}"),
+                        };
+                        Self::set_info(&gui_state, text);
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::ForceUpdateService(name) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        let result = Self::update_service(&docker, &name, |spec| {
+                            if let Some(task) = spec.task_template.as_mut() {
+                                task.force_update = Some(task.force_update.unwrap_or_default() + 1);
+                            }
+                            Ok(())
+                        })
+                        .await;
+                        let text = match result {
+                            Ok(()) => format!("updating {name}, tasks will be replaced"),
+                            Err(e) => format!("unable to update {name}: {e}"),
+                        };
+                        Self::set_info(&gui_state, text);
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::ConfirmDeleteService(name) => self.confirm_delete_service(name),
+                DockerMessage::DeleteService(name) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        if let Err(e) = docker.delete_service(&name).await {
+                            Self::set_info(
+                                &gui_state,
+                                format!("unable to delete {name}: {}", Self::error_message(e)),
+                            );
+                        }
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::ConfirmUpdateNode(id, change) => {
+                    self.confirm_update_node(id, change);
+                }
+                DockerMessage::UpdateNode(id, change) => {
+                    let swarm = self.swarm.clone();
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        let text = match swarm.update_node(&id, change).await {
+                            Ok(()) => format!("{change} node {id}"),
+                            Err(e) => format!("unable to {change} node {id}: {e}"),
+                        };
+                        Self::set_info(&gui_state, text);
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
                     self.update_everything().await;
                 }
+                DockerMessage::ConfirmComposeDown(file) => self.confirm_compose_down(file).await,
+                DockerMessage::Compose(file, action) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        Self::compose(docker, Arc::clone(&gui_state), file, action).await;
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::DeleteNetwork(name) => {
+                    tokio::spawn(async move {
+                        let loading_spin = Self::loading_spin(uuid, &gui_state).await;
+                        if let Err(e) = docker.remove_network(&name).await {
+                            Self::set_info(&gui_state, format!("unable to delete {name}: {e}"));
+                        }
+                        Self::stop_loading_spin(&gui_state, &loading_spin, uuid);
+                    });
+                    self.update_everything().await;
+                }
+                DockerMessage::CancelProgress => {
+                    if let Some(handle) = self.spawns.lock().remove(&SpawnId::Progress) {
+                        handle.abort();
+                    }
+                    if let Some(progress) = app_data.lock().get_mut_progress() {
+                        progress.finish(ProgressState::Cancelled, String::from("cancelled"));
+                    }
+                }
                 DockerMessage::Update => self.update_everything().await,
                 DockerMessage::Quit => {
+                    Self::close_files(&app_data, &docker).await;
                     self.spawns
                         .lock()
                         .values()
@@ -506,6 +2958,10 @@ impl DockerData {
                 is_running,
                 receiver: docker_rx,
                 spawns: Arc::new(Mutex::new(HashMap::new())),
+                #[cfg(unix)]
+                swarm: SwarmApi::new(DOCKER_SOCKET),
+                #[cfg(not(unix))]
+                swarm: SwarmApi::new(),
             };
             inner.initialise_container_data().await;
             inner.message_handler().await;
@@ -0,0 +1,156 @@
+use bollard::service::{Node, NodeSpecAvailabilityEnum, NodeSpecRoleEnum, Task};
+use hyper::{body::Bytes, Body, Method};
+#[cfg(unix)]
+use hyper::{Client, Request};
+#[cfg(unix)]
+use hyperlocal::{UnixClientExt, UnixConnector, Uri};
+
+use crate::app_data::node_state::NodeChange;
+
+/// Number of lines to get the first time the logs of a service are requested
+const LOG_TAIL: usize = 1000;
+
+/// Requests fail with this reason on platforms without unix sockets
+#[cfg(not(unix))]
+const UNSUPPORTED: &str = "swarm tasks and nodes are only available on unix";
+
+/// The swarm endpoints that bollard doesn't cover, requested directly over the docker socket
+/// This is given the same socket as the bollard client, so that both talk to the same daemon
+/// Only a unix socket is supported, so on other platforms every request fails
+#[derive(Debug, Clone)]
+pub struct SwarmApi {
+    #[cfg(unix)]
+    client: Client<UnixConnector>,
+    #[cfg(unix)]
+    socket: String,
+}
+
+impl SwarmApi {
+    #[cfg(unix)]
+    pub fn new(socket: &str) -> Self {
+        Self {
+            client: Client::unix(),
+            socket: socket.to_owned(),
+        }
+    }
+
+    #[cfg(not(unix))]
+    pub const fn new() -> Self {
+        Self {}
+    }
+
+    /// Send a request, if docker responds with an error status, return the message from the response
+    #[cfg(unix)]
+    async fn request(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<String>,
+    ) -> Result<Body, String> {
+        let request = Request::builder()
+            .method(method)
+            .uri(Uri::new(&self.socket, path))
+            .header("Content-Type", "application/json")
+            .body(body.map_or_else(Body::empty, Body::from))
+            .map_err(|e| e.to_string())?;
+        let response = self
+            .client
+            .request(request)
+            .await
+            .map_err(|e| e.to_string())?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response.into_body());
+        }
+        let bytes = hyper::body::to_bytes(response.into_body())
+            .await
+            .unwrap_or_default();
+        Err(serde_json::from_slice::<serde_json::Value>(&bytes)
+            .ok()
+            .and_then(|i| i.get("message")?.as_str().map(ToOwned::to_owned))
+            .unwrap_or_else(|| status.to_string()))
+    }
+
+    #[cfg(not(unix))]
+    #[allow(clippy::unused_async)]
+    async fn request(&self, _: Method, _: &str, _: Option<String>) -> Result<Body, String> {
+        Err(UNSUPPORTED.to_owned())
+    }
+
+    /// Get the whole response body of a GET request
+    async fn get(&self, path: &str) -> Result<Bytes, String> {
+        let body = self.request(Method::GET, path, None).await?;
+        hyper::body::to_bytes(body).await.map_err(|e| e.to_string())
+    }
+
+    /// All tasks, of all services, including those that have stopped
+    pub async fn list_tasks(&self) -> Result<Vec<Task>, String> {
+        serde_json::from_slice(&self.get("/tasks").await?).map_err(|e| e.to_string())
+    }
+
+    /// The logs of all tasks of a service since a unix timestamp, each line has the timestamp, then the task details, then the message
+    /// If `since` is 0, only the last LOG_TAIL lines are requested
+    pub async fn service_logs(&self, id: &str, since: u64) -> Result<Vec<String>, String> {
+        let tail = if since == 0 {
+            format!("&tail={LOG_TAIL}")
+        } else {
+            String::new()
+        };
+        let body = self
+            .get(&format!(
+                "/services/{id}/logs?stdout=1&stderr=1&timestamps=1&details=1&since={since}{tail}"
+            ))
+            .await?;
+        Ok(Self::demultiplex(&body)
+            .lines()
+            .filter(|i| !i.trim().is_empty())
+            .map(ToOwned::to_owned)
+            .collect())
+    }
+
+    /// Unless the service uses a tty, stdout and stderr are sent as frames, each with an 8 byte header, the last 4 bytes of which are the frame size
+    fn demultiplex(body: &[u8]) -> String {
+        let is_frame = |i: &[u8]| i.len() >= 8 && i[0] <= 2 && i[1..4] == [0, 0, 0];
+        if !is_frame(body) {
+            return String::from_utf8_lossy(body).to_string();
+        }
+        let mut output = vec![];
+        let mut rest = body;
+        while is_frame(rest) {
+            let size = u32::from_be_bytes([rest[4], rest[5], rest[6], rest[7]]) as usize;
+            let end = (8 + size).min(rest.len());
+            output.extend_from_slice(&rest[8..end]);
+            rest = &rest[end..];
+        }
+        String::from_utf8_lossy(&output).to_string()
+    }
+
+    /// All nodes of the swarm
+    pub async fn list_nodes(&self) -> Result<Vec<Node>, String> {
+        serde_json::from_slice(&self.get("/nodes").await?).map_err(|e| e.to_string())
+    }
+
+    /// Change the availability, or the role, of a node
+    /// The current version is sent with the update, so docker rejects it if the node was changed in the meantime
+    pub async fn update_node(&self, id: &str, change: NodeChange) -> Result<(), String> {
+        let node = serde_json::from_slice::<Node>(&self.get(&format!("/nodes/{id}")).await?)
+            .map_err(|e| e.to_string())?;
+        let version = node.version.and_then(|i| i.index).unwrap_or_default();
+        let mut spec = node.spec.unwrap_or_default();
+        match change {
+            NodeChange::Activate => spec.availability = Some(NodeSpecAvailabilityEnum::ACTIVE),
+            NodeChange::Pause => spec.availability = Some(NodeSpecAvailabilityEnum::PAUSE),
+            NodeChange::Drain => spec.availability = Some(NodeSpecAvailabilityEnum::DRAIN),
+            NodeChange::Promote => spec.role = Some(NodeSpecRoleEnum::MANAGER),
+            NodeChange::Demote => spec.role = Some(NodeSpecRoleEnum::WORKER),
+        }
+        let body = serde_json::to_string(&spec).map_err(|e| e.to_string())?;
+        self.request(
+            Method::POST,
+            &format!("/nodes/{id}/update?version={version}"),
+            Some(body),
+        )
+        .await
+        .map(|_| ())
+    }
+}
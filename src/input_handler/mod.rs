@@ -21,9 +21,11 @@ pub use message::InputMessages;
 
 use crate::{
     app_data::container_data::Header,
+    app_data::image_data::ImageHeader,
+    app_data::process_data::ProcessHeader,
     app_data::AppData,
     docker_data::DockerMessage,
-    ui::{Action, DeleteButton, GuiState, NavPanel, Status},
+    ui::{Action, DeleteButton, Form, GuiState, NavPanel, Status},
 };
 
 mod message;
@@ -71,6 +73,10 @@ impl InputHandler {
                         Status::Error,
                         Status::Help,
                         Status::DeleteConfirm,
+                        Status::Confirm,
+                        Status::Form,
+                        Status::Progress,
+                        Status::Export,
                     ]);
                     if !error_or_help {
                         self.mouse_press(mouse_event);
@@ -98,6 +104,61 @@ impl InputHandler {
             .set_sort_by_header(selected_header);
     }
 
+    /// Sort the images by a given header
+    fn sort_images(&self, selected_header: ImageHeader) {
+        self.app_data
+            .lock()
+            .image_data
+            .set_sort_by_header(selected_header);
+    }
+
+    /// Sort the processes by a given header
+    fn sort_processes(&self, selected_header: ProcessHeader) {
+        self.app_data
+            .lock()
+            .process_data
+            .set_sort_by_header(selected_header);
+    }
+
+    /// Sort the list in the current panel by the header of the given column number, 0 removes the sort
+    fn sort_by_column(&self, column: char) {
+        let current_panel = self.gui_state.lock().get_current_nav().clone();
+        match current_panel {
+            NavPanel::Containers => match column {
+                '0' => self.app_data.lock().container_data.reset_sorted(),
+                '1' => self.sort(Header::State),
+                '2' => self.sort(Header::Status),
+                '3' => self.sort(Header::Cpu),
+                '4' => self.sort(Header::Memory),
+                '5' => self.sort(Header::Id),
+                '6' => self.sort(Header::Name),
+                '7' => self.sort(Header::Image),
+                '8' => self.sort(Header::Rx),
+                '9' => self.sort(Header::Tx),
+                _ => (),
+            },
+            NavPanel::Processes => match column {
+                '0' => self.app_data.lock().process_data.reset_sorted(),
+                '1' => self.sort_processes(ProcessHeader::Pid),
+                '2' => self.sort_processes(ProcessHeader::User),
+                '3' => self.sort_processes(ProcessHeader::Cpu),
+                '4' => self.sort_processes(ProcessHeader::Memory),
+                '5' => self.sort_processes(ProcessHeader::Command),
+                _ => (),
+            },
+            NavPanel::Images => match column {
+                '0' => self.app_data.lock().image_data.reset_sorted(),
+                '1' => self.sort_images(ImageHeader::Name),
+                '2' => self.sort_images(ImageHeader::Id),
+                '3' => self.sort_images(ImageHeader::Size),
+                '4' => self.sort_images(ImageHeader::Created),
+                '5' => self.sort_images(ImageHeader::Containers),
+                _ => (),
+            },
+            _ => (),
+        }
+    }
+
     /// Send a quit message to docker, to abort all spawns, if an error is returned, set is_running to false here instead
     /// If gui_status is Error or Init, then just set the is_running to false immediately, for a quicker exit
     async fn quit(&self) {
@@ -127,6 +188,115 @@ impl InputHandler {
         self.gui_state.lock().set_delete_container(None);
     }
 
+    /// Handle key presses whilst a form is open, Enter submits the form, Esc closes it
+    async fn form_key(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Esc => self.gui_state.lock().set_form(None),
+            KeyCode::Enter => {
+                let message = self
+                    .gui_state
+                    .lock()
+                    .get_form()
+                    .and_then(|form| form.to_message());
+                if let Some(message) = message {
+                    self.gui_state.lock().set_form(None);
+                    self.docker_sender.send(message).await.ok();
+                }
+            }
+            KeyCode::Tab | KeyCode::Down => {
+                if let Some(form) = self.gui_state.lock().get_mut_form() {
+                    form.next_field();
+                }
+            }
+            KeyCode::BackTab | KeyCode::Up => {
+                if let Some(form) = self.gui_state.lock().get_mut_form() {
+                    form.previous_field();
+                }
+            }
+            KeyCode::Left | KeyCode::Right => {
+                if let Some(form) = self.gui_state.lock().get_mut_form() {
+                    form.cycle_option(key_code == KeyCode::Right);
+                }
+            }
+            KeyCode::Backspace => {
+                if let Some(form) = self.gui_state.lock().get_mut_form() {
+                    form.pop_char();
+                }
+            }
+            KeyCode::Char(c) => {
+                if let Some(form) = self.gui_state.lock().get_mut_form() {
+                    form.push_char(c);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Handle key presses whilst a confirm dialog is open, y sends the message of the dialog, n or Esc closes it
+    async fn confirm_key(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char('y' | 'Y') => {
+                let confirm = self.gui_state.lock().get_confirm();
+                self.gui_state.lock().set_confirm(None);
+                if let Some(confirm) = confirm {
+                    self.docker_sender.send(confirm.message).await.ok();
+                }
+            }
+            KeyCode::Char('n' | 'N') | KeyCode::Esc => self.gui_state.lock().set_confirm(None),
+            _ => (),
+        }
+    }
+
+    /// Handle key presses whilst the progress popup is open
+    /// If still running, Esc or c will cancel the operation, once finished, Esc, c, or Enter will close the popup
+    async fn progress_key(&self, key_code: KeyCode) {
+        let is_running = self
+            .app_data
+            .lock()
+            .get_progress()
+            .is_some_and(|progress| progress.is_running());
+        match key_code {
+            KeyCode::Esc | KeyCode::Char('c' | 'C') if is_running => {
+                self.docker_sender
+                    .send(DockerMessage::CancelProgress)
+                    .await
+                    .ok();
+            }
+            KeyCode::Esc | KeyCode::Char('c' | 'C') | KeyCode::Enter if !is_running => {
+                self.app_data.lock().set_progress(None);
+                self.gui_state.lock().status_del(Status::Progress);
+            }
+            _ => (),
+        }
+    }
+
+    /// Handle key presses whilst the export popup is open, s opens a form to save the export to a file, Esc or Enter closes it
+    fn export_key(&self, key_code: KeyCode) {
+        let mut app_data = self.app_data.lock();
+        let Some(export) = app_data.get_mut_export() else {
+            return;
+        };
+        match key_code {
+            KeyCode::Up => export.scroll_up(1),
+            KeyCode::Down => export.scroll_down(1),
+            KeyCode::PageUp => export.scroll_up(10),
+            KeyCode::PageDown => export.scroll_down(10),
+            KeyCode::Home => export.scroll_start(),
+            KeyCode::End => export.scroll_end(),
+            KeyCode::Char('s' | 'S') => {
+                let form = Form::save_export(&export.name);
+                drop(app_data);
+                self.gui_state.lock().set_form(Some(form));
+            }
+            KeyCode::Esc | KeyCode::Enter => {
+                app_data.set_export(None);
+                drop(app_data);
+                self.gui_state.lock().status_del(Status::Export);
+            }
+            _ => (),
+        }
+    }
+
     /// Handle any keyboard button events
     #[allow(clippy::too_many_lines)]
     async fn button_press(&mut self, key_code: KeyCode, key_modififer: KeyModifiers) {
@@ -137,11 +307,15 @@ impl InputHandler {
             .gui_state
             .lock()
             .status_contains(&[Status::DeleteConfirm]);
+        let contains_form = self.gui_state.lock().status_contains(&[Status::Form]);
+        let contains_progress = self.gui_state.lock().status_contains(&[Status::Progress]);
+        let contains_export = self.gui_state.lock().status_contains(&[Status::Export]);
+        let contains_confirm = self.gui_state.lock().status_contains(&[Status::Confirm]);
 
-        // Always just quit on Ctrl + c/C or q/Q
+        // Always just quit on Ctrl + c/C, or q/Q when not typing into a form
         let is_c = || key_code == KeyCode::Char('c') || key_code == KeyCode::Char('C');
         let is_q = || key_code == KeyCode::Char('q') || key_code == KeyCode::Char('Q');
-        if key_modififer == KeyModifiers::CONTROL && is_c() || is_q() {
+        if key_modififer == KeyModifiers::CONTROL && is_c() || is_q() && !contains_form {
             self.quit().await;
         }
 
@@ -157,6 +331,14 @@ impl InputHandler {
                 }
                 _ => (),
             }
+        } else if contains_form {
+            self.form_key(key_code).await;
+        } else if contains_confirm {
+            self.confirm_key(key_code).await;
+        } else if contains_progress {
+            self.progress_key(key_code).await;
+        } else if contains_export {
+            self.export_key(key_code);
         } else if contains_delete {
             match key_code {
                 KeyCode::Char('y' | 'Y') => self.confirm_delete().await,
@@ -176,6 +358,21 @@ impl InputHandler {
                         NavPanel::Logs => locked_data.container_data.log_start(),
                         NavPanel::Metrics => {}
                         NavPanel::Info => locked_data.container_data.info_start(),
+                        NavPanel::Images => locked_data.image_data.images_start(),
+                        NavPanel::ImageInfo => locked_data.image_data.info_start(),
+                        NavPanel::ImageHistory => locked_data.image_data.history_start(),
+                        NavPanel::BuildLog => locked_data.build_start(),
+                        NavPanel::Volumes => locked_data.volume_data.volumes_start(),
+                        NavPanel::Networks => locked_data.network_data.networks_start(),
+                        NavPanel::Services => locked_data.service_data.services_start(),
+                        NavPanel::ServiceTasks => locked_data.service_data.tasks_start(),
+                        NavPanel::ServiceLogs => locked_data.service_data.log_start(),
+                        NavPanel::Nodes => locked_data.node_data.nodes_start(),
+                        NavPanel::VolumeInfo => locked_data.volume_data.info_start(),
+                        NavPanel::Files => locked_data.files_start(),
+                        NavPanel::Changes => locked_data.changes_start(),
+                        NavPanel::Processes => locked_data.process_data.processes_start(),
+                        NavPanel::FileView => locked_data.viewer_start(),
                     }
                 }
                 KeyCode::End => {
@@ -185,6 +382,21 @@ impl InputHandler {
                         NavPanel::Logs => locked_data.container_data.log_end(),
                         NavPanel::Metrics => {}
                         NavPanel::Info => locked_data.container_data.info_end(),
+                        NavPanel::Images => locked_data.image_data.images_end(),
+                        NavPanel::ImageInfo => locked_data.image_data.info_end(),
+                        NavPanel::ImageHistory => locked_data.image_data.history_end(),
+                        NavPanel::BuildLog => locked_data.build_end(),
+                        NavPanel::Volumes => locked_data.volume_data.volumes_end(),
+                        NavPanel::Networks => locked_data.network_data.networks_end(),
+                        NavPanel::Services => locked_data.service_data.services_end(),
+                        NavPanel::ServiceTasks => locked_data.service_data.tasks_end(),
+                        NavPanel::ServiceLogs => locked_data.service_data.log_end(),
+                        NavPanel::Nodes => locked_data.node_data.nodes_end(),
+                        NavPanel::VolumeInfo => locked_data.volume_data.info_end(),
+                        NavPanel::Files => locked_data.files_end(),
+                        NavPanel::Changes => locked_data.changes_end(),
+                        NavPanel::Processes => locked_data.process_data.processes_end(),
+                        NavPanel::FileView => locked_data.viewer_end(),
                     }
                 }
                 KeyCode::Up => self.previous(),
@@ -199,6 +411,13 @@ impl InputHandler {
                         self.next();
                     }
                 }
+                KeyCode::Char(column @ '0'..='9') => self.sort_by_column(column),
+                KeyCode::Char('g') if current_panel == NavPanel::Containers => {
+                    self.app_data.lock().container_data.toggle_grouped();
+                }
+                KeyCode::Enter if current_panel == NavPanel::Containers => {
+                    self.app_data.lock().container_data.toggle_collapsed();
+                }
 
                 kc => {
                     let maybe_action = current_actions.iter().find(|a| a.key() == kc);
@@ -216,6 +435,13 @@ impl InputHandler {
                                 self.gui_state.lock().append_nav(next.clone());
                                 self.docker_sender.send(docker_message.clone()).await.ok();
                             }
+                            Action::BackAndDockerMessage(_, _, docker_message) => {
+                                self.gui_state.lock().back_in_nav();
+                                self.docker_sender.send(docker_message.clone()).await.ok();
+                            }
+                            Action::Form(_, _, form) => {
+                                self.gui_state.lock().set_form(Some(form.clone()));
+                            }
                         }
                     }
                 }
@@ -260,6 +486,21 @@ impl InputHandler {
             NavPanel::Logs => locked_data.container_data.log_next(),
             NavPanel::Info => locked_data.container_data.info_next(),
             NavPanel::Metrics => {}
+            NavPanel::Images => locked_data.image_data.images_next(),
+            NavPanel::ImageInfo => locked_data.image_data.info_next(),
+            NavPanel::ImageHistory => locked_data.image_data.history_next(),
+            NavPanel::BuildLog => locked_data.build_next(),
+            NavPanel::Volumes => locked_data.volume_data.volumes_next(),
+            NavPanel::Networks => locked_data.network_data.networks_next(),
+            NavPanel::Services => locked_data.service_data.services_next(),
+            NavPanel::ServiceTasks => locked_data.service_data.tasks_next(),
+            NavPanel::ServiceLogs => locked_data.service_data.log_next(),
+            NavPanel::Nodes => locked_data.node_data.nodes_next(),
+            NavPanel::VolumeInfo => locked_data.volume_data.info_next(),
+            NavPanel::Files => locked_data.files_next(),
+            NavPanel::Changes => locked_data.changes_next(),
+            NavPanel::Processes => locked_data.process_data.processes_next(),
+            NavPanel::FileView => locked_data.viewer_next(),
         };
     }
 
@@ -271,6 +512,21 @@ impl InputHandler {
             NavPanel::Logs => locked_data.container_data.log_previous(),
             NavPanel::Info => locked_data.container_data.info_previous(),
             NavPanel::Metrics => {}
+            NavPanel::Images => locked_data.image_data.images_previous(),
+            NavPanel::ImageInfo => locked_data.image_data.info_previous(),
+            NavPanel::ImageHistory => locked_data.image_data.history_previous(),
+            NavPanel::BuildLog => locked_data.build_previous(),
+            NavPanel::Volumes => locked_data.volume_data.volumes_previous(),
+            NavPanel::Networks => locked_data.network_data.networks_previous(),
+            NavPanel::Services => locked_data.service_data.services_previous(),
+            NavPanel::ServiceTasks => locked_data.service_data.tasks_previous(),
+            NavPanel::ServiceLogs => locked_data.service_data.log_previous(),
+            NavPanel::Nodes => locked_data.node_data.nodes_previous(),
+            NavPanel::VolumeInfo => locked_data.volume_data.info_previous(),
+            NavPanel::Files => locked_data.files_previous(),
+            NavPanel::Changes => locked_data.changes_previous(),
+            NavPanel::Processes => locked_data.process_data.processes_previous(),
+            NavPanel::FileView => locked_data.viewer_previous(),
         }
     }
 }
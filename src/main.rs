@@ -20,7 +20,6 @@ use std::sync::{
     Arc,
 };
 
-use bollard::Docker;
 use parking_lot::Mutex;
 use tokio::sync::mpsc::{Receiver, Sender};
 use tracing::{info, Level};
@@ -71,7 +70,7 @@ async fn docker_init(
     gui_state: &Arc<Mutex<GuiState>>,
     is_running: &Arc<AtomicBool>,
 ) {
-    if let Ok(docker) = Docker::connect_with_socket_defaults() {
+    if let Ok(docker) = DockerData::connect() {
         if docker.ping().await.is_ok() {
             let app_data = Arc::clone(app_data);
             let gui_state = Arc::clone(gui_state);
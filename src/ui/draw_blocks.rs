@@ -10,18 +10,32 @@ use ratatui::{
     text::{Line, Span},
     widgets::{
         Axis, Block, BorderType, Borders, Chart, Clear, Dataset, GraphType, List, ListItem,
-        Paragraph,
+        Paragraph, Wrap,
     },
     Frame,
 };
 
-use crate::app_data::container_state::{ByteStats, Columns, CpuStats, State};
+use crate::app_data::change_state::ChangeKind;
+use crate::app_data::container_data::SortedOrder;
+use crate::app_data::container_state::{
+    ByteStats, Columns, ContainerItem, ContainerRow, CpuStats, ProjectSummary, State,
+};
+use crate::app_data::export_state::ContainerExport;
+use crate::app_data::file_state::FileKind;
+use crate::app_data::image_state::ImageColumns;
+use crate::app_data::network_state::NetworkColumns;
+use crate::app_data::node_state::NodeColumns;
+use crate::app_data::process_state::ProcessColumns;
+use crate::app_data::progress_state::{Progress, ProgressState};
+use crate::app_data::service_state::{ServiceColumns, TaskItem};
+use crate::app_data::volume_data::VolumeHeader;
+use crate::app_data::volume_state::VolumeColumns;
 use crate::ui::gui_state::nav::NavPanel;
 use crate::ui::Status;
 use crate::{app_data::container_state::Stats, app_data::AppData, app_error::AppError};
 
 use super::gui_state::BoxLocation;
-use super::GuiState;
+use super::{Confirm, Form, GuiState};
 
 const LOGO: &str = r#"    .___.________
   __| _/|   ____/______
@@ -47,142 +61,1359 @@ fn max_line_width(text: &str) -> usize {
         .unwrap_or_default()
 }
 
-/// Generate block, add a border if is the selected panel,
-/// add custom title based on state of each panel
-fn generate_block<'a>(
-    app_data: &Arc<Mutex<AppData>>,
-    area: Rect,
-    gui_state: &Arc<Mutex<GuiState>>,
-) -> Block<'a> {
-    let nav_panel = gui_state.lock().get_current_nav().clone();
-    let mut title = match nav_panel {
-        NavPanel::Containers => {
-            format!(
-                "{} {}",
-                nav_panel.title(),
-                app_data.lock().container_data.container_title()
-            )
-        }
-        NavPanel::Logs => {
-            format!(
-                "{} {}",
-                nav_panel.title(),
-                app_data.lock().container_data.get_log_title()
-            )
-        }
-        _ => format!(
-            "{} ({})",
-            nav_panel.title(),
-            app_data
-                .lock()
-                .container_data
-                .get_selected_container_name()
-                .get_or_insert("".to_string())
-        ),
-    };
-    if !title.is_empty() {
-        title = format!(" {title} ");
+/// Generate block, add a border if is the selected panel,
+/// add custom title based on state of each panel
+fn generate_block<'a>(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    gui_state: &Arc<Mutex<GuiState>>,
+) -> Block<'a> {
+    let nav_panel = gui_state.lock().get_current_nav().clone();
+    let mut title = match nav_panel {
+        NavPanel::Containers => {
+            format!(
+                "{} {}",
+                nav_panel.title(),
+                app_data.lock().container_data.container_title()
+            )
+        }
+        NavPanel::Logs => {
+            format!(
+                "{} {}",
+                nav_panel.title(),
+                app_data.lock().container_data.get_log_title()
+            )
+        }
+        NavPanel::Images => {
+            format!(
+                "{} {}",
+                nav_panel.title(),
+                app_data.lock().image_data.image_title()
+            )
+        }
+        NavPanel::ImageInfo | NavPanel::ImageHistory => format!(
+            "{} ({})",
+            nav_panel.title(),
+            app_data
+                .lock()
+                .image_data
+                .get_selected_image_name()
+                .unwrap_or_default()
+        ),
+        NavPanel::Volumes => {
+            format!(
+                "{} {}",
+                nav_panel.title(),
+                app_data.lock().volume_data.volume_title()
+            )
+        }
+        NavPanel::Networks => {
+            format!(
+                "{} {}",
+                nav_panel.title(),
+                app_data.lock().network_data.network_title()
+            )
+        }
+        NavPanel::Services => {
+            format!(
+                "{} {}",
+                nav_panel.title(),
+                app_data.lock().service_data.service_title()
+            )
+        }
+        NavPanel::Nodes => {
+            format!(
+                "{} {}",
+                nav_panel.title(),
+                app_data.lock().node_data.node_title()
+            )
+        }
+        NavPanel::ServiceLogs => {
+            format!(
+                "{} {}",
+                nav_panel.title(),
+                app_data.lock().service_data.get_log_title()
+            )
+        }
+        NavPanel::ServiceTasks => format!(
+            "{} ({})",
+            nav_panel.title(),
+            app_data
+                .lock()
+                .service_data
+                .get_selected_service_name()
+                .unwrap_or_default()
+        ),
+        NavPanel::VolumeInfo => format!(
+            "{} ({})",
+            nav_panel.title(),
+            app_data
+                .lock()
+                .volume_data
+                .get_selected_volume_name()
+                .unwrap_or_default()
+        ),
+        NavPanel::Processes => {
+            format!(
+                "{} {}",
+                nav_panel.title(),
+                app_data.lock().process_data.process_title()
+            )
+        }
+        NavPanel::Changes => app_data
+            .lock()
+            .get_changes()
+            .map(|changes| format!("{} {}", nav_panel.title(), changes.get_title()))
+            .unwrap_or_default(),
+        NavPanel::Files => app_data
+            .lock()
+            .get_files()
+            .map(|files| format!("{} {}", nav_panel.title(), files.get_title()))
+            .unwrap_or_default(),
+        NavPanel::FileView => format!(
+            "{} ({})",
+            nav_panel.title(),
+            app_data
+                .lock()
+                .get_files()
+                .and_then(|files| files.viewing)
+                .unwrap_or_default()
+        ),
+        NavPanel::BuildLog => format!(
+            "{} ({})",
+            nav_panel.title(),
+            app_data
+                .lock()
+                .get_build()
+                .map(|build| build.title)
+                .unwrap_or_default()
+        ),
+        _ => format!(
+            "{} ({})",
+            nav_panel.title(),
+            app_data
+                .lock()
+                .container_data
+                .get_selected_container_name()
+                .get_or_insert("".to_string())
+        ),
+    };
+    if !title.is_empty() {
+        title = format!(" {title} ");
+    }
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title);
+    block
+}
+
+/// A single container line of the containers panel
+fn container_line(i: &ContainerItem, widths: &Columns) -> Line<'static> {
+    let state_style = Style::default().fg(i.state.get_color());
+    let blue = Style::default().fg(Color::Blue);
+
+    Line::from(vec![
+        Span::styled(
+            format!(
+                "{:<width$}",
+                i.state.to_string(),
+                width = widths.state.1.into()
+            ),
+            state_style,
+        ),
+        Span::styled(
+            format!(
+                "{MARGIN}{:>width$}",
+                i.status,
+                width = &widths.status.1.into()
+            ),
+            state_style,
+        ),
+        Span::styled(
+            format!(
+                "{}{:>width$}",
+                MARGIN,
+                i.cpu_stats.back().copied().unwrap_or_default(),
+                width = &widths.cpu.1.into()
+            ),
+            state_style,
+        ),
+        Span::styled(
+            format!(
+                "{MARGIN}{:>width_current$} / {:>width_limit$}",
+                i.mem_stats.back().copied().unwrap_or_default(),
+                i.mem_limit,
+                width_current = &widths.mem.1.into(),
+                width_limit = &widths.mem.2.into()
+            ),
+            state_style,
+        ),
+        Span::styled(
+            format!(
+                "{}{:>width$}",
+                MARGIN,
+                i.id.get().chars().take(8).collect::<String>(),
+                width = &widths.id.1.into()
+            ),
+            blue,
+        ),
+        Span::styled(
+            format!("{MARGIN}{:>width$}", i.name, width = widths.name.1.into()),
+            blue,
+        ),
+        Span::styled(
+            format!("{MARGIN}{:>width$}", i.image, width = widths.image.1.into()),
+            blue,
+        ),
+        Span::styled(
+            format!("{MARGIN}{:>width$}", i.rx, width = widths.net_rx.1.into()),
+            Style::default().fg(Color::Rgb(255, 233, 193)),
+        ),
+        Span::styled(
+            format!("{MARGIN}{:>width$}", i.tx, width = widths.net_tx.1.into()),
+            Style::default().fg(Color::Rgb(205, 140, 140)),
+        ),
+    ])
+}
+
+/// A compose project header line of the grouped containers panel, with the total cpu & memory of its containers
+fn project_line(project: &ProjectSummary) -> Line<'static> {
+    let arrow = if project.collapsed { "▶" } else { "▼" };
+    Line::from(vec![
+        Span::styled(
+            format!("{arrow} {}", project.name),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::from(format!("{MARGIN}{}", project.services_text())),
+        Span::from(format!("{MARGIN}{}", project.states_text())),
+        Span::styled(
+            format!("{MARGIN}cpu {}", project.cpu),
+            Style::default().fg(Color::Green),
+        ),
+        Span::styled(
+            format!("{MARGIN}mem {}", project.mem),
+            Style::default().fg(Color::Green),
+        ),
+    ])
+}
+
+/// Draw the containers panel, if grouped, each compose project has a header line, followed by its containers
+pub fn containers<B: Backend>(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    f: &mut Frame<'_, B>,
+    gui_state: &Arc<Mutex<GuiState>>,
+    widths: &Columns,
+) {
+    let block = generate_block(app_data, area, gui_state);
+
+    let (items, grouped) = {
+        let app_data = app_data.lock();
+        let containers = app_data.container_data.get_container_items();
+        let items = app_data
+            .container_data
+            .get_rows()
+            .iter()
+            .filter_map(|row| match row {
+                ContainerRow::Project(project) => Some(project_line(project)),
+                ContainerRow::Container(index) => {
+                    containers.get(*index).map(|i| container_line(i, widths))
+                }
+            })
+            .map(ListItem::new)
+            .collect::<Vec<_>>();
+        (items, app_data.container_data.is_grouped())
+    };
+
+    if items.is_empty() {
+        let paragraph = Paragraph::new("no containers running")
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+    } else {
+        let items = List::new(items)
+            .block(block)
+            .highlight_style(
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .bg(Color::Blue),
+            )
+            .highlight_symbol(CIRCLE);
+
+        let mut app_data = app_data.lock();
+        let state = if grouped {
+            app_data.container_data.get_group_state()
+        } else {
+            app_data.container_data.get_container_state()
+        };
+        f.render_stateful_widget(items, area, state);
+    }
+}
+
+pub fn infos<B: Backend>(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    f: &mut Frame<'_, B>,
+    gui_state: &Arc<Mutex<GuiState>>,
+) {
+    let block = || generate_block(app_data, area, gui_state);
+
+    let infos = app_data.lock().container_data.get_infos();
+
+    let items = List::new(infos)
+        .block(block())
+        .highlight_symbol(ARROW)
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD));
+
+    if let Some(i) = app_data.lock().container_data.get_info_state() {
+        f.render_stateful_widget(items, area, i);
+    }
+}
+
+/// Generate a line of column titles, first column left aligned, the rest right aligned, with an arrow to mark the sorted column
+fn heading_bar<T: Copy + Display + PartialEq>(
+    columns: &[(T, u8)],
+    sorted_by: Option<(T, SortedOrder)>,
+) -> Line<'static> {
+    let text = columns
+        .iter()
+        .enumerate()
+        .map(|(index, (header, width))| {
+            let suffix = match sorted_by {
+                Some((h, SortedOrder::Asc)) if h == *header => " ▲",
+                Some((h, SortedOrder::Desc)) if h == *header => " ▼",
+                _ => "",
+            };
+            let text = format!("{header}{suffix}");
+            let width = usize::from(*width);
+            if index == 0 {
+                format!("  {text:<width$}")
+            } else {
+                format!("{MARGIN}{text:>width$}")
+            }
+        })
+        .collect::<String>();
+    Line::from(Span::styled(
+        text,
+        Style::default().add_modifier(Modifier::BOLD),
+    ))
+}
+
+/// Draw the images panel, with a heading line showing the column titles, and the current sort order
+pub fn images<B: Backend>(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    f: &mut Frame<'_, B>,
+    gui_state: &Arc<Mutex<GuiState>>,
+    widths: &ImageColumns,
+) {
+    let block = generate_block(app_data, area, gui_state);
+    let sorted_by = app_data.lock().image_data.get_sorted();
+
+    let blue = Style::default().fg(Color::Blue);
+    let items = app_data
+        .lock()
+        .image_data
+        .get_image_items()
+        .iter()
+        .map(|i| {
+            let in_use = if i.containers > 0 {
+                Style::default().fg(Color::Green)
+            } else {
+                Style::default()
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<width$}", i.name(), width = widths.name.1.into()),
+                    in_use,
+                ),
+                Span::styled(
+                    format!(
+                        "{MARGIN}{:>width$}",
+                        i.id.short(),
+                        width = widths.id.1.into()
+                    ),
+                    blue,
+                ),
+                Span::styled(
+                    format!("{MARGIN}{:>width$}", i.size, width = widths.size.1.into()),
+                    Style::default().fg(Color::Rgb(255, 233, 193)),
+                ),
+                Span::styled(
+                    format!(
+                        "{MARGIN}{:>width$}",
+                        i.created,
+                        width = widths.created.1.into()
+                    ),
+                    blue,
+                ),
+                Span::styled(
+                    format!(
+                        "{MARGIN}{:>width$}",
+                        i.containers,
+                        width = widths.containers.1.into()
+                    ),
+                    in_use,
+                ),
+            ]))
+        })
+        .collect::<Vec<_>>();
+
+    if items.is_empty() {
+        let paragraph = Paragraph::new("no images found")
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+    } else {
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let split = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Max(1), Constraint::Min(1)].as_ref())
+            .split(inner);
+
+        let heading = heading_bar(
+            &[
+                widths.name,
+                widths.id,
+                widths.size,
+                widths.created,
+                widths.containers,
+            ],
+            sorted_by,
+        );
+        f.render_widget(Paragraph::new(heading), split[0]);
+
+        let items = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .bg(Color::Blue),
+            )
+            .highlight_symbol(CIRCLE);
+
+        f.render_stateful_widget(
+            items,
+            split[1],
+            app_data.lock().image_data.get_image_state(),
+        );
+    }
+}
+
+/// Draw the image infos panel
+pub fn image_infos<B: Backend>(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    f: &mut Frame<'_, B>,
+    gui_state: &Arc<Mutex<GuiState>>,
+) {
+    let block = generate_block(app_data, area, gui_state);
+
+    let infos = app_data.lock().image_data.get_infos();
+
+    let items = List::new(infos)
+        .block(block)
+        .highlight_symbol(ARROW)
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD));
+
+    if let Some(i) = app_data.lock().image_data.get_info_state() {
+        f.render_stateful_widget(items, area, i);
+    }
+}
+
+/// Draw the image history panel, each layer with a bar showing the cumulative size of the image up to, and including, that layer
+pub fn image_history<B: Backend>(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    f: &mut Frame<'_, B>,
+    gui_state: &Arc<Mutex<GuiState>>,
+) {
+    let block = generate_block(app_data, area, gui_state);
+    let history = app_data.lock().image_data.get_history();
+
+    if history.is_empty() {
+        let paragraph = Paragraph::new("no history found")
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let total = history
+        .first()
+        .map(|i| i.cumulative.get())
+        .unwrap_or_default();
+    let size_width = history
+        .iter()
+        .map(|i| i.size.to_string().chars().count())
+        .max()
+        .unwrap_or_default()
+        .max(4);
+    let created_width = history
+        .iter()
+        .map(|i| i.created.to_string().chars().count())
+        .max()
+        .unwrap_or_default()
+        .max(7);
+    let bar_width = 20;
+
+    let items = history
+        .iter()
+        .map(|i| {
+            let percent = (i.cumulative.get() * 100)
+                .checked_div(total)
+                .and_then(|i| u16::try_from(i).ok());
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:>size_width$}", i.size),
+                    Style::default().fg(Color::Rgb(255, 233, 193)),
+                ),
+                Span::styled(
+                    format!("{MARGIN}{}", progress_bar(percent, bar_width)),
+                    Style::default().fg(Color::Green),
+                ),
+                Span::styled(
+                    format!("{MARGIN}{:>created_width$}", i.created),
+                    Style::default().fg(Color::Blue),
+                ),
+                Span::from(format!("{MARGIN}{}", i.created_by)),
+            ]))
+        })
+        .collect::<Vec<_>>();
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    let split = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Max(1), Constraint::Min(1)].as_ref())
+        .split(inner);
+
+    let heading = format!(
+        "  {:>size_width$}{MARGIN}{:<width$}{MARGIN}{:>created_width$}{MARGIN}created by",
+        "size",
+        "cumulative",
+        "created",
+        width = bar_width + 6
+    );
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            heading,
+            Style::default().add_modifier(Modifier::BOLD),
+        ))),
+        split[0],
+    );
+
+    let items = List::new(items)
+        .highlight_symbol(ARROW)
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD));
+
+    if let Some(i) = app_data.lock().image_data.get_history_state() {
+        f.render_stateful_widget(items, split[1], i);
+    }
+}
+
+/// Draw the volumes panel, with a heading line showing the column titles
+pub fn volumes<B: Backend>(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    f: &mut Frame<'_, B>,
+    gui_state: &Arc<Mutex<GuiState>>,
+    widths: VolumeColumns,
+) {
+    let block = generate_block(app_data, area, gui_state);
+
+    let blue = Style::default().fg(Color::Blue);
+    let items = app_data
+        .lock()
+        .volume_data
+        .get_volume_items()
+        .iter()
+        .map(|i| {
+            let in_use = if i.containers.is_empty() {
+                Style::default()
+            } else {
+                Style::default().fg(Color::Green)
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<width$}", i.name, width = widths.name.1.into()),
+                    in_use,
+                ),
+                Span::styled(
+                    format!(
+                        "{MARGIN}{:>width$}",
+                        i.driver,
+                        width = widths.driver.1.into()
+                    ),
+                    blue,
+                ),
+                Span::from(format!(
+                    "{MARGIN}{:>width$}",
+                    i.mountpoint,
+                    width = widths.mountpoint.1.into()
+                )),
+                Span::styled(
+                    format!(
+                        "{MARGIN}{:>width$}",
+                        i.containers_text(),
+                        width = widths.containers.1.into()
+                    ),
+                    in_use,
+                ),
+                Span::styled(format!("{MARGIN}{}", i.labels_text()), blue),
+            ]))
+        })
+        .collect::<Vec<_>>();
+
+    if items.is_empty() {
+        let paragraph = Paragraph::new("no volumes found")
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+    } else {
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let split = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Max(1), Constraint::Min(1)].as_ref())
+            .split(inner);
+
+        let heading = heading_bar(
+            &[
+                widths.name,
+                widths.driver,
+                widths.mountpoint,
+                widths.containers,
+                (VolumeHeader::Labels, 6),
+            ],
+            None,
+        );
+        f.render_widget(Paragraph::new(heading), split[0]);
+
+        let items = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .bg(Color::Blue),
+            )
+            .highlight_symbol(CIRCLE);
+
+        f.render_stateful_widget(
+            items,
+            split[1],
+            app_data.lock().volume_data.get_volume_state(),
+        );
+    }
+}
+
+/// Draw the networks panel, with a heading line showing the column titles
+/// Beneath the networks, show the containers attached to the selected network, with their address and aliases
+pub fn networks<B: Backend>(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    f: &mut Frame<'_, B>,
+    gui_state: &Arc<Mutex<GuiState>>,
+    widths: NetworkColumns,
+) {
+    let block = generate_block(app_data, area, gui_state);
+
+    let blue = Style::default().fg(Color::Blue);
+    let items = app_data
+        .lock()
+        .network_data
+        .get_network_items()
+        .iter()
+        .map(|i| {
+            let in_use = if i.containers.is_empty() {
+                Style::default()
+            } else {
+                Style::default().fg(Color::Green)
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<width$}", i.name, width = widths.name.1.into()),
+                    in_use,
+                ),
+                Span::styled(
+                    format!(
+                        "{MARGIN}{:>width$}",
+                        i.driver,
+                        width = widths.driver.1.into()
+                    ),
+                    blue,
+                ),
+                Span::from(format!(
+                    "{MARGIN}{:>width$}",
+                    i.scope,
+                    width = widths.scope.1.into()
+                )),
+                Span::from(format!(
+                    "{MARGIN}{:>width$}",
+                    i.subnet,
+                    width = widths.subnet.1.into()
+                )),
+                Span::styled(
+                    format!(
+                        "{MARGIN}{:>width$}",
+                        i.containers.len(),
+                        width = widths.containers.1.into()
+                    ),
+                    in_use,
+                ),
+            ]))
+        })
+        .collect::<Vec<_>>();
+
+    if items.is_empty() {
+        let paragraph = Paragraph::new("no networks found")
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let split = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)].as_ref())
+        .split(area);
+
+    let inner = block.inner(split[0]);
+    f.render_widget(block, split[0]);
+    let network_split = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Max(1), Constraint::Min(1)].as_ref())
+        .split(inner);
+
+    let heading = heading_bar(
+        &[
+            widths.name,
+            widths.driver,
+            widths.scope,
+            widths.subnet,
+            widths.containers,
+        ],
+        None,
+    );
+    f.render_widget(Paragraph::new(heading), network_split[0]);
+
+    let items = List::new(items)
+        .highlight_style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .bg(Color::Blue),
+        )
+        .highlight_symbol(CIRCLE);
+
+    f.render_stateful_widget(
+        items,
+        network_split[1],
+        app_data.lock().network_data.get_network_state(),
+    );
+
+    network_containers(app_data, split[1], f);
+}
+
+/// Draw the swarm services panel, with a heading line showing the column titles
+/// If the services can't be listed, e.g. the daemon isn't a swarm manager, show the reason instead
+pub fn services<B: Backend>(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    f: &mut Frame<'_, B>,
+    gui_state: &Arc<Mutex<GuiState>>,
+    widths: ServiceColumns,
+) {
+    let block = generate_block(app_data, area, gui_state);
+
+    if let Some(reason) = app_data.lock().service_data.get_unavailable() {
+        let paragraph = Paragraph::new(reason)
+            .block(block)
+            .wrap(Wrap { trim: true })
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let blue = Style::default().fg(Color::Blue);
+    let items = app_data
+        .lock()
+        .service_data
+        .get_service_items()
+        .iter()
+        .map(|i| {
+            let replicas = i.replicas_text();
+            let healthy = if replicas.split_once('/').is_some_and(|(a, b)| a == b) {
+                Style::default().fg(Color::Green)
+            } else {
+                Style::default().fg(Color::Yellow)
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<width$}", i.name, width = widths.name.1.into()),
+                    healthy,
+                ),
+                Span::styled(
+                    format!("{MARGIN}{:>width$}", i.mode(), width = widths.mode.1.into()),
+                    blue,
+                ),
+                Span::styled(
+                    format!(
+                        "{MARGIN}{:>width$}",
+                        replicas,
+                        width = widths.replicas.1.into()
+                    ),
+                    healthy,
+                ),
+                Span::from(format!(
+                    "{MARGIN}{:>width$}",
+                    i.image,
+                    width = widths.image.1.into()
+                )),
+                Span::from(format!(
+                    "{MARGIN}{:>width$}",
+                    i.ports,
+                    width = widths.ports.1.into()
+                )),
+            ]))
+        })
+        .collect::<Vec<_>>();
+
+    if items.is_empty() {
+        let paragraph = Paragraph::new("no services found")
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    let split = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Max(1), Constraint::Min(1)].as_ref())
+        .split(inner);
+
+    let heading = heading_bar(
+        &[
+            widths.name,
+            widths.mode,
+            widths.replicas,
+            widths.image,
+            widths.ports,
+        ],
+        None,
+    );
+    f.render_widget(Paragraph::new(heading), split[0]);
+
+    let items = List::new(items)
+        .highlight_style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .bg(Color::Blue),
+        )
+        .highlight_symbol(CIRCLE);
+
+    f.render_stateful_widget(
+        items,
+        split[1],
+        app_data.lock().service_data.get_service_state(),
+    );
+}
+
+/// Draw the swarm nodes panel, a drained node is red, and a paused node yellow
+pub fn nodes<B: Backend>(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    f: &mut Frame<'_, B>,
+    gui_state: &Arc<Mutex<GuiState>>,
+    widths: NodeColumns,
+) {
+    let block = generate_block(app_data, area, gui_state);
+
+    if let Some(reason) = app_data.lock().node_data.get_unavailable() {
+        let paragraph = Paragraph::new(reason)
+            .block(block)
+            .wrap(Wrap { trim: true })
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let items = app_data
+        .lock()
+        .node_data
+        .get_node_items()
+        .iter()
+        .map(|i| {
+            let availability = match i.availability.as_str() {
+                "drain" => Style::default().fg(Color::Red),
+                "pause" => Style::default().fg(Color::Yellow),
+                _ => Style::default().fg(Color::Green),
+            };
+            let status = if i.status == "ready" {
+                Style::default().fg(Color::Green)
+            } else {
+                Style::default().fg(Color::Red)
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<width$}", i.hostname, width = widths.hostname.1.into()),
+                    status,
+                ),
+                Span::styled(
+                    format!("{MARGIN}{:>width$}", i.role, width = widths.role.1.into()),
+                    Style::default().fg(Color::Blue),
+                ),
+                Span::styled(
+                    format!(
+                        "{MARGIN}{:>width$}",
+                        i.availability,
+                        width = widths.availability.1.into()
+                    ),
+                    availability,
+                ),
+                Span::styled(
+                    format!(
+                        "{MARGIN}{:>width$}",
+                        i.status,
+                        width = widths.status.1.into()
+                    ),
+                    status,
+                ),
+                Span::from(format!(
+                    "{MARGIN}{:>width$}",
+                    i.engine,
+                    width = widths.engine.1.into()
+                )),
+                Span::from(format!(
+                    "{MARGIN}{:>width$}",
+                    i.labels,
+                    width = widths.labels.1.into()
+                )),
+            ]))
+        })
+        .collect::<Vec<_>>();
+
+    if items.is_empty() {
+        let paragraph = Paragraph::new("no nodes found")
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    let split = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Max(1), Constraint::Min(1)].as_ref())
+        .split(inner);
+
+    let heading = heading_bar(
+        &[
+            widths.hostname,
+            widths.role,
+            widths.availability,
+            widths.status,
+            widths.engine,
+            widths.labels,
+        ],
+        None,
+    );
+    f.render_widget(Paragraph::new(heading), split[0]);
+
+    let items = List::new(items)
+        .highlight_style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .bg(Color::Blue),
+        )
+        .highlight_symbol(CIRCLE);
+
+    f.render_stateful_widget(items, split[1], app_data.lock().node_data.get_node_state());
+}
+
+/// Draw the tasks of the selected service, with the node each task is on, its current and desired state, and any error
+/// If the tasks can't be listed, show the reason instead
+pub fn service_tasks<B: Backend>(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    f: &mut Frame<'_, B>,
+    gui_state: &Arc<Mutex<GuiState>>,
+) {
+    let block = generate_block(app_data, area, gui_state);
+
+    if let Some(reason) = app_data.lock().service_data.get_tasks_unavailable() {
+        let paragraph = Paragraph::new(reason)
+            .block(block)
+            .wrap(Wrap { trim: true })
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let tasks = app_data.lock().service_data.get_tasks();
+
+    if tasks.is_empty() {
+        let paragraph = Paragraph::new("no tasks found")
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let width = |f: fn(&TaskItem) -> &str, min: usize| {
+        tasks
+            .iter()
+            .map(|i| f(i).chars().count())
+            .max()
+            .unwrap_or_default()
+            .max(min)
+    };
+    let name_width = width(|i| &i.name, 4);
+    let node_width = width(|i| &i.node, 4);
+    let state_width = width(|i| &i.state, 5);
+    let desired_width = width(|i| &i.desired_state, 7);
+    let updated_width = width(|i| &i.updated, 7);
+
+    let items = tasks
+        .iter()
+        .map(|i| {
+            let state = match i.state.as_str() {
+                "running" => Style::default().fg(Color::Green),
+                "failed" | "rejected" | "orphaned" => Style::default().fg(Color::Red),
+                "complete" | "shutdown" | "remove" => Style::default().fg(Color::DarkGray),
+                _ => Style::default().fg(Color::Yellow),
+            };
+            ListItem::new(Line::from(vec![
+                Span::from(format!("{:<name_width$}", i.name)),
+                Span::styled(
+                    format!("{MARGIN}{:<node_width$}", i.node),
+                    Style::default().fg(Color::Blue),
+                ),
+                Span::styled(format!("{MARGIN}{:<state_width$}", i.state), state),
+                Span::from(format!("{MARGIN}{:<desired_width$}", i.desired_state)),
+                Span::styled(
+                    format!("{MARGIN}{:<updated_width$}", i.updated),
+                    Style::default().fg(Color::Rgb(255, 233, 193)),
+                ),
+                Span::styled(
+                    format!("{MARGIN}{}", i.error),
+                    Style::default().fg(Color::Red),
+                ),
+            ]))
+        })
+        .collect::<Vec<_>>();
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    let split = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Max(1), Constraint::Min(1)].as_ref())
+        .split(inner);
+
+    let heading = format!(
+        "  {:<name_width$}{MARGIN}{:<node_width$}{MARGIN}{:<state_width$}{MARGIN}{:<desired_width$}{MARGIN}{:<updated_width$}{MARGIN}error",
+        "name", "node", "state", "desired", "updated"
+    );
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            heading,
+            Style::default().add_modifier(Modifier::BOLD),
+        ))),
+        split[0],
+    );
+
+    let items = List::new(items)
+        .highlight_symbol(ARROW)
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD));
+
+    if let Some(i) = app_data.lock().service_data.get_task_state() {
+        f.render_stateful_widget(items, split[1], i);
+    }
+}
+
+/// Draw the containers attached to the selected network
+fn network_containers<B: Backend>(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    f: &mut Frame<'_, B>,
+) {
+    let (name, containers) = {
+        let app_data = app_data.lock();
+        (
+            app_data
+                .network_data
+                .get_selected_network_name()
+                .unwrap_or_default(),
+            app_data.network_data.get_selected_containers(),
+        )
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" Containers ({name}) "));
+
+    if containers.is_empty() {
+        let paragraph = Paragraph::new("no containers attached")
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let name_width = containers
+        .iter()
+        .map(|i| i.name.chars().count())
+        .max()
+        .unwrap_or_default()
+        .max(4);
+    let ip_width = containers
+        .iter()
+        .map(|i| i.ip.chars().count())
+        .max()
+        .unwrap_or_default()
+        .max(2);
+
+    let mut lines = vec![Line::from(Span::styled(
+        format!(
+            "  {:<name_width$}{MARGIN}{:<ip_width$}{MARGIN}aliases",
+            "name", "ip"
+        ),
+        Style::default().add_modifier(Modifier::BOLD),
+    ))];
+    lines.extend(containers.iter().map(|i| {
+        Line::from(vec![
+            Span::styled(
+                format!("  {:<name_width$}", i.name),
+                Style::default().fg(Color::Green),
+            ),
+            Span::styled(
+                format!("{MARGIN}{:<ip_width$}", i.ip),
+                Style::default().fg(Color::Blue),
+            ),
+            Span::from(format!("{MARGIN}{}", i.aliases.join(", "))),
+        ])
+    }));
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Draw the volume infos panel
+pub fn volume_infos<B: Backend>(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    f: &mut Frame<'_, B>,
+    gui_state: &Arc<Mutex<GuiState>>,
+) {
+    let block = generate_block(app_data, area, gui_state);
+
+    let infos = app_data.lock().volume_data.get_infos();
+
+    let items = List::new(infos)
+        .block(block)
+        .highlight_symbol(ARROW)
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD));
+
+    if let Some(i) = app_data.lock().volume_data.get_info_state() {
+        f.render_stateful_widget(items, area, i);
+    }
+}
+
+/// Draw the file browser panel, in a similar format to `ls -l`
+pub fn files<B: Backend>(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    f: &mut Frame<'_, B>,
+    gui_state: &Arc<Mutex<GuiState>>,
+) {
+    let block = generate_block(app_data, area, gui_state);
+    let entries = app_data
+        .lock()
+        .get_files()
+        .map(|i| i.entries.items)
+        .unwrap_or_default();
+
+    if entries.is_empty() {
+        let paragraph = Paragraph::new("no files found")
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let size_width = entries
+        .iter()
+        .map(|i| i.size.to_string().chars().count())
+        .max()
+        .unwrap_or_default()
+        .max(4);
+    let modified_width = entries
+        .iter()
+        .map(|i| i.modified.to_string().chars().count())
+        .max()
+        .unwrap_or_default()
+        .max(8);
+
+    let items = entries
+        .iter()
+        .map(|i| {
+            let name = match i.kind {
+                FileKind::Directory => Style::default().fg(Color::Blue),
+                FileKind::Symlink(_) => Style::default().fg(Color::Cyan),
+                FileKind::File | FileKind::Other => Style::default(),
+            };
+            ListItem::new(Line::from(vec![
+                Span::from(i.mode_text()),
+                Span::styled(
+                    format!("{MARGIN}{:>size_width$}", i.size),
+                    Style::default().fg(Color::Rgb(255, 233, 193)),
+                ),
+                Span::styled(
+                    format!("{MARGIN}{:>modified_width$}", i.modified),
+                    Style::default().fg(Color::Green),
+                ),
+                Span::styled(format!("{MARGIN}{i}"), name),
+            ]))
+        })
+        .collect::<Vec<_>>();
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    let split = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Max(1), Constraint::Min(1)].as_ref())
+        .split(inner);
+
+    let heading = format!(
+        "  {:<10}{MARGIN}{:>size_width$}{MARGIN}{:>modified_width$}{MARGIN}name",
+        "mode", "size", "modified",
+    );
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            heading,
+            Style::default().add_modifier(Modifier::BOLD),
+        ))),
+        split[0],
+    );
+
+    let items = List::new(items)
+        .highlight_symbol(ARROW)
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD));
+
+    if let Some(files) = app_data.lock().get_mut_files() {
+        f.render_stateful_widget(items, split[1], files.entries_state());
+    }
+}
+
+/// Draw the contents of the file being viewed
+pub fn file_view<B: Backend>(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    f: &mut Frame<'_, B>,
+    gui_state: &Arc<Mutex<GuiState>>,
+) {
+    let block = generate_block(app_data, area, gui_state);
+    let lines = app_data
+        .lock()
+        .get_files()
+        .map(|i| i.viewer.items)
+        .unwrap_or_default();
+
+    if lines.is_empty() {
+        let paragraph = Paragraph::new("empty file")
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let items = List::new(lines)
+        .block(block)
+        .highlight_symbol(ARROW)
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD));
+
+    if let Some(files) = app_data.lock().get_mut_files() {
+        f.render_stateful_widget(items, area, files.viewer_state());
     }
-    let mut block = Block::default()
-        .borders(Borders::ALL)
-        .border_type(BorderType::Rounded)
-        .title(title);
-    block
 }
 
-/// Draw the containers panel
-pub fn containers<B: Backend>(
+/// Draw the processes panel, with a heading line showing the column titles, and the current sort order
+pub fn processes<B: Backend>(
     app_data: &Arc<Mutex<AppData>>,
     area: Rect,
     f: &mut Frame<'_, B>,
     gui_state: &Arc<Mutex<GuiState>>,
-    widths: &Columns,
+    widths: ProcessColumns,
 ) {
     let block = generate_block(app_data, area, gui_state);
+    let sorted_by = app_data.lock().process_data.get_sorted();
 
     let items = app_data
         .lock()
-        .container_data
-        .get_container_items()
+        .process_data
+        .get_process_items()
         .iter()
         .map(|i| {
-            let state_style = Style::default().fg(i.state.get_color());
-            let blue = Style::default().fg(Color::Blue);
-
-            let lines = Line::from(vec![
-                Span::styled(
-                    format!(
-                        "{:<width$}",
-                        i.state.to_string(),
-                        width = widths.state.1.into()
-                    ),
-                    state_style,
-                ),
-                Span::styled(
-                    format!(
-                        "{MARGIN}{:>width$}",
-                        i.status,
-                        width = &widths.status.1.into()
-                    ),
-                    state_style,
-                ),
+            ListItem::new(Line::from(vec![
                 Span::styled(
-                    format!(
-                        "{}{:>width$}",
-                        MARGIN,
-                        i.cpu_stats.back().unwrap_or(&CpuStats::default()),
-                        width = &widths.cpu.1.into()
-                    ),
-                    state_style,
+                    format!("{:<width$}", i.pid, width = widths.pid.1.into()),
+                    Style::default().fg(Color::Blue),
                 ),
+                Span::from(format!(
+                    "{MARGIN}{:>width$}",
+                    i.user,
+                    width = widths.user.1.into()
+                )),
                 Span::styled(
                     format!(
-                        "{MARGIN}{:>width_current$} / {:>width_limit$}",
-                        i.mem_stats.back().unwrap_or(&ByteStats::default()),
-                        i.mem_limit,
-                        width_current = &widths.mem.1.into(),
-                        width_limit = &widths.mem.2.into()
+                        "{MARGIN}{:>width$.1}%",
+                        i.cpu,
+                        width = usize::from(widths.cpu.1) - 1
                     ),
-                    state_style,
+                    Style::default().fg(Color::Magenta),
                 ),
                 Span::styled(
                     format!(
-                        "{}{:>width$}",
-                        MARGIN,
-                        i.id.get().chars().take(8).collect::<String>(),
-                        width = &widths.id.1.into()
+                        "{MARGIN}{:>width$.1}%",
+                        i.memory,
+                        width = usize::from(widths.memory.1) - 1
                     ),
-                    blue,
-                ),
-                Span::styled(
-                    format!("{MARGIN}{:>width$}", i.name, width = widths.name.1.into()),
-                    blue,
-                ),
-                Span::styled(
-                    format!("{MARGIN}{:>width$}", i.image, width = widths.image.1.into()),
-                    blue,
-                ),
-                Span::styled(
-                    format!("{MARGIN}{:>width$}", i.rx, width = widths.net_rx.1.into()),
-                    Style::default().fg(Color::Rgb(255, 233, 193)),
-                ),
-                Span::styled(
-                    format!("{MARGIN}{:>width$}", i.tx, width = widths.net_tx.1.into()),
-                    Style::default().fg(Color::Rgb(205, 140, 140)),
+                    Style::default().fg(Color::Cyan),
                 ),
-            ]);
-            ListItem::new(lines)
+                Span::from(format!("{MARGIN}{}", i.command)),
+            ]))
         })
         .collect::<Vec<_>>();
 
     if items.is_empty() {
-        let paragraph = Paragraph::new("no containers running")
+        let paragraph = Paragraph::new("no processes found")
             .block(block)
             .alignment(Alignment::Center);
         f.render_widget(paragraph, area);
     } else {
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let split = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Max(1), Constraint::Min(1)].as_ref())
+            .split(inner);
+
+        let heading = heading_bar(
+            &[
+                widths.pid,
+                widths.user,
+                widths.cpu,
+                widths.memory,
+                (widths.command.0, 0),
+            ],
+            sorted_by,
+        );
+        f.render_widget(Paragraph::new(heading), split[0]);
+
         let items = List::new(items)
-            .block(block)
             .highlight_style(
                 Style::default()
                     .add_modifier(Modifier::BOLD)
@@ -192,29 +1423,59 @@ pub fn containers<B: Backend>(
 
         f.render_stateful_widget(
             items,
-            area,
-            app_data.lock().container_data.get_container_state(),
+            split[1],
+            app_data.lock().process_data.get_process_state(),
         );
     }
 }
 
-pub fn infos<B: Backend>(
+/// Draw the changes panel, each path colored by the kind of change, the same letters as `docker diff`
+pub fn changes<B: Backend>(
     app_data: &Arc<Mutex<AppData>>,
     area: Rect,
     f: &mut Frame<'_, B>,
     gui_state: &Arc<Mutex<GuiState>>,
 ) {
-    let block = || generate_block(app_data, area, gui_state);
+    let block = generate_block(app_data, area, gui_state);
+    let items = app_data
+        .lock()
+        .get_changes()
+        .map(|i| i.list.items)
+        .unwrap_or_default();
 
-    let infos = app_data.lock().container_data.get_infos();
+    if items.is_empty() {
+        let paragraph = Paragraph::new("no changes found")
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
 
-    let items = List::new(infos)
-        .block(block())
+    let items = items
+        .iter()
+        .map(|i| {
+            let color = match i.kind {
+                ChangeKind::Added => Color::Green,
+                ChangeKind::Modified => Color::Yellow,
+                ChangeKind::Deleted => Color::Red,
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    i.kind.to_string(),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(format!("{MARGIN}{}", i.path), Style::default().fg(color)),
+            ]))
+        })
+        .collect::<Vec<_>>();
+
+    let items = List::new(items)
+        .block(block)
         .highlight_symbol(ARROW)
         .highlight_style(Style::default().add_modifier(Modifier::BOLD));
 
-    if let Some(i) = app_data.lock().container_data.get_info_state() {
-        f.render_stateful_widget(items, area, i);
+    if let Some(changes) = app_data.lock().get_mut_changes() {
+        f.render_stateful_widget(items, area, changes.list_state());
     }
 }
 
@@ -255,6 +1516,76 @@ pub fn logs<B: Backend>(
     }
 }
 
+/// Draw the logs of all tasks of the selected service, scrolled the same as the container logs
+pub fn service_logs<B: Backend>(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    f: &mut Frame<'_, B>,
+    gui_state: &Arc<Mutex<GuiState>>,
+) {
+    let block = generate_block(app_data, area, gui_state);
+    let logs = app_data.lock().service_data.get_logs();
+
+    if logs.is_empty() {
+        let paragraph = Paragraph::new("no logs found")
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let items = List::new(logs)
+        .block(block)
+        .highlight_symbol(ARROW)
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD));
+
+    if let Some(i) = app_data.lock().service_data.get_log_state() {
+        f.render_stateful_widget(items, area, i);
+    }
+}
+
+/// Draw the build log panel, the same as the logs panel, with the build status on the last line
+pub fn build_log<B: Backend>(
+    app_data: &Arc<Mutex<AppData>>,
+    area: Rect,
+    f: &mut Frame<'_, B>,
+    gui_state: &Arc<Mutex<GuiState>>,
+) {
+    let block = generate_block(app_data, area, gui_state);
+    let Some(build) = app_data.lock().get_build() else {
+        let paragraph = Paragraph::new("no build found")
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    };
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    let split = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Max(1)].as_ref())
+        .split(inner);
+
+    let items = List::new(build.logs.to_vec())
+        .highlight_symbol(ARROW)
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD));
+    if let Some(build) = app_data.lock().get_mut_build() {
+        f.render_stateful_widget(items, split[0], build.logs.state());
+    }
+
+    let color = match build.state {
+        ProgressState::Running => Color::Blue,
+        ProgressState::Complete => Color::Green,
+        ProgressState::Cancelled | ProgressState::Failed => Color::Red,
+    };
+    let status = Paragraph::new(Line::from(Span::styled(
+        build.status,
+        Style::default().fg(color).add_modifier(Modifier::BOLD),
+    )));
+    f.render_widget(status, split[1]);
+}
+
 /// Draw the cpu + mem charts
 pub fn chart<B: Backend>(f: &mut Frame<'_, B>, area: Rect, app_data: &Arc<Mutex<AppData>>) {
     if let Some((cpu, mem)) = app_data.lock().container_data.get_chart_data() {
@@ -354,10 +1685,14 @@ pub fn top_menu<B: Backend>(
         .split(area);
 
     let loading = gui_state.lock().is_loading();
+    let loading_status = gui_state.lock().get_loading_status();
     let mut loading_text: String = String::from("");
     if loading {
-        loading_text = format!("{loading_icon:>2} Loading")
-    };
+        loading_text = format!(
+            "{loading_icon:>2} {}",
+            loading_status.unwrap_or_else(|| String::from("Loading"))
+        );
+    }
     // left part
     let left_lines = vec![
         Line::from(""),
@@ -400,8 +1735,8 @@ pub fn top_menu<B: Backend>(
     // --- column 1
     let mut actions_lines_0 = vec![Line::from("")];
     let current_nav = gui_state.lock().get_current_nav().clone();
-    let actions = current_nav.actions_0(gui_state, app_data);
-    actions.iter().for_each(|a| {
+    let columns = current_nav.actions(gui_state, app_data);
+    columns[0].iter().for_each(|a| {
         actions_lines_0.insert(
             actions_lines_0.len(),
             Line::from(Span::styled(a.label(), Style::default().fg(Color::White))),
@@ -419,9 +1754,7 @@ pub fn top_menu<B: Backend>(
 
     // --- column 2
     let mut actions_lines_1 = vec![Line::from("")];
-    let current_nav = gui_state.lock().get_current_nav().clone();
-    let actions = current_nav.actions_1(gui_state, app_data);
-    actions.iter().for_each(|a| {
+    columns[1].iter().for_each(|a| {
         actions_lines_1.insert(
             actions_lines_1.len(),
             Line::from(Span::styled(a.label(), Style::default().fg(Color::White))),
@@ -439,9 +1772,7 @@ pub fn top_menu<B: Backend>(
 
     // --- columns 3
     let mut actions_lines_2 = vec![Line::from("")];
-    let current_nav = gui_state.lock().get_current_nav().clone();
-    let actions = current_nav.actions_2(gui_state, app_data);
-    actions.iter().for_each(|a| {
+    columns[2].iter().for_each(|a| {
         actions_lines_2.insert(
             actions_lines_2.len(),
             Line::from(Span::styled(a.label(), Style::default().fg(Color::White))),
@@ -584,6 +1915,16 @@ impl HelpInfo {
                 button_item("h"),
                 button_desc("to toggle this help information"),
             ]),
+            Line::from(vec![
+                space(),
+                button_item("g"),
+                button_desc("to group containers by compose project"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("enter"),
+                button_desc("to expand or collapse a compose project"),
+            ]),
             Line::from(vec![space(), button_item("0"), button_desc("to stop sort")]),
             Line::from(vec![
                 space(),
@@ -817,6 +2158,252 @@ pub fn delete_confirm<B: Backend>(
     f.render_widget(yes_para, yes_area);
 }
 
+/// Draw a form popup in the centre of the screen, the selected field has a cursor at the end of its value
+pub fn form<B: Backend>(f: &mut Frame<'_, B>, form: &Form) {
+    let block = Block::default()
+        .title(form.title.as_str())
+        .border_type(BorderType::Rounded)
+        .style(Style::default().bg(Color::White).fg(Color::Black))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let label_width = form
+        .fields
+        .iter()
+        .map(|i| i.label.chars().count())
+        .max()
+        .unwrap_or_default();
+
+    let mut lines = vec![Line::from("")];
+    for (index, field) in form.fields.iter().enumerate() {
+        let selected = index == form.selected;
+        let label_style = if selected {
+            Style::default()
+                .fg(Color::Blue)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        let cursor = if selected { "█" } else { "" };
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {:>label_width$}: ", field.label), label_style),
+            Span::from(format!("{}{cursor}", field.value)),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " ( enter ) submit  ( tab ) next field  ( esc ) cancel ",
+        Style::default().add_modifier(Modifier::ITALIC),
+    )));
+
+    let max_line_width = lines
+        .iter()
+        .map(Line::width)
+        .max()
+        .unwrap_or_default()
+        .max(60)
+        + 4;
+    let area = popup(
+        lines.len() + 2,
+        max_line_width,
+        f.size(),
+        BoxLocation::MiddleCentre,
+    );
+
+    let paragraph = Paragraph::new(lines).block(block);
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+/// Draw a confirm dialog in the centre of the screen, long lists are truncated to fit the screen
+pub fn confirm<B: Backend>(f: &mut Frame<'_, B>, confirm: &Confirm) {
+    let block = Block::default()
+        .title(confirm.title.as_str())
+        .border_type(BorderType::Rounded)
+        .style(Style::default().bg(Color::White).fg(Color::Black))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let max_items = usize::from(f.size().height).saturating_sub(10).max(1);
+    let mut lines = vec![Line::from("")];
+    for (index, text) in confirm.lines.iter().enumerate() {
+        if index == max_items {
+            lines.push(Line::from(format!(
+                " ... and {} more ",
+                confirm.lines.len() - index
+            )));
+            break;
+        }
+        let style = if index == 0 {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Red)
+        };
+        lines.push(Line::from(Span::styled(format!(" {text} "), style)));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " ( y ) yes  ( n ) no ",
+        Style::default().add_modifier(Modifier::ITALIC),
+    )));
+
+    let max_line_width = lines
+        .iter()
+        .map(Line::width)
+        .max()
+        .unwrap_or_default()
+        .max(40)
+        + 4;
+    let area = popup(
+        lines.len() + 2,
+        max_line_width,
+        f.size(),
+        BoxLocation::MiddleCentre,
+    );
+
+    let paragraph = Paragraph::new(lines).block(block);
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+/// Create a text progress bar of a given width, empty if the percentage isn't known
+fn progress_bar(percent: Option<u16>, width: usize) -> String {
+    percent.map_or_else(
+        || " ".repeat(width + 6),
+        |percent| {
+            let done = usize::from(percent) * width / 100;
+            format!(
+                "{}{} {percent:>3}% ",
+                "█".repeat(done),
+                "░".repeat(width - done)
+            )
+        },
+    )
+}
+
+/// Draw the progress of a streamed docker operation, e.g. an image pull, with a progress bar for each layer
+pub fn progress<B: Backend>(f: &mut Frame<'_, B>, progress: &Progress) {
+    let (color, footer) = match progress.state {
+        ProgressState::Running => (Color::Blue, " ( esc ) cancel "),
+        ProgressState::Complete => (Color::Green, " ( esc ) close "),
+        ProgressState::Cancelled | ProgressState::Failed => (Color::Red, " ( esc ) close "),
+    };
+
+    let block = Block::default()
+        .title(format!(" {} ", progress.title))
+        .border_type(BorderType::Rounded)
+        .style(Style::default().bg(Color::White).fg(Color::Black))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let status_width = progress
+        .layers
+        .iter()
+        .map(|i| i.status.chars().count())
+        .max()
+        .unwrap_or_default();
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!(" {} ", progress.status),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    for layer in &progress.layers {
+        let bytes = if layer.total.get() > 0 {
+            format!("{} / {}", layer.current, layer.total)
+        } else {
+            String::new()
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(" {:<12} ", layer.id),
+                Style::default().fg(Color::Blue),
+            ),
+            Span::from(format!("{:<status_width$} ", layer.status)),
+            Span::styled(
+                progress_bar(layer.percent(), 20),
+                Style::default().fg(Color::Green),
+            ),
+            Span::from(bytes),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        footer,
+        Style::default().add_modifier(Modifier::ITALIC),
+    )));
+
+    let max_line_width = lines
+        .iter()
+        .map(Line::width)
+        .max()
+        .unwrap_or_default()
+        .max(60)
+        + 4;
+    // Leave a small margin, any layers that don't fit on screen are cut off
+    let max_height = usize::from(f.size().height.saturating_sub(4));
+    let height = (lines.len() + 2).min(max_height);
+    let area = popup(height, max_line_width, f.size(), BoxLocation::MiddleCentre);
+
+    let paragraph = Paragraph::new(lines).block(block);
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+/// Draw the export popup, the run command and compose service of a container, scrolled to the current offset
+pub fn export<B: Backend>(f: &mut Frame<'_, B>, export: &ContainerExport) {
+    let block = Block::default()
+        .title(format!(" Export {} ", export.name))
+        .border_type(BorderType::Rounded)
+        .style(Style::default().bg(Color::White).fg(Color::Black))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let text = export.lines();
+    let max_line_width = text
+        .iter()
+        .map(|i| i.chars().count())
+        .max()
+        .unwrap_or_default()
+        .max(60)
+        + 4;
+    // Leave a small margin, and room for the borders, a blank line, and the footer
+    let height = usize::from(f.size().height.saturating_sub(4)).min(text.len() + 4);
+    let visible = height.saturating_sub(4).max(1);
+    let offset = export.offset().min(text.len().saturating_sub(visible));
+
+    let mut lines = text
+        .iter()
+        .skip(offset)
+        .take(visible)
+        .map(|i| {
+            if i.starts_with('#') {
+                Line::from(Span::styled(
+                    format!(" {i}"),
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(format!(" {i}"))
+            }
+        })
+        .collect::<Vec<_>>();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " ( ↑ ↓ ) scroll  ( s ) save  ( esc ) close ",
+        Style::default().add_modifier(Modifier::ITALIC),
+    )));
+
+    let area = popup(height, max_line_width, f.size(), BoxLocation::MiddleCentre);
+    let paragraph = Paragraph::new(lines).block(block);
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
 /// Draw an error popup over whole screen
 pub fn error<B: Backend>(f: &mut Frame<'_, B>, error: AppError, seconds: Option<u8>) {
     let block = Block::default()
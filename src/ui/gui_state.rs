@@ -4,12 +4,16 @@ use std::{
     fmt,
 };
 
+use confirm::Confirm;
+use form::Form;
 use nav::NavPanel;
 use ratatui::layout::{Constraint, Rect};
 use uuid::Uuid;
 
 use crate::app_data::container_state::ContainerId;
 
+pub mod confirm;
+pub mod form;
 pub mod nav;
 
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
@@ -171,7 +175,11 @@ pub enum Status {
     Help,
     DockerConnect,
     DeleteConfirm,
+    Confirm,
     Error,
+    Export,
+    Form,
+    Progress,
     Shell,
 }
 
@@ -180,9 +188,12 @@ pub enum Status {
 pub struct GuiState {
     is_loading: HashSet<Uuid>,
     loading_icon: Loading,
+    loading_status: Option<String>,
     delete_map: HashMap<DeleteButton, Rect>,
     status: HashSet<Status>,
     delete_container: Option<ContainerId>,
+    form: Option<Form>,
+    confirm: Option<Confirm>,
     pub info_box_text: Option<String>,
     pub nav: Vec<NavPanel>,
 }
@@ -233,6 +244,43 @@ impl GuiState {
         self.delete_container = id;
     }
 
+    /// Get a clone of the open form, if any, for drawing
+    pub fn get_form(&self) -> Option<Form> {
+        self.form.clone()
+    }
+
+    /// Get a mutable reference to the open form, to handle key presses
+    pub const fn get_mut_form(&mut self) -> Option<&mut Form> {
+        self.form.as_mut()
+    }
+
+    /// Open, or close, a form
+    /// If Some, will also insert the Form status into self.status
+    pub fn set_form(&mut self, form: Option<Form>) {
+        if form.is_some() {
+            self.status.insert(Status::Form);
+        } else {
+            self.status.remove(&Status::Form);
+        }
+        self.form = form;
+    }
+
+    /// Get a clone of the open confirm dialog, if any
+    pub fn get_confirm(&self) -> Option<Confirm> {
+        self.confirm.clone()
+    }
+
+    /// Open, or close, a confirm dialog
+    /// If Some, will also insert the Confirm status into self.status
+    pub fn set_confirm(&mut self, confirm: Option<Confirm>) {
+        if confirm.is_some() {
+            self.status.insert(Status::Confirm);
+        } else {
+            self.status.remove(&Status::Confirm);
+        }
+        self.confirm = confirm;
+    }
+
     /// Check if the current gui_status contains any of the given status'
     /// Don't really like this methodology for gui state, needs a re-think
     pub fn status_contains(&self, status: &[Status]) -> bool {
@@ -271,6 +319,16 @@ impl GuiState {
         return !self.is_loading.is_empty();
     }
 
+    /// Text to show next to the loading icon, instead of the generic "Loading", for operations that can report their progress
+    pub fn get_loading_status(&self) -> Option<String> {
+        self.loading_status.clone()
+    }
+
+    /// Set, or remove, the loading status text
+    pub fn set_loading_status(&mut self, status: Option<String>) {
+        self.loading_status = status;
+    }
+
     /// Remove a loading_uuid from the is_loading HashSet
     pub fn remove_loading(&mut self, uuid: Uuid) {
         self.is_loading.remove(&uuid);
@@ -0,0 +1,19 @@
+use crate::docker_data::DockerMessage;
+
+/// A yes/no dialog, listing what will be affected, the message is only sent to docker if confirmed
+#[derive(Debug, Clone)]
+pub struct Confirm {
+    pub title: String,
+    pub lines: Vec<String>,
+    pub message: DockerMessage,
+}
+
+impl Confirm {
+    pub const fn new(title: String, lines: Vec<String>, message: DockerMessage) -> Self {
+        Self {
+            title,
+            lines,
+            message,
+        }
+    }
+}
@@ -0,0 +1,630 @@
+use crate::{
+    app_data::{
+        change_state::ChangeFilter,
+        container_state::{
+            join_command, ComposeAction, ContainerCommit, ContainerId, ContainerRun, Signal,
+            SIGNALS,
+        },
+        export_state::ExportFormat,
+        image_state::{split_repo_tag, ImageBuild, ImageId, ImagePrune},
+        network_state::{NetworkConnect, NetworkCreate},
+        process_state::ProcessTarget,
+    },
+    docker_data::DockerMessage,
+};
+
+/// The restart policies of a container, as used by `docker run --restart`
+const RESTART_POLICIES: &[&str] = &["no", "always", "unless-stopped", "on-failure"];
+
+/// The restart policy, with the maximum retry count, if any, appended when it's `on-failure`, e.g. `on-failure:3`
+/// Returns None if the retry count isn't a number
+fn restart_policy(restart: &str, max_retries: &str) -> Option<String> {
+    if restart != "on-failure" || max_retries.is_empty() {
+        return Some(restart.to_owned());
+    }
+    max_retries
+        .parse::<u32>()
+        .ok()
+        .map(|count| format!("{restart}:{count}"))
+}
+
+/// What a form is for, used to convert the submitted values into a DockerMessage
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum FormKind {
+    PullImage,
+    PruneImages,
+    TagImage(ImageId),
+    UntagImage,
+    PushImage,
+    BuildImage,
+    SaveImages,
+    LoadImages,
+    SaveFiles(String, bool),
+    GoToPath,
+    FilterChanges,
+    SignalProcess(ContainerId, ProcessTarget),
+    KillContainer(ContainerId),
+    DownloadFiles(ContainerId),
+    UploadFiles(ContainerId),
+    ConnectNetwork,
+    DisconnectNetwork,
+    CreateNetwork,
+    Compose,
+    ScaleService(String),
+    RunContainer,
+    CommitContainer(ContainerId),
+    RecreateContainer(ContainerId),
+    SaveExport(String),
+}
+
+/// A single labelled text input, or, if options are given, a choice between those options
+#[derive(Debug, Clone)]
+pub struct FormField {
+    pub label: &'static str,
+    pub value: String,
+    pub options: &'static [&'static str],
+}
+
+/// A popup of text inputs, only one form can be open at a time, and while open, it receives all key presses
+#[derive(Debug, Clone)]
+pub struct Form {
+    pub kind: FormKind,
+    pub title: String,
+    pub fields: Vec<FormField>,
+    pub selected: usize,
+}
+
+impl Form {
+    /// Create a form, each field with an initial value
+    pub fn new(kind: FormKind, title: &str, fields: &[(&'static str, String)]) -> Self {
+        Self {
+            kind,
+            title: title.to_owned(),
+            fields: fields
+                .iter()
+                .map(|(label, value)| FormField {
+                    label,
+                    value: value.clone(),
+                    options: &[],
+                })
+                .collect(),
+            selected: 0,
+        }
+    }
+
+    /// Form to pull an image from a registry
+    pub fn pull_image() -> Self {
+        Self::new(
+            FormKind::PullImage,
+            " Pull image ",
+            &[("image", String::new())],
+        )
+    }
+
+    /// Form to preview, and then prune, unused images
+    pub fn prune_images() -> Self {
+        Self::new(
+            FormKind::PruneImages,
+            " Prune images ",
+            &[
+                ("remove", String::new()),
+                ("until", String::new()),
+                ("label", String::new()),
+            ],
+        )
+        .choice("remove", &["dangling", "unused"])
+    }
+
+    /// Form to add a new repo:tag to an image
+    pub fn tag_image(id: ImageId, name: &str) -> Self {
+        // Dangling images have no repo to suggest
+        let value = if name.starts_with("<none>") {
+            String::new()
+        } else {
+            format!("{}:", split_repo_tag(name).0)
+        };
+        Self::new(FormKind::TagImage(id), " Tag image ", &[("tag", value)])
+    }
+
+    /// Form to remove a repo:tag, prefilled with the displayed name of the image
+    pub fn untag_image(name: &str) -> Self {
+        Self::new(
+            FormKind::UntagImage,
+            " Untag image ",
+            &[("tag", name.to_owned())],
+        )
+    }
+
+    /// Form to push a repo:tag to its registry, prefilled with the displayed name of the image
+    pub fn push_image(name: &str) -> Self {
+        Self::new(
+            FormKind::PushImage,
+            " Push image ",
+            &[("tag", name.to_owned())],
+        )
+    }
+
+    /// Form to build an image from a context directory, build args are whitespace separated `KEY=VALUE` pairs
+    pub fn build_image() -> Self {
+        Self::new(
+            FormKind::BuildImage,
+            " Build image ",
+            &[
+                ("context", String::from(".")),
+                ("dockerfile", String::from("Dockerfile")),
+                ("tag", String::new()),
+                ("build args", String::new()),
+            ],
+        )
+    }
+
+    /// Form to save images to a tar file, multiple images are whitespace separated
+    pub fn save_images(name: &str) -> Self {
+        let file = format!(
+            "{}.tar",
+            split_repo_tag(name)
+                .0
+                .rsplit('/')
+                .next()
+                .unwrap_or("images")
+                .replace(['<', '>'], "")
+        );
+        Self::new(
+            FormKind::SaveImages,
+            " Save images ",
+            &[("images", name.to_owned()), ("file", file)],
+        )
+    }
+
+    /// Form to load images from a tar file, as created by `docker save`
+    pub fn load_images() -> Self {
+        Self::new(
+            FormKind::LoadImages,
+            " Load images ",
+            &[("file", String::new())],
+        )
+    }
+
+    /// Form to save a file, or a directory as a tarball, from the file browser to the local filesystem
+    pub fn save_files(path: &str, directory: bool) -> Self {
+        let name = path.rsplit('/').next().unwrap_or_default();
+        let name = match (name.is_empty(), directory) {
+            (true, _) => String::from("root.tar"),
+            (false, true) => format!("{name}.tar"),
+            (false, false) => name.to_owned(),
+        };
+        Self::new(
+            FormKind::SaveFiles(path.to_owned(), directory),
+            " Save ",
+            &[("save to", name)],
+        )
+    }
+
+    /// Form to open a directory, by its absolute path, in the file browser
+    pub fn go_to_path(path: &str) -> Self {
+        Self::new(FormKind::GoToPath, " Go to ", &[("path", path.to_owned())])
+    }
+
+    /// Form to filter the changes of a container by kind, and by text in the path
+    pub fn filter_changes(filter: &ChangeFilter) -> Self {
+        Self::new(
+            FormKind::FilterChanges,
+            " Filter changes ",
+            &[("kind", String::new()), ("path", filter.path.clone())],
+        )
+        .choice("kind", &["all", "added", "changed", "deleted"])
+        .select_option("kind", filter.kind_text())
+    }
+
+    /// Form to kill a container with a chosen signal, a custom signal number is used instead of the chosen signal
+    pub fn kill_container(id: ContainerId, name: &str) -> Self {
+        Self::new(
+            FormKind::KillContainer(id),
+            &format!(" Kill {name} "),
+            &[("signal", String::new()), ("number", String::new())],
+        )
+        .choice("signal", SIGNALS)
+    }
+
+    /// Form to send a signal to a process in a container, a custom signal number is used instead of the chosen signal
+    pub fn signal_process(id: ContainerId, target: ProcessTarget) -> Self {
+        let title = format!(" Signal process {} ", target.pid);
+        Self::new(
+            FormKind::SignalProcess(id, target),
+            &title,
+            &[("signal", String::new()), ("number", String::new())],
+        )
+        .choice("signal", SIGNALS)
+    }
+
+    /// Form to download a path from a container, extracting it into a local directory, the current directory by default
+    pub fn download_files(id: ContainerId) -> Self {
+        Self::new(
+            FormKind::DownloadFiles(id),
+            " Download from container ",
+            &[
+                ("container path", String::new()),
+                ("local directory", String::from(".")),
+            ],
+        )
+    }
+
+    /// Form to upload a local file, or directory, into a directory of a container
+    pub fn upload_files(id: ContainerId) -> Self {
+        Self::new(
+            FormKind::UploadFiles(id),
+            " Upload to container ",
+            &[
+                ("local path", String::new()),
+                ("container directory", String::from("/")),
+            ],
+        )
+    }
+
+    /// Form to connect a container to a network, alias and ip are optional
+    pub fn connect_network(container: &str, network: &str) -> Self {
+        Self::new(
+            FormKind::ConnectNetwork,
+            " Connect network ",
+            &[
+                ("container", container.to_owned()),
+                ("network", network.to_owned()),
+                ("alias", String::new()),
+                ("ip", String::new()),
+            ],
+        )
+    }
+
+    /// Form to disconnect a container from a network
+    pub fn disconnect_network(container: &str, network: &str) -> Self {
+        Self::new(
+            FormKind::DisconnectNetwork,
+            " Disconnect network ",
+            &[
+                ("container", container.to_owned()),
+                ("network", network.to_owned()),
+            ],
+        )
+    }
+
+    /// Form to create a network, the subnet is optional
+    pub fn create_network() -> Self {
+        Self::new(
+            FormKind::CreateNetwork,
+            " Create network ",
+            &[
+                ("name", String::new()),
+                ("driver", String::new()),
+                ("subnet", String::new()),
+            ],
+        )
+        .choice("driver", &["bridge", "overlay", "macvlan", "ipvlan"])
+    }
+
+    /// Form to run a compose file, the service is only needed to recreate a single service
+    pub fn compose(file: &str) -> Self {
+        Self::new(
+            FormKind::Compose,
+            " Compose ",
+            &[
+                ("file", file.to_owned()),
+                ("action", String::new()),
+                ("service", String::new()),
+            ],
+        )
+        .choice("action", &["up", "down", "recreate"])
+    }
+
+    /// Form to set the number of replicas of a service
+    pub fn scale_service(name: &str, replicas: u64) -> Self {
+        Self::new(
+            FormKind::ScaleService(name.to_owned()),
+            " Scale service ",
+            &[("replicas", replicas.to_string())],
+        )
+    }
+
+    /// Form to create, and start, a new container from an image
+    /// Env, ports, and volumes are whitespace separated lists, as they would be given to `docker run`
+    pub fn run_container(image: &str) -> Self {
+        Self::new(
+            FormKind::RunContainer,
+            " Run container ",
+            &[
+                ("image", image.to_owned()),
+                ("name", String::new()),
+                ("command", String::new()),
+                ("env", String::new()),
+                ("ports", String::new()),
+                ("volumes", String::new()),
+                ("network", String::new()),
+                ("restart", String::new()),
+                ("memory", String::new()),
+                ("cpus", String::new()),
+            ],
+        )
+        .choice("restart", RESTART_POLICIES)
+    }
+
+    /// Form to commit a container to a new image, prefilled with the current config of the container
+    pub fn commit_container(id: ContainerId, commit: &ContainerCommit) -> Self {
+        let mut labels = commit
+            .labels
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>();
+        labels.sort();
+        Self::new(
+            FormKind::CommitContainer(id),
+            " Commit container ",
+            &[
+                ("tag", commit.tag.clone()),
+                ("cmd", join_command(&commit.cmd)),
+                ("entrypoint", join_command(&commit.entrypoint)),
+                ("env", join_command(&commit.env)),
+                ("labels", join_command(&labels)),
+                ("ports", commit.exposed_ports.join(" ")),
+                ("recreate", String::new()),
+            ],
+        )
+        .choice("recreate", &["no", "yes"])
+    }
+
+    /// Form to replace a container with a copy, with an edited config, prefilled with the current config of the container
+    /// The maximum retry count of an `on-failure` restart policy is its own field, so the policy is unchanged if the form isn't edited
+    pub fn recreate_container(id: ContainerId, name: &str, run: &ContainerRun) -> Self {
+        let (restart, max_retries) = run
+            .restart
+            .split_once(':')
+            .unwrap_or((run.restart.as_str(), ""));
+        Self::new(
+            FormKind::RecreateContainer(id),
+            &format!(" Recreate {name} "),
+            &[
+                ("image", run.image.clone()),
+                ("env", join_command(&run.env)),
+                ("ports", run.ports_text()),
+                ("volumes", run.volumes.join(" ")),
+                ("restart", String::new()),
+                ("max retries", max_retries.to_owned()),
+                ("memory", run.memory_text()),
+                ("cpus", run.cpus_text()),
+            ],
+        )
+        .choice("restart", RESTART_POLICIES)
+        .select_option("restart", restart)
+    }
+
+    /// Form to save the compose service, or the run command, of an exported container, the file is named after the container if left empty
+    pub fn save_export(name: &str) -> Self {
+        Self::new(
+            FormKind::SaveExport(name.to_owned()),
+            " Save export ",
+            &[("format", String::new()), ("file", String::new())],
+        )
+        .choice("format", &["compose", "run"])
+    }
+
+    /// Turn a field into a choice between the given options, selecting the first option
+    fn choice(mut self, label: &str, options: &'static [&'static str]) -> Self {
+        if let Some(field) = self.fields.iter_mut().find(|i| i.label == label) {
+            field.options = options;
+            field.value = options
+                .first()
+                .map_or_else(String::new, |i| (*i).to_owned());
+        }
+        self
+    }
+
+    /// Select an option of a choice field, if the value isn't one of the options, the first option stays selected
+    fn select_option(mut self, label: &str, value: &str) -> Self {
+        if let Some(field) = self.fields.iter_mut().find(|i| i.label == label) {
+            if field.options.contains(&value) {
+                value.clone_into(&mut field.value);
+            }
+        }
+        self
+    }
+
+    /// Cycle the selected choice field to the next, or previous, option
+    pub fn cycle_option(&mut self, forward: bool) {
+        if let Some(field) = self.fields.get_mut(self.selected) {
+            let len = field.options.len();
+            if len > 0 {
+                let current = field
+                    .options
+                    .iter()
+                    .position(|i| *i == field.value)
+                    .unwrap_or_default();
+                let next = if forward {
+                    (current + 1) % len
+                } else {
+                    current.checked_sub(1).unwrap_or(len - 1)
+                };
+                field.value = field.options[next].to_owned();
+            }
+        }
+    }
+
+    /// Select the next field, wraps around to the first
+    pub const fn next_field(&mut self) {
+        if !self.fields.is_empty() {
+            self.selected = (self.selected + 1) % self.fields.len();
+        }
+    }
+
+    /// Select the previous field, wraps around to the last
+    pub fn previous_field(&mut self) {
+        if !self.fields.is_empty() {
+            self.selected = self
+                .selected
+                .checked_sub(1)
+                .unwrap_or(self.fields.len() - 1);
+        }
+    }
+
+    /// Add a char to the end of the selected field, choice fields can't be typed into
+    pub fn push_char(&mut self, c: char) {
+        if let Some(field) = self.fields.get_mut(self.selected) {
+            if field.options.is_empty() {
+                field.value.push(c);
+            }
+        }
+    }
+
+    /// Remove the last char of the selected field
+    pub fn pop_char(&mut self) {
+        if let Some(field) = self.fields.get_mut(self.selected) {
+            if field.options.is_empty() {
+                field.value.pop();
+            }
+        }
+    }
+
+    /// Get the trimmed value of a field by its label, empty if field doesn't exist
+    pub fn value(&self, label: &str) -> String {
+        self.fields
+            .iter()
+            .find(|i| i.label == label)
+            .map_or_else(String::new, |i| i.value.trim().to_owned())
+    }
+
+    /// Convert the form values into the DockerMessage to send, None if a required value is missing
+    #[allow(clippy::too_many_lines)]
+    pub fn to_message(&self) -> Option<DockerMessage> {
+        let required = |label: &str| Some(self.value(label)).filter(|i| !i.is_empty());
+        match &self.kind {
+            FormKind::PullImage => required("image").map(DockerMessage::PullImage),
+            FormKind::TagImage(id) => {
+                required("tag").map(|tag| DockerMessage::TagImage(id.clone(), tag))
+            }
+            FormKind::UntagImage => required("tag").map(DockerMessage::UntagImage),
+            FormKind::PushImage => required("tag").map(DockerMessage::PushImage),
+            FormKind::BuildImage => ImageBuild::new(
+                &self.value("context"),
+                &self.value("dockerfile"),
+                &self.value("tag"),
+                &self.value("build args"),
+            )
+            .map(DockerMessage::BuildImage),
+            FormKind::SaveImages => {
+                let images = self
+                    .value("images")
+                    .split_whitespace()
+                    .map(ToOwned::to_owned)
+                    .collect::<Vec<_>>();
+                required("file")
+                    .filter(|_| !images.is_empty())
+                    .map(|file| DockerMessage::SaveImages(images, file))
+            }
+            FormKind::LoadImages => required("file").map(DockerMessage::LoadImages),
+            FormKind::SaveFiles(path, true) => {
+                required("save to").map(|file| DockerMessage::SaveDirectory(path.clone(), file))
+            }
+            FormKind::SaveFiles(path, false) => {
+                required("save to").map(|file| DockerMessage::SaveFile(path.clone(), file))
+            }
+            FormKind::GoToPath => required("path")
+                .filter(|i| i.starts_with('/'))
+                .map(DockerMessage::ListFiles),
+            FormKind::FilterChanges => Some(DockerMessage::FilterChanges(ChangeFilter::new(
+                &self.value("kind"),
+                &self.value("path"),
+            ))),
+            FormKind::KillContainer(id) => {
+                Signal::new(&self.value("signal"), &self.value("number"))
+                    .map(|signal| DockerMessage::KillContainer(id.clone(), signal))
+            }
+            FormKind::SignalProcess(id, target) => {
+                Signal::new(&self.value("signal"), &self.value("number"))
+                    .map(|signal| DockerMessage::SignalProcess(id.clone(), target.clone(), signal))
+            }
+            FormKind::DownloadFiles(id) => required("container path").map(|path| {
+                let directory = required("local directory").unwrap_or_else(|| String::from("."));
+                DockerMessage::DownloadFiles(id.clone(), path, directory)
+            }),
+            FormKind::UploadFiles(id) => required("local path")
+                .zip(required("container directory"))
+                .map(|(path, directory)| DockerMessage::UploadFiles(id.clone(), path, directory)),
+            FormKind::ConnectNetwork => NetworkConnect::new(
+                &self.value("container"),
+                &self.value("network"),
+                &self.value("alias"),
+                &self.value("ip"),
+            )
+            .map(DockerMessage::ConnectNetwork),
+            FormKind::DisconnectNetwork => required("container").and_then(|container| {
+                required("network")
+                    .map(|network| DockerMessage::DisconnectNetwork(container, network))
+            }),
+            FormKind::CreateNetwork => NetworkCreate::new(
+                &self.value("name"),
+                &self.value("driver"),
+                &self.value("subnet"),
+            )
+            .map(DockerMessage::CreateNetwork),
+            FormKind::Compose => {
+                required("file").and_then(|file| match self.value("action").as_str() {
+                    "down" => Some(DockerMessage::ConfirmComposeDown(file)),
+                    "recreate" => required("service").map(|service| {
+                        DockerMessage::Compose(file, ComposeAction::Recreate(service))
+                    }),
+                    _ => Some(DockerMessage::Compose(file, ComposeAction::Up)),
+                })
+            }
+            FormKind::ScaleService(name) => required("replicas")
+                .and_then(|i| i.parse::<u64>().ok())
+                .map(|replicas| DockerMessage::ScaleService(name.clone(), replicas)),
+            FormKind::RunContainer => ContainerRun::new(
+                &self.value("image"),
+                &self.value("name"),
+                &self.value("command"),
+            )
+            .and_then(|run| run.env(&self.value("env")))
+            .and_then(|run| run.ports(&self.value("ports")))
+            .map(|run| {
+                run.volumes(&self.value("volumes"))
+                    .network(&self.value("network"), &self.value("restart"))
+            })
+            .and_then(|run| run.limits(&self.value("memory"), &self.value("cpus")))
+            .map(DockerMessage::RunContainer),
+            FormKind::CommitContainer(id) => ContainerCommit::new(
+                &self.value("tag"),
+                &self.value("cmd"),
+                &self.value("entrypoint"),
+                &self.value("env"),
+                &self.value("labels"),
+                &self.value("ports"),
+                self.value("recreate") == "yes",
+            )
+            .map(|commit| DockerMessage::CommitContainer(id.clone(), commit)),
+            FormKind::RecreateContainer(id) => ContainerRun::new(&self.value("image"), "", "")
+                .and_then(|run| run.env(&self.value("env")))
+                .and_then(|run| run.ports(&self.value("ports")))
+                .and_then(|run| {
+                    let restart =
+                        restart_policy(&self.value("restart"), &self.value("max retries"))?;
+                    Some(run.volumes(&self.value("volumes")).network("", &restart))
+                })
+                .and_then(|run| run.limits(&self.value("memory"), &self.value("cpus")))
+                .map(|run| DockerMessage::RecreateContainer(id.clone(), run)),
+            FormKind::SaveExport(name) => {
+                let (format, file) = if self.value("format") == "run" {
+                    (ExportFormat::Run, format!("{name}.sh"))
+                } else {
+                    (ExportFormat::Compose, format!("{name}.compose.yml"))
+                };
+                Some(DockerMessage::SaveExport(
+                    format,
+                    required("file").unwrap_or(file),
+                ))
+            }
+            FormKind::PruneImages => ImagePrune::new(
+                self.value("remove") == "unused",
+                &self.value("until"),
+                &self.value("label"),
+            )
+            .map(DockerMessage::ConfirmPruneImages),
+        }
+    }
+}
@@ -2,7 +2,8 @@ use std::{borrow::Cow, sync::Arc};
 
 use crate::{
     app_data::{
-        container_state::{ContainerId, State},
+        container_state::{ContainerItem, State},
+        node_state::NodeChange,
         AppData,
     },
     docker_data::DockerMessage,
@@ -11,7 +12,7 @@ use crossterm::event::KeyCode;
 
 use parking_lot::Mutex;
 
-use super::{GuiState, Status};
+use super::{form::Form, GuiState};
 
 #[derive(Debug, Default, Clone, Eq, Hash, PartialEq)]
 pub enum NavPanel {
@@ -20,6 +21,21 @@ pub enum NavPanel {
     Logs,
     Metrics,
     Info,
+    Images,
+    ImageInfo,
+    ImageHistory,
+    BuildLog,
+    Volumes,
+    VolumeInfo,
+    Files,
+    FileView,
+    Networks,
+    Services,
+    ServiceTasks,
+    ServiceLogs,
+    Nodes,
+    Changes,
+    Processes,
 }
 
 pub enum Action {
@@ -27,6 +43,8 @@ pub enum Action {
     BackAction(String, KeyCode),
     DockerMessageAction(String, KeyCode, DockerMessage),
     NavAndDockerMessageAction(String, KeyCode, NavPanel, DockerMessage),
+    BackAndDockerMessage(String, KeyCode, DockerMessage),
+    Form(String, KeyCode, Form),
 }
 
 impl Action {
@@ -36,6 +54,8 @@ impl Action {
             Self::BackAction(label, _) => label,
             Self::DockerMessageAction(label, _, _) => label,
             Self::NavAndDockerMessageAction(label, _, _, _) => label,
+            Self::BackAndDockerMessage(label, _, _) => label,
+            Self::Form(label, _, _) => label,
         }
     }
 
@@ -45,6 +65,8 @@ impl Action {
             Self::BackAction(_, k) => *k,
             Self::DockerMessageAction(_, k, _) => *k,
             Self::NavAndDockerMessageAction(_, k, _, _) => *k,
+            Self::BackAndDockerMessage(_, k, _) => *k,
+            Self::Form(_, k, _) => *k,
         }
     }
 }
@@ -56,6 +78,21 @@ impl NavPanel {
             Self::Logs => "Logs".into(),
             Self::Metrics => "Metrics".into(),
             Self::Info => "Infos".into(),
+            Self::Images => "Images".into(),
+            Self::ImageInfo => "Image infos".into(),
+            Self::ImageHistory => "Image history".into(),
+            Self::BuildLog => "Build".into(),
+            Self::Volumes => "Volumes".into(),
+            Self::VolumeInfo => "Volume infos".into(),
+            Self::Files => "Files".into(),
+            Self::FileView => "File".into(),
+            Self::Networks => "Networks".into(),
+            Self::Services => "Services".into(),
+            Self::ServiceTasks => "Tasks".into(),
+            Self::ServiceLogs => "Service logs".into(),
+            Self::Nodes => "Nodes".into(),
+            Self::Changes => "Changes".into(),
+            Self::Processes => "Processes".into(),
         }
     }
 
@@ -64,186 +101,629 @@ impl NavPanel {
         gui_state: &Arc<Mutex<GuiState>>,
         app_data: &Arc<Mutex<AppData>>,
     ) -> Vec<Action> {
-        let mut out: Vec<Action> = vec![];
-        out.append(&mut self.actions_0(gui_state, app_data));
-        out.append(&mut self.actions_1(gui_state, app_data));
-        out.append(&mut self.actions_2(gui_state, app_data));
-        return out;
+        self.actions(gui_state, app_data)
+            .into_iter()
+            .flatten()
+            .collect()
     }
 
-    pub fn actions_0(
+    /// The actions of the panel, one list for each of the three columns of the actions bar
+    pub fn actions(
         &self,
         gui_state: &Arc<Mutex<GuiState>>,
         app_data: &Arc<Mutex<AppData>>,
-    ) -> Vec<Action> {
+    ) -> [Vec<Action>; 3] {
+        let loading = gui_state.lock().is_loading();
+        let app_data = app_data.lock();
         match self {
-            Self::Containers => {
-                let _app_data = app_data.lock();
-                let maybe_selected_container = _app_data.container_data.get_selected_container();
-                if let Some(selected_container) = maybe_selected_container {
-                    match selected_container.state {
-                        State::Running => vec![
-                            Action::NavAction(
-                                String::from("(l) Logs"),
-                                KeyCode::Char('l'),
-                                NavPanel::Logs,
-                            ),
-                            Action::NavAndDockerMessageAction(
-                                String::from("(i) Info"),
-                                KeyCode::Char('i'),
-                                NavPanel::Info,
-                                DockerMessage::InfosContainer(selected_container.id.clone()),
-                            ),
-                            Action::NavAction(
-                                String::from("(m) Metrics"),
-                                KeyCode::Char('m'),
-                                NavPanel::Metrics,
-                            ),
-                            Action::DockerMessageAction(
-                                String::from("(s) Shell"),
-                                KeyCode::Char('s'),
-                                DockerMessage::ShellContainer(selected_container.id.clone()),
-                            ),
-                        ],
-                        _ => vec![
-                            Action::NavAction(
-                                String::from("(l) Logs"),
-                                KeyCode::Char('l'),
-                                NavPanel::Logs,
-                            ),
-                            Action::NavAndDockerMessageAction(
-                                String::from("(i) Info"),
-                                KeyCode::Char('i'),
-                                NavPanel::Info,
-                                DockerMessage::InfosContainer(selected_container.id.clone()),
-                            ),
-                        ],
-                    }
-                } else {
-                    vec![]
-                }
+            Self::Containers => Self::containers_actions(&app_data, loading),
+            Self::Info => Self::info_actions(&app_data, loading),
+            Self::Images => Self::images_actions(&app_data, loading),
+            Self::BuildLog => Self::build_log_actions(&app_data),
+            Self::Volumes => Self::volumes_actions(&app_data, loading),
+            Self::Files => Self::files_actions(&app_data),
+            Self::Networks => Self::networks_actions(&app_data, loading),
+            Self::Services => Self::services_actions(&app_data, loading),
+            Self::Nodes => Self::nodes_actions(&app_data, loading),
+            Self::Changes => Self::changes_actions(&app_data),
+            Self::Processes => Self::processes_actions(&app_data),
+            Self::Logs
+            | Self::Metrics
+            | Self::ImageInfo
+            | Self::ImageHistory
+            | Self::VolumeInfo
+            | Self::FileView
+            | Self::ServiceTasks
+            | Self::ServiceLogs => [vec![back()], vec![], vec![]],
+        }
+    }
+
+    fn containers_actions(app_data: &AppData, loading: bool) -> [Vec<Action>; 3] {
+        let container = app_data.container_data.get_selected_container();
+        let views = container.map_or_else(Vec::new, container_views);
+        let controls = if loading {
+            vec![]
+        } else if let Some(container) = container {
+            container_controls(container)
+        } else if let Some(project) = app_data.container_data.get_selected_project() {
+            vec![
+                Action::DockerMessageAction(
+                    String::from("(r) Restart all"),
+                    KeyCode::Char('r'),
+                    DockerMessage::RestartProject(project.clone()),
+                ),
+                Action::DockerMessageAction(
+                    String::from("(s) Start all"),
+                    KeyCode::Char('s'),
+                    DockerMessage::StartProject(project.clone()),
+                ),
+                Action::DockerMessageAction(
+                    String::from("(x) Stop all"),
+                    KeyCode::Char('x'),
+                    DockerMessage::StopProject(project),
+                ),
+            ]
+        } else {
+            vec![]
+        };
+
+        let mut panels = vec![
+            Action::NavAction(String::from("(I) Images"), KeyCode::Char('I'), Self::Images),
+            Action::NavAction(
+                String::from("(V) Volumes"),
+                KeyCode::Char('V'),
+                Self::Volumes,
+            ),
+            Action::NavAction(
+                String::from("(N) Networks"),
+                KeyCode::Char('N'),
+                Self::Networks,
+            ),
+            Action::NavAction(
+                String::from("(S) Services"),
+                KeyCode::Char('S'),
+                Self::Services,
+            ),
+        ];
+        let project = app_data
+            .container_data
+            .get_selected_project()
+            .or_else(|| container.and_then(|i| i.project().map(ToOwned::to_owned)));
+        let file = project
+            .and_then(|i| app_data.container_data.get_project_file(&i))
+            .unwrap_or_else(|| String::from("docker-compose.yml"));
+        panels.push(Action::Form(
+            String::from("(o) Compose"),
+            KeyCode::Char('o'),
+            Form::compose(&file),
+        ));
+        panels.push(Action::Form(
+            String::from("(R) Run new"),
+            KeyCode::Char('R'),
+            Form::run_container(""),
+        ));
+        if let Some(container) = container {
+            let network = container
+                .networks
+                .first()
+                .map(|i| i.name.clone())
+                .unwrap_or_default();
+            panels.push(Action::Form(
+                String::from("(c) Connect"),
+                KeyCode::Char('c'),
+                Form::connect_network(&container.name, ""),
+            ));
+            panels.push(Action::Form(
+                String::from("(C) Disconnect"),
+                KeyCode::Char('C'),
+                Form::disconnect_network(&container.name, &network),
+            ));
+        }
+        [views, controls, panels]
+    }
+
+    fn info_actions(app_data: &AppData, loading: bool) -> [Vec<Action>; 3] {
+        let export = match app_data.container_data.get_selected_container_id() {
+            Some(id) if !loading => vec![Action::DockerMessageAction(
+                String::from("(e) Export"),
+                KeyCode::Char('e'),
+                DockerMessage::ExportContainer(id),
+            )],
+            _ => vec![],
+        };
+        [vec![back()], export, vec![]]
+    }
+
+    fn images_actions(app_data: &AppData, loading: bool) -> [Vec<Action>; 3] {
+        let mut views = vec![back()];
+        if let Some(id) = app_data.image_data.get_selected_image_id() {
+            views.push(Action::NavAndDockerMessageAction(
+                String::from("(i) Info"),
+                KeyCode::Char('i'),
+                Self::ImageInfo,
+                DockerMessage::InfosImage(id.clone()),
+            ));
+            views.push(Action::NavAndDockerMessageAction(
+                String::from("(y) History"),
+                KeyCode::Char('y'),
+                Self::ImageHistory,
+                DockerMessage::HistoryImage(id),
+            ));
+        }
+        if loading {
+            return [views, vec![], vec![]];
+        }
+
+        let mut controls = vec![
+            Action::Form(
+                String::from("(P) Pull"),
+                KeyCode::Char('P'),
+                Form::pull_image(),
+            ),
+            Action::Form(
+                String::from("(p) Prune"),
+                KeyCode::Char('p'),
+                Form::prune_images(),
+            ),
+            Action::Form(
+                String::from("(b) Build"),
+                KeyCode::Char('b'),
+                Form::build_image(),
+            ),
+        ];
+        if app_data.build.is_some() {
+            controls.push(Action::NavAction(
+                String::from("(B) Build log"),
+                KeyCode::Char('B'),
+                Self::BuildLog,
+            ));
+        }
+        if let Some(id) = app_data.image_data.get_selected_image_id() {
+            controls.push(Action::DockerMessageAction(
+                String::from("(d) Delete"),
+                KeyCode::Char('d'),
+                DockerMessage::DeleteImage(id.clone()),
+            ));
+            controls.push(Action::DockerMessageAction(
+                String::from("(D) Force delete"),
+                KeyCode::Char('D'),
+                DockerMessage::ForceDeleteImage(id),
+            ));
+        }
+
+        let mut image_actions =
+            app_data
+                .image_data
+                .get_selected_image()
+                .map_or_else(Vec::new, |image| {
+                    let name = image.name();
+                    vec![
+                        Action::Form(
+                            String::from("(t) Tag"),
+                            KeyCode::Char('t'),
+                            Form::tag_image(image.id.clone(), &name),
+                        ),
+                        Action::Form(
+                            String::from("(T) Untag"),
+                            KeyCode::Char('T'),
+                            Form::untag_image(&name),
+                        ),
+                        Action::Form(
+                            String::from("(u) Push"),
+                            KeyCode::Char('u'),
+                            Form::push_image(&name),
+                        ),
+                        Action::Form(
+                            String::from("(e) Save"),
+                            KeyCode::Char('e'),
+                            Form::save_images(&name),
+                        ),
+                        Action::Form(
+                            String::from("(R) Run"),
+                            KeyCode::Char('R'),
+                            // Dangling images can only be run by their id
+                            Form::run_container(if name.starts_with("<none>") {
+                                image.id.get()
+                            } else {
+                                &name
+                            }),
+                        ),
+                    ]
+                });
+        image_actions.push(Action::Form(
+            String::from("(L) Load"),
+            KeyCode::Char('L'),
+            Form::load_images(),
+        ));
+        [views, controls, image_actions]
+    }
+
+    fn build_log_actions(app_data: &AppData) -> [Vec<Action>; 3] {
+        let cancel = if app_data.get_build().is_some_and(|build| build.is_running()) {
+            vec![Action::DockerMessageAction(
+                String::from("(c) Cancel"),
+                KeyCode::Char('c'),
+                DockerMessage::CancelBuild,
+            )]
+        } else {
+            vec![]
+        };
+        [vec![back()], cancel, vec![]]
+    }
+
+    fn volumes_actions(app_data: &AppData, loading: bool) -> [Vec<Action>; 3] {
+        let name = app_data.volume_data.get_selected_volume_name();
+        let mut views = vec![back()];
+        if let Some(name) = name.clone() {
+            views.push(Action::NavAndDockerMessageAction(
+                String::from("(i) Info"),
+                KeyCode::Char('i'),
+                Self::VolumeInfo,
+                DockerMessage::InfosVolume(name.clone()),
+            ));
+            views.push(Action::NavAndDockerMessageAction(
+                String::from("(f) Files"),
+                KeyCode::Char('f'),
+                Self::Files,
+                DockerMessage::BrowseVolume(name),
+            ));
+        }
+        let mut controls = vec![];
+        if !loading {
+            controls.push(Action::DockerMessageAction(
+                String::from("(p) Prune"),
+                KeyCode::Char('p'),
+                DockerMessage::ConfirmPruneVolumes,
+            ));
+            if let Some(name) = name {
+                controls.push(Action::DockerMessageAction(
+                    String::from("(d) Delete"),
+                    KeyCode::Char('d'),
+                    DockerMessage::ConfirmDeleteVolume(name),
+                ));
             }
-            Self::Logs => {
-                vec![Action::BackAction(String::from("(Esc) back"), KeyCode::Esc)]
+        }
+        [views, controls, vec![]]
+    }
+
+    fn files_actions(app_data: &AppData) -> [Vec<Action>; 3] {
+        let back = vec![Action::BackAndDockerMessage(
+            String::from("(Esc) back"),
+            KeyCode::Esc,
+            DockerMessage::CloseFiles,
+        )];
+        let Some(files) = app_data.get_files() else {
+            return [back, vec![], vec![]];
+        };
+        let mut actions = vec![];
+        if let Some((path, entry)) = files.get_selected() {
+            let open = if entry.is_directory() {
+                DockerMessage::ListFiles(path.clone())
+            } else {
+                DockerMessage::ViewFile(path.clone())
+            };
+            actions.push(Action::DockerMessageAction(
+                String::from("(enter) Open"),
+                KeyCode::Enter,
+                open,
+            ));
+            actions.push(Action::Form(
+                String::from("(s) Save"),
+                KeyCode::Char('s'),
+                Form::save_files(&path, entry.is_directory()),
+            ));
+        }
+        if let Some(parent) = files.get_parent() {
+            actions.push(Action::DockerMessageAction(
+                String::from("(backspace) Up"),
+                KeyCode::Backspace,
+                DockerMessage::ListFiles(parent),
+            ));
+        }
+        // A volume is mounted inside a helper container, so only the volume itself can be browsed
+        if !files.helper {
+            actions.push(Action::Form(
+                String::from("(g) Go to"),
+                KeyCode::Char('g'),
+                Form::go_to_path(&files.path),
+            ));
+            actions.push(Action::DockerMessageAction(
+                String::from("(/) Root"),
+                KeyCode::Char('/'),
+                DockerMessage::ListFiles(String::from("/")),
+            ));
+            actions.push(Action::DockerMessageAction(
+                String::from("(e) /etc"),
+                KeyCode::Char('e'),
+                DockerMessage::ListFiles(String::from("/etc")),
+            ));
+            if let Some(workdir) = files.workdir.clone() {
+                actions.push(Action::DockerMessageAction(
+                    String::from("(w) Workdir"),
+                    KeyCode::Char('w'),
+                    DockerMessage::ListFiles(workdir),
+                ));
             }
-            Self::Metrics => {
-                vec![Action::BackAction(String::from("(Esc) back"), KeyCode::Esc)]
+        }
+        actions.push(Action::DockerMessageAction(
+            String::from("(r) Refresh"),
+            KeyCode::Char('r'),
+            DockerMessage::ListFiles(files.path),
+        ));
+        [back, actions, vec![]]
+    }
+
+    fn networks_actions(app_data: &AppData, loading: bool) -> [Vec<Action>; 3] {
+        if loading {
+            return [vec![back()], vec![], vec![]];
+        }
+        let mut actions = vec![Action::Form(
+            String::from("(n) New"),
+            KeyCode::Char('n'),
+            Form::create_network(),
+        )];
+        if let Some(network) = app_data.network_data.get_selected_network() {
+            let container = network
+                .containers
+                .first()
+                .map(|i| i.name.clone())
+                .unwrap_or_default();
+            actions.push(Action::Form(
+                String::from("(c) Connect"),
+                KeyCode::Char('c'),
+                Form::connect_network("", &network.name),
+            ));
+            actions.push(Action::Form(
+                String::from("(C) Disconnect"),
+                KeyCode::Char('C'),
+                Form::disconnect_network(&container, &network.name),
+            ));
+            if !network.is_predefined() {
+                actions.push(Action::DockerMessageAction(
+                    String::from("(d) Delete"),
+                    KeyCode::Char('d'),
+                    DockerMessage::ConfirmDeleteNetwork(network.name.clone()),
+                ));
             }
-            Self::Info => {
-                vec![Action::BackAction(String::from("(Esc) back"), KeyCode::Esc)]
+        }
+        [vec![back()], actions, vec![]]
+    }
+
+    fn services_actions(app_data: &AppData, loading: bool) -> [Vec<Action>; 3] {
+        let service = app_data.service_data.get_selected_service();
+        let mut views = vec![back()];
+        if service.is_some() {
+            // Service logs are only fetched whilst the panel is open, so update straight away rather than waiting for the next tick
+            views.push(Action::NavAndDockerMessageAction(
+                String::from("(l) Logs"),
+                KeyCode::Char('l'),
+                Self::ServiceLogs,
+                DockerMessage::Update,
+            ));
+            views.push(Action::NavAction(
+                String::from("(t) Tasks"),
+                KeyCode::Char('t'),
+                Self::ServiceTasks,
+            ));
+        }
+        let mut controls = vec![];
+        if let Some(service) = service.filter(|_| !loading) {
+            if let Some(replicas) = service.replicas {
+                controls.push(Action::Form(
+                    String::from("(c) Scale"),
+                    KeyCode::Char('c'),
+                    Form::scale_service(&service.name, replicas),
+                ));
             }
+            controls.push(Action::DockerMessageAction(
+                String::from("(u) Force update"),
+                KeyCode::Char('u'),
+                DockerMessage::ForceUpdateService(service.name.clone()),
+            ));
+            controls.push(Action::DockerMessageAction(
+                String::from("(d) Delete"),
+                KeyCode::Char('d'),
+                DockerMessage::ConfirmDeleteService(service.name.clone()),
+            ));
         }
+        let panels = vec![Action::NavAction(
+            String::from("(n) Nodes"),
+            KeyCode::Char('n'),
+            Self::Nodes,
+        )];
+        [views, controls, panels]
     }
 
-    pub fn actions_1(
-        &self,
-        gui_state: &Arc<Mutex<GuiState>>,
-        app_data: &Arc<Mutex<AppData>>,
-    ) -> Vec<Action> {
-        match self {
-            Self::Containers => {
-                let loading = gui_state.lock().is_loading();
-                if loading {
-                    vec![]
-                } else {
-                    let _app_data = app_data.lock();
-                    let maybe_selected_container =
-                        _app_data.container_data.get_selected_container();
-                    if let Some(selected_container) = maybe_selected_container {
-                        match selected_container.state {
-                            State::Running => vec![
-                                Action::DockerMessageAction(
-                                    String::from("(r) Restart"),
-                                    KeyCode::Char('r'),
-                                    DockerMessage::RestartContainer(selected_container.id.clone()),
-                                ),
-                                Action::DockerMessageAction(
-                                    String::from("(p) Pause"),
-                                    KeyCode::Char('p'),
-                                    DockerMessage::PauseContainer(selected_container.id.clone()),
-                                ),
-                                Action::DockerMessageAction(
-                                    String::from("(x) Stop"),
-                                    KeyCode::Char('x'),
-                                    DockerMessage::StopContainer(selected_container.id.clone()),
-                                ),
-                                Action::DockerMessageAction(
-                                    String::from("(X) Delete"),
-                                    KeyCode::Char('X'),
-                                    DockerMessage::DeleteContainer(selected_container.id.clone()),
-                                ),
-                            ],
-                            State::Dead | State::Exited => vec![
-                                Action::DockerMessageAction(
-                                    String::from("(r) Run"),
-                                    KeyCode::Char('r'),
-                                    DockerMessage::StartContainer(selected_container.id.clone()),
-                                ),
-                                Action::DockerMessageAction(
-                                    String::from("(X) Delete"),
-                                    KeyCode::Char('X'),
-                                    DockerMessage::DeleteContainer(selected_container.id.clone()),
-                                ),
-                            ],
-                            State::Paused => vec![
-                                Action::DockerMessageAction(
-                                    String::from("(u) Unpause"),
-                                    KeyCode::Char('u'),
-                                    DockerMessage::UnpauseContainer(selected_container.id.clone()),
-                                ),
-                                Action::DockerMessageAction(
-                                    String::from("(x) Stop"),
-                                    KeyCode::Char('x'),
-                                    DockerMessage::StopContainer(selected_container.id.clone()),
-                                ),
-                                Action::DockerMessageAction(
-                                    String::from("(X) Delete"),
-                                    KeyCode::Char('X'),
-                                    DockerMessage::DeleteContainer(selected_container.id.clone()),
-                                ),
-                            ],
-                            State::Restarting | State::Removing | State::Unknown => {
-                                vec![Action::DockerMessageAction(
-                                    String::from("(X) Delete"),
-                                    KeyCode::Char('X'),
-                                    DockerMessage::DeleteContainer(selected_container.id.clone()),
-                                )]
-                            }
-                        }
-                    } else {
-                        vec![]
-                    }
+    fn nodes_actions(app_data: &AppData, loading: bool) -> [Vec<Action>; 3] {
+        let mut actions = vec![];
+        if let Some(node) = app_data.node_data.get_selected_node().filter(|_| !loading) {
+            for (text, key, change) in [
+                ("(a) Activate", 'a', NodeChange::Activate),
+                ("(p) Pause", 'p', NodeChange::Pause),
+                ("(d) Drain", 'd', NodeChange::Drain),
+            ] {
+                if node.availability != change.to_string() {
+                    actions.push(Action::DockerMessageAction(
+                        String::from(text),
+                        KeyCode::Char(key),
+                        node_message(&node.id, change),
+                    ));
                 }
             }
-            Self::Logs => {
-                vec![]
-            }
-            Self::Metrics => {
-                vec![]
-            }
-            Self::Info => {
-                vec![]
-            }
+            let (text, key, change) = if node.is_manager {
+                ("(D) Demote", 'D', NodeChange::Demote)
+            } else {
+                ("(P) Promote", 'P', NodeChange::Promote)
+            };
+            actions.push(Action::DockerMessageAction(
+                String::from(text),
+                KeyCode::Char(key),
+                node_message(&node.id, change),
+            ));
         }
+        [vec![back()], actions, vec![]]
     }
-    pub fn actions_2(
-        &self,
-        gui_state: &Arc<Mutex<GuiState>>,
-        app_data: &Arc<Mutex<AppData>>,
-    ) -> Vec<Action> {
-        match self {
-            Self::Containers => {
-                vec![]
-            }
-            Self::Logs => {
-                vec![]
-            }
-            Self::Metrics => {
-                vec![]
-            }
-            Self::Info => {
-                vec![]
-            }
+
+    fn changes_actions(app_data: &AppData) -> [Vec<Action>; 3] {
+        let actions = match app_data.get_changes() {
+            Some(changes) => vec![
+                Action::Form(
+                    String::from("(/) Filter"),
+                    KeyCode::Char('/'),
+                    Form::filter_changes(&changes.filter),
+                ),
+                Action::DockerMessageAction(
+                    String::from("(r) Refresh"),
+                    KeyCode::Char('r'),
+                    DockerMessage::ContainerChanges(changes.container),
+                ),
+            ],
+            None => vec![],
+        };
+        [vec![back()], actions, vec![]]
+    }
+
+    fn processes_actions(app_data: &AppData) -> [Vec<Action>; 3] {
+        let id = app_data.process_data.get_container_id();
+        let actions = match (id, app_data.process_data.get_selected_target()) {
+            (Some(id), Some(target)) => vec![Action::Form(
+                String::from("(k) Signal"),
+                KeyCode::Char('k'),
+                Form::signal_process(id, target),
+            )],
+            _ => vec![],
+        };
+        [vec![back()], actions, vec![]]
+    }
+}
+
+fn back() -> Action {
+    Action::BackAction(String::from("(Esc) back"), KeyCode::Esc)
+}
+
+/// The panels that can be opened for the selected container, a stopped container has no metrics, processes, shell, or files
+fn container_views(container: &ContainerItem) -> Vec<Action> {
+    let mut actions = vec![
+        Action::NavAction(String::from("(l) Logs"), KeyCode::Char('l'), NavPanel::Logs),
+        Action::NavAndDockerMessageAction(
+            String::from("(i) Info"),
+            KeyCode::Char('i'),
+            NavPanel::Info,
+            DockerMessage::InfosContainer(container.id.clone()),
+        ),
+        Action::NavAndDockerMessageAction(
+            String::from("(D) Changes"),
+            KeyCode::Char('D'),
+            NavPanel::Changes,
+            DockerMessage::ContainerChanges(container.id.clone()),
+        ),
+    ];
+    if container.state == State::Running {
+        actions.push(Action::NavAction(
+            String::from("(m) Metrics"),
+            KeyCode::Char('m'),
+            NavPanel::Metrics,
+        ));
+        actions.push(Action::NavAndDockerMessageAction(
+            String::from("(t) Top"),
+            KeyCode::Char('t'),
+            NavPanel::Processes,
+            DockerMessage::ContainerProcesses(container.id.clone()),
+        ));
+        actions.push(Action::DockerMessageAction(
+            String::from("(s) Shell"),
+            KeyCode::Char('s'),
+            DockerMessage::ShellContainer(container.id.clone()),
+        ));
+        actions.push(Action::NavAndDockerMessageAction(
+            String::from("(f) Files"),
+            KeyCode::Char('f'),
+            NavPanel::Files,
+            DockerMessage::BrowseContainer(container.id.clone()),
+        ));
+    }
+    actions
+}
+
+/// The commands that can be sent to the selected container, depending on its state
+fn container_controls(container: &ContainerItem) -> Vec<Action> {
+    let id = &container.id;
+    let mut actions = match container.state {
+        State::Running => vec![
+            Action::DockerMessageAction(
+                String::from("(r) Restart"),
+                KeyCode::Char('r'),
+                DockerMessage::RestartContainer(id.clone()),
+            ),
+            Action::DockerMessageAction(
+                String::from("(p) Pause"),
+                KeyCode::Char('p'),
+                DockerMessage::PauseContainer(id.clone()),
+            ),
+            Action::DockerMessageAction(
+                String::from("(x) Stop"),
+                KeyCode::Char('x'),
+                DockerMessage::StopContainer(id.clone()),
+            ),
+            Action::Form(
+                String::from("(k) Kill"),
+                KeyCode::Char('k'),
+                Form::kill_container(id.clone(), &container.name),
+            ),
+        ],
+        State::Dead | State::Exited => vec![Action::DockerMessageAction(
+            String::from("(r) Run"),
+            KeyCode::Char('r'),
+            DockerMessage::StartContainer(id.clone()),
+        )],
+        State::Paused => vec![
+            Action::DockerMessageAction(
+                String::from("(u) Unpause"),
+                KeyCode::Char('u'),
+                DockerMessage::UnpauseContainer(id.clone()),
+            ),
+            Action::DockerMessageAction(
+                String::from("(x) Stop"),
+                KeyCode::Char('x'),
+                DockerMessage::StopContainer(id.clone()),
+            ),
+        ],
+        State::Restarting | State::Removing | State::Unknown => vec![],
+    };
+    actions.push(Action::DockerMessageAction(
+        String::from("(X) Delete"),
+        KeyCode::Char('X'),
+        DockerMessage::DeleteContainer(id.clone()),
+    ));
+    actions.push(Action::DockerMessageAction(
+        String::from("(a) Commit"),
+        KeyCode::Char('a'),
+        DockerMessage::CommitForm(id.clone()),
+    ));
+    actions.push(Action::DockerMessageAction(
+        String::from("(e) Recreate"),
+        KeyCode::Char('e'),
+        DockerMessage::RecreateForm(id.clone()),
+    ));
+    actions.push(Action::Form(
+        String::from("(d) Download"),
+        KeyCode::Char('d'),
+        Form::download_files(id.clone()),
+    ));
+    actions.push(Action::Form(
+        String::from("(U) Upload"),
+        KeyCode::Char('U'),
+        Form::upload_files(id.clone()),
+    ));
+    actions
+}
+
+/// Draining a node moves all of its tasks, and demoting a manager can lose the quorum, so both are confirmed first
+fn node_message(id: &str, change: NodeChange) -> DockerMessage {
+    match change {
+        NodeChange::Drain | NodeChange::Demote => {
+            DockerMessage::ConfirmUpdateNode(id.to_owned(), change)
         }
+        _ => DockerMessage::UpdateNode(id.to_owned(), change),
     }
 }
@@ -11,6 +11,8 @@ use crossterm::{
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+pub use gui_state::confirm::Confirm;
+pub use gui_state::form::Form;
 pub use gui_state::nav::*;
 use parking_lot::Mutex;
 use ratatui::{
@@ -231,6 +233,7 @@ fn draw_empty<B: Backend>(f: &mut Frame<'_, B>) {
 
 /// Draw the main ui to a frame of the terminal
 /// TODO add a single line area for debug message - if not in release mode, maybe with #[cfg(debug_assertions)] ?
+#[allow(clippy::too_many_lines)]
 fn draw_frame<B: Backend>(
     f: &mut Frame<'_, B>,
     app_data: &Arc<Mutex<AppData>>,
@@ -249,6 +252,10 @@ fn draw_frame<B: Backend>(
 
     let show_help = gui_state.lock().status_contains(&[Status::Help]);
     let info_text = gui_state.lock().info_box_text.clone();
+    let form = gui_state.lock().get_form();
+    let confirm = gui_state.lock().get_confirm();
+    let progress = app_data.lock().get_progress();
+    let export = app_data.lock().get_export();
     let loading_icon = gui_state.lock().get_loading();
 
     // Whole_layout :
@@ -282,6 +289,43 @@ fn draw_frame<B: Backend>(
         NavPanel::Logs => draw_blocks::logs(app_data, whole_layout[1], f, gui_state, &loading_icon),
         NavPanel::Metrics => draw_blocks::chart(f, whole_layout[1], app_data),
         NavPanel::Info => draw_blocks::infos(app_data, whole_layout[1], f, gui_state),
+        NavPanel::Images => {
+            let image_widths = app_data.lock().image_data.get_width();
+            draw_blocks::images(app_data, whole_layout[1], f, gui_state, &image_widths);
+        }
+        NavPanel::ImageInfo => draw_blocks::image_infos(app_data, whole_layout[1], f, gui_state),
+        NavPanel::ImageHistory => {
+            draw_blocks::image_history(app_data, whole_layout[1], f, gui_state);
+        }
+        NavPanel::BuildLog => draw_blocks::build_log(app_data, whole_layout[1], f, gui_state),
+        NavPanel::Volumes => {
+            let volume_widths = app_data.lock().volume_data.get_width();
+            draw_blocks::volumes(app_data, whole_layout[1], f, gui_state, volume_widths);
+        }
+        NavPanel::VolumeInfo => draw_blocks::volume_infos(app_data, whole_layout[1], f, gui_state),
+        NavPanel::Networks => {
+            let network_widths = app_data.lock().network_data.get_width();
+            draw_blocks::networks(app_data, whole_layout[1], f, gui_state, network_widths);
+        }
+        NavPanel::Services => {
+            let service_widths = app_data.lock().service_data.get_width();
+            draw_blocks::services(app_data, whole_layout[1], f, gui_state, service_widths);
+        }
+        NavPanel::ServiceLogs => draw_blocks::service_logs(app_data, whole_layout[1], f, gui_state),
+        NavPanel::ServiceTasks => {
+            draw_blocks::service_tasks(app_data, whole_layout[1], f, gui_state);
+        }
+        NavPanel::Nodes => {
+            let node_widths = app_data.lock().node_data.get_width();
+            draw_blocks::nodes(app_data, whole_layout[1], f, gui_state, node_widths);
+        }
+        NavPanel::Processes => {
+            let process_widths = app_data.lock().process_data.get_width();
+            draw_blocks::processes(app_data, whole_layout[1], f, gui_state, process_widths);
+        }
+        NavPanel::Changes => draw_blocks::changes(app_data, whole_layout[1], f, gui_state),
+        NavPanel::Files => draw_blocks::files(app_data, whole_layout[1], f, gui_state),
+        NavPanel::FileView => draw_blocks::file_view(app_data, whole_layout[1], f, gui_state),
     }
 
     // nav - TODO
@@ -303,6 +347,22 @@ fn draw_frame<B: Backend>(
             );
     }
 
+    if let Some(progress) = progress {
+        draw_blocks::progress(f, &progress);
+    }
+
+    if let Some(export) = export {
+        draw_blocks::export(f, &export);
+    }
+
+    if let Some(form) = form {
+        draw_blocks::form(f, &form);
+    }
+
+    if let Some(confirm) = confirm {
+        draw_blocks::confirm(f, &confirm);
+    }
+
     if let Some(info) = info_text {
         draw_blocks::info(f, info);
     }